@@ -1,18 +1,10 @@
-use std::sync::OnceLock;
-
 use padauk::{
     app_bar, button, children, column, Text, Widget,
-    prelude::{State, state},
+    prelude::use_state,
 };
 
 use crate::example_layout::example_screen;
 
-static COUNTER: OnceLock<State<i32>> = OnceLock::new();
-
-fn counter() -> &'static State<i32> {
-    COUNTER.get_or_init(|| state(0))
-}
-
 pub struct StateDemoScreen;
 
 impl Widget for StateDemoScreen {
@@ -30,29 +22,25 @@ struct StateDemoContent;
 
 impl Widget for StateDemoContent {
     fn build(&self) -> padauk::UiNode {
-        let value = counter().get();
+        // Kept while this screen is on the stack; reset when it is popped.
+        let counter = use_state(|| 0);
+        let value = counter.get();
         column(children![
             Text::new(format!("Count: {}", value)),
-            button("Increment", || {
-                counter().update(|v| *v += 1);
+            button("Increment", move || {
+                counter.update(|v| *v += 1);
             }),
         ])
         .build()
     }
 }
 
-const STATE_DEMO_CODE: &str = r#"use std::sync::OnceLock;
-use padauk::prelude::{State, state};
-
-static COUNTER: OnceLock<State<i32>> = OnceLock::new();
-
-fn counter() -> &'static State<i32> {
-    COUNTER.get_or_init(|| state(0))
-}
+const STATE_DEMO_CODE: &str = r#"use padauk::prelude::use_state;
 
-// In your widget:
-let value = counter().get();
-button("Increment", || {
-    counter().update(|v| *v += 1);
+// In your widget's build():
+let counter = use_state(|| 0);
+let value = counter.get();
+button("Increment", move || {
+    counter.update(|v| *v += 1);
 });
 "#;
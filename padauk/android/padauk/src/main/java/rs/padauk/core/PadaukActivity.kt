@@ -1,17 +1,38 @@
 package rs.padauk.core
 
+import android.content.Intent
+import android.content.res.Configuration
 import android.os.Bundle
 import android.util.Log
 import androidx.activity.ComponentActivity
-import androidx.activity.compose.BackHandler
+import androidx.activity.OnBackPressedCallback
 import androidx.activity.compose.setContent
 import androidx.activity.enableEdgeToEdge
 import androidx.compose.material3.Text
+import androidx.compose.runtime.SideEffect
 import androidx.compose.runtime.mutableIntStateOf
 import androidx.compose.runtime.mutableStateOf
 import rs.padauk.core.resource.AndroidResourceLoader
 
 open class PadaukActivity : ComponentActivity() {
+    // Retained copy of the Rust tree; updated with patches instead of re-fetched.
+    private var tree: AndroidUiNode? = null
+
+    // Intercepts system back when Rust has a use for it: a route to pop, or a back
+    // interceptor to ask. Enabled ahead of time, so a predictive back gesture only
+    // previews leaving the app when Rust would let it go; the pop runs on commit.
+    private val backCallback = object : OnBackPressedCallback(false) {
+        override fun handleOnBackPressed() {
+            Log.d("Padauk", "System back -> Navigator")
+            if (!padaukNavPop()) {
+                // Rust let it through (e.g. the root route's interceptor agreed to
+                // leave): handle it as if Padauk weren't there.
+                isEnabled = false
+                onBackPressedDispatcher.onBackPressed()
+            }
+        }
+    }
+
     override fun onCreate(savedInstanceState: Bundle?) {
         super.onCreate(savedInstanceState)
         enableEdgeToEdge()
@@ -41,6 +62,22 @@ open class PadaukActivity : ComponentActivity() {
         val loader = AndroidResourceLoader(this)
         registerResourceLoader(loader)
 
+        // Report the starting configuration; later changes come through onConfigurationChanged.
+        padaukAppOnConfigChanged(resources.configuration.toAppConfig())
+
+        // Recreated after the process was killed: put the navigator back where it was.
+        // Otherwise, if started from a link, build the stack for it instead of the initial route.
+        val savedStack = savedInstanceState?.getString(NAVIGATOR_STATE_KEY)
+        if (savedStack != null && !processRestored) {
+            if (!padaukNavRestoreState(savedStack)) {
+                Log.w("Padauk", "Could not restore the navigator stack.")
+            }
+        } else if (savedInstanceState == null) {
+            openDeepLink(intent)
+        }
+        // Rust state outlives activities recreated in the same process (e.g. on rotation).
+        processRestored = true
+
 
         // 2. Setup Re-rendering Listener
         // We use a mutable state integer as a trigger. Changing it forces recomposition.
@@ -56,23 +93,102 @@ open class PadaukActivity : ComponentActivity() {
         }
         // Register this activity as the renderer
         registerRenderCallback(callback)
+        onBackPressedDispatcher.addCallback(this, backCallback)
 
 
         setContent {
             // Read value to subscribe to updates
             val trigger = refreshTrigger.intValue
 
-            // Intercept system back to pop navigator stack when possible.
             val canPop = padaukNavCanPop()
-            BackHandler(enabled = canPop) {
-                Log.d("Padauk", "System back -> Navigator.pop()")
-                padaukNavPop()
-            }
+            SideEffect { backCallback.isEnabled = canPop }
 
 
-            // Fetch the latest tree from Rust
-            val root = padaukRenderRoot()
+            // Fetch the full tree once, then only the changes since the last render
+            val current = tree
+            val root = if (current == null) {
+                padaukRenderRoot()
+            } else {
+                current.applyPatches(padaukRenderPatch())
+            }
+            tree = root
             PadaukRenderer(root)
         }
     }
+
+    override fun onSaveInstanceState(outState: Bundle) {
+        super.onSaveInstanceState(outState)
+        // Empty when saving failed; the activity then comes back to the initial route
+        val saved = padaukNavSaveState()
+        if (saved.isNotEmpty()) {
+            outState.putString(NAVIGATOR_STATE_KEY, saved)
+        }
+    }
+
+    override fun onNewIntent(intent: Intent) {
+        super.onNewIntent(intent)
+        // Later reads of `intent` (e.g. after recreation) see the new link
+        setIntent(intent)
+        openDeepLink(intent)
+    }
+
+    private fun openDeepLink(intent: Intent?) {
+        val uri = intent?.data ?: return
+        Log.d("Padauk", "Deep link: $uri")
+        if (!padaukOpenDeepLink(uri.toString())) {
+            Log.w("Padauk", "No route for deep link: $uri")
+        }
+    }
+
+    // --- Lifecycle, forwarded to PadaukApp ---
+
+    override fun onStart() {
+        super.onStart()
+        padaukAppOnStart()
+    }
+
+    override fun onResume() {
+        super.onResume()
+        padaukAppOnResume()
+        // Back may have been let through to the system, which only moved the task back
+        backCallback.isEnabled = padaukNavCanPop()
+    }
+
+    override fun onPause() {
+        padaukAppOnPause()
+        super.onPause()
+    }
+
+    override fun onStop() {
+        padaukAppOnStop()
+        super.onStop()
+    }
+
+    override fun onLowMemory() {
+        super.onLowMemory()
+        padaukAppOnLowMemory()
+    }
+
+    override fun onConfigurationChanged(newConfig: Configuration) {
+        super.onConfigurationChanged(newConfig)
+        padaukAppOnConfigChanged(newConfig.toAppConfig())
+    }
+
+    companion object {
+        private const val NAVIGATOR_STATE_KEY = "rs.padauk.navigator"
+
+        // Whether an activity has already started in this process
+        private var processRestored = false
+    }
+}
+
+private fun Configuration.toAppConfig(): AppConfig {
+    val nightMode = uiMode and Configuration.UI_MODE_NIGHT_MASK
+    return AppConfig(
+        darkTheme = nightMode == Configuration.UI_MODE_NIGHT_YES,
+        fontScale = fontScale,
+        locale = locales.get(0)?.toLanguageTag() ?: "en-US",
+        screenWidthDp = screenWidthDp.coerceAtLeast(0).toUInt(),
+        screenHeightDp = screenHeightDp.coerceAtLeast(0).toUInt()
+    )
 }
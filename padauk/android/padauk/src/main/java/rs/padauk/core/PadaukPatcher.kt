@@ -0,0 +1,125 @@
+package rs.padauk.core
+
+// Applies the patches returned by padaukRenderPatch() to the retained tree.
+// Mirrors `ui::reconciler::apply` on the Rust side: paths index into childSlots()
+// flattened in declaration order, and only list containers receive Insert/Remove.
+
+fun AndroidUiNode.applyPatches(patches: List<UiPatch>): AndroidUiNode {
+    return patches.fold(this) { tree, patch -> tree.applyPatch(patch) }
+}
+
+private fun AndroidUiNode.applyPatch(patch: UiPatch): AndroidUiNode {
+    return when (patch) {
+        is UiPatch.Insert -> updateAt(patch.path, 0) { parent ->
+            val list = parent.childSlots().first().toMutableList()
+            list.add(patch.index.toInt(), patch.node)
+            parent.withChildSlots(listOf(list))
+        }
+        is UiPatch.Remove -> updateAt(patch.path, 0) { parent ->
+            val list = parent.childSlots().first().toMutableList()
+            list.removeAt(patch.index.toInt())
+            parent.withChildSlots(listOf(list))
+        }
+        is UiPatch.Replace -> updateAt(patch.path, 0) { patch.node }
+        // The patch node carries empty child slots; keep the children we already have.
+        is UiPatch.UpdateProps -> updateAt(patch.path, 0) { old ->
+            patch.node.withChildSlots(old.childSlots())
+        }
+    }
+}
+
+private fun AndroidUiNode.updateAt(
+    path: List<UInt>,
+    depth: Int,
+    update: (AndroidUiNode) -> AndroidUiNode
+): AndroidUiNode {
+    if (depth == path.size) {
+        return update(this)
+    }
+    val index = path[depth].toInt()
+    var offset = 0
+    val slots = childSlots().map { slot ->
+        val start = offset
+        offset += slot.size
+        if (index in start until offset) {
+            slot.mapIndexed { i, child ->
+                if (start + i == index) child.updateAt(path, depth + 1, update) else child
+            }
+        } else {
+            slot
+        }
+    }
+    return withChildSlots(slots)
+}
+
+internal fun AndroidUiNode.childSlots(): List<List<AndroidUiNode>> {
+    return when (this) {
+        is AndroidUiNode.Column -> listOf(this.children)
+        is AndroidUiNode.Row -> listOf(this.children)
+        is AndroidUiNode.Stack -> listOf(this.children)
+        is AndroidUiNode.Card -> listOf(this.children)
+        is AndroidUiNode.Scroll -> listOf(this.child)
+        is AndroidUiNode.Draggable -> listOf(this.child)
+        is AndroidUiNode.LazyList -> listOf(this.items)
+        is AndroidUiNode.Scaffold -> listOf(this.appBar, this.body, this.floatingActionButton, this.bottomBar, this.snackbar)
+        is AndroidUiNode.AppBar -> listOf(this.leading)
+        is AndroidUiNode.NavigationRail -> listOf(this.header)
+        is AndroidUiNode.Button -> listOf(this.content)
+        is AndroidUiNode.Dialog -> listOf(this.content)
+        is AndroidUiNode.BottomSheet -> listOf(this.content)
+        is AndroidUiNode.Text,
+        is AndroidUiNode.NavigationBar,
+        is AndroidUiNode.TabRow,
+        is AndroidUiNode.Spacer,
+        is AndroidUiNode.Divider,
+        is AndroidUiNode.IconButton,
+        is AndroidUiNode.Checkbox,
+        is AndroidUiNode.Switch,
+        is AndroidUiNode.RadioButton,
+        is AndroidUiNode.Slider,
+        is AndroidUiNode.Chip,
+        is AndroidUiNode.Fab,
+        is AndroidUiNode.Image,
+        is AndroidUiNode.TextField,
+        is AndroidUiNode.Snackbar -> emptyList()
+    }
+}
+
+internal fun AndroidUiNode.withChildSlots(slots: List<List<AndroidUiNode>>): AndroidUiNode {
+    return when (this) {
+        is AndroidUiNode.Column -> this.copy(children = slots[0])
+        is AndroidUiNode.Row -> this.copy(children = slots[0])
+        is AndroidUiNode.Stack -> this.copy(children = slots[0])
+        is AndroidUiNode.Card -> this.copy(children = slots[0])
+        is AndroidUiNode.Scroll -> this.copy(child = slots[0])
+        is AndroidUiNode.Draggable -> this.copy(child = slots[0])
+        is AndroidUiNode.LazyList -> this.copy(items = slots[0])
+        is AndroidUiNode.Scaffold -> this.copy(
+            appBar = slots[0],
+            body = slots[1],
+            floatingActionButton = slots[2],
+            bottomBar = slots[3],
+            snackbar = slots[4]
+        )
+        is AndroidUiNode.AppBar -> this.copy(leading = slots[0])
+        is AndroidUiNode.NavigationRail -> this.copy(header = slots[0])
+        is AndroidUiNode.Button -> this.copy(content = slots[0])
+        is AndroidUiNode.Dialog -> this.copy(content = slots[0])
+        is AndroidUiNode.BottomSheet -> this.copy(content = slots[0])
+        is AndroidUiNode.Text,
+        is AndroidUiNode.NavigationBar,
+        is AndroidUiNode.TabRow,
+        is AndroidUiNode.Spacer,
+        is AndroidUiNode.Divider,
+        is AndroidUiNode.IconButton,
+        is AndroidUiNode.Checkbox,
+        is AndroidUiNode.Switch,
+        is AndroidUiNode.RadioButton,
+        is AndroidUiNode.Slider,
+        is AndroidUiNode.Chip,
+        is AndroidUiNode.Fab,
+        is AndroidUiNode.Image,
+        is AndroidUiNode.TextField,
+        is AndroidUiNode.Snackbar -> this
+    }
+}
@@ -2,9 +2,12 @@ package rs.padauk.core
 
 import android.util.Log
 import androidx.compose.foundation.clickable
+import androidx.compose.foundation.gestures.detectDragGestures
+import androidx.compose.foundation.selection.selectable
 import androidx.compose.foundation.layout.Box
 import androidx.compose.foundation.layout.Column
 import androidx.compose.foundation.layout.Row
+import androidx.compose.foundation.layout.Spacer
 import androidx.compose.foundation.layout.padding
 import androidx.compose.foundation.layout.size
 import androidx.compose.foundation.layout.PaddingValues
@@ -14,10 +17,15 @@ import androidx.compose.material.icons.Icons
 import androidx.compose.material.icons.automirrored.filled.ArrowBack
 import androidx.compose.material.icons.filled.Add
 import androidx.compose.material.icons.filled.Close
+import androidx.compose.material.icons.filled.Email
 import androidx.compose.material.icons.filled.Favorite
+import androidx.compose.material.icons.filled.Home
 import androidx.compose.material.icons.filled.Menu
+import androidx.compose.material.icons.filled.Notifications
 import androidx.compose.material.icons.filled.Person
 import androidx.compose.material.icons.filled.Search
+import androidx.compose.material.icons.filled.Settings
+import androidx.compose.material.icons.filled.Star
 import androidx.compose.material3.Button
 import androidx.compose.material3.CenterAlignedTopAppBar
 import androidx.compose.material3.FilledIconButton
@@ -36,6 +44,10 @@ import androidx.compose.material3.IconButtonDefaults
 import androidx.compose.material3.FloatingActionButtonDefaults
 import androidx.compose.material3.Checkbox
 import androidx.compose.material3.CheckboxDefaults
+import androidx.compose.material3.RadioButton
+import androidx.compose.material3.RadioButtonDefaults
+import androidx.compose.material3.Switch
+import androidx.compose.material3.SwitchDefaults
 import androidx.compose.material3.AssistChip
 import androidx.compose.material3.FilterChip
 import androidx.compose.material3.InputChip
@@ -46,10 +58,20 @@ import androidx.compose.material3.LargeFloatingActionButton
 import androidx.compose.material3.LargeTopAppBar
 import androidx.compose.material3.MaterialTheme
 import androidx.compose.material3.MediumTopAppBar
+import androidx.compose.material3.DividerDefaults
+import androidx.compose.material3.HorizontalDivider
+import androidx.compose.material3.ModalBottomSheet
+import androidx.compose.material3.ModalBottomSheetProperties
+import androidx.compose.material3.SheetValue
+import androidx.compose.material3.Surface
+import androidx.compose.material3.rememberModalBottomSheetState
 import androidx.compose.material3.Scaffold
+import androidx.compose.material3.SnackbarHost
+import androidx.compose.material3.SnackbarHostState
 import androidx.compose.material3.Text
 import androidx.compose.material3.TopAppBar
 import androidx.compose.material3.TopAppBarDefaults
+import androidx.compose.material3.VerticalDivider
 import androidx.compose.material3.Card
 import androidx.compose.material3.ElevatedCard
 import androidx.compose.material3.OutlinedCard
@@ -62,22 +84,50 @@ import androidx.compose.material3.InputChipDefaults
 import androidx.compose.material3.SuggestionChipDefaults
 import androidx.compose.material3.contentColorFor
 import androidx.compose.runtime.Composable
-import androidx.compose.ui.Alignment
+import androidx.compose.runtime.LaunchedEffect
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.key
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.rememberUpdatedState
+import androidx.compose.runtime.snapshotFlow
 import androidx.compose.ui.Modifier
 import androidx.compose.ui.draw.clip
+import androidx.compose.ui.input.pointer.pointerInput
+import androidx.compose.ui.platform.LocalDensity
 import androidx.compose.ui.unit.dp
 import androidx.compose.ui.unit.sp
+import androidx.compose.ui.window.Dialog
+import androidx.compose.ui.window.DialogProperties
+import kotlinx.coroutines.flow.drop
 import rs.padauk.core.widget.PadaukImage
+import rs.padauk.core.widget.PadaukLazyList
+import rs.padauk.core.widget.PadaukNavigationBar
+import rs.padauk.core.widget.PadaukNavigationRail
+import rs.padauk.core.widget.PadaukSlider
+import rs.padauk.core.widget.PadaukSnackbar
+import rs.padauk.core.widget.PadaukSnackbarEffect
+import rs.padauk.core.widget.PadaukTabRow
+import rs.padauk.core.widget.PadaukTextField
 import rs.padauk.core.widget.toCompose
 import rs.padauk.core.widget.toComposeColor
+import rs.padauk.core.widget.toHorizontal
+import rs.padauk.core.widget.toHorizontalArrangement
+import rs.padauk.core.widget.toVertical
+import rs.padauk.core.widget.toVerticalArrangement
 
 @OptIn(ExperimentalMaterial3Api::class)
 @Composable
 fun PadaukRenderer(widget: AndroidUiNode) {
     when (widget) {
         is AndroidUiNode.Scaffold -> {
+            val snackbarHostState = remember { SnackbarHostState() }
+            PadaukSnackbarEffect(
+                snackbarHostState,
+                widget.snackbar.firstOrNull() as? AndroidUiNode.Snackbar
+            )
             Scaffold(
                 modifier = widget.modifiers.toCompose(),
+                snackbarHost = { SnackbarHost(snackbarHostState) },
                 topBar = {
                     // Check if the vector has items
                     if (widget.appBar.isNotEmpty()) {
@@ -88,6 +138,11 @@ fun PadaukRenderer(widget: AndroidUiNode) {
                     if (widget.floatingActionButton.isNotEmpty()) {
                         PadaukRenderer(widget.floatingActionButton.first())
                     }
+                },
+                bottomBar = {
+                    if (widget.bottomBar.isNotEmpty()) {
+                        PadaukRenderer(widget.bottomBar.first())
+                    }
                 }
             ) { innerPadding ->
                 // IMPORTANT: We apply the innerPadding to the body
@@ -100,6 +155,18 @@ fun PadaukRenderer(widget: AndroidUiNode) {
             }
         }
 
+        is AndroidUiNode.NavigationBar -> {
+            PadaukNavigationBar(widget)
+        }
+
+        is AndroidUiNode.NavigationRail -> {
+            PadaukNavigationRail(widget)
+        }
+
+        is AndroidUiNode.TabRow -> {
+            PadaukTabRow(widget)
+        }
+
         is AndroidUiNode.AppBar -> {
             val navIcon: @Composable () -> Unit = {
                 if (widget.leading.isNotEmpty()) {
@@ -157,52 +224,109 @@ fun PadaukRenderer(widget: AndroidUiNode) {
 
         is AndroidUiNode.Column -> {
             Column(
-                horizontalAlignment = Alignment.CenterHorizontally,
+                verticalArrangement = widget.mainAxisAlignment.toVerticalArrangement(widget.spacing),
+                horizontalAlignment = widget.crossAxisAlignment.toHorizontal(),
                 modifier = widget.modifiers.toCompose()
             ) {
                 val columnScope = this
                 widget.children.forEach { child ->
-                    val childModifiers = child.modifiersOrNull()
-                    val weight = childModifiers?.weight
-                    if (weight != null) {
-                        val fill = childModifiers.weightFill ?: true
-                        Box(modifier = with(columnScope) { Modifier.weight(weight, fill) }) {
+                    KeyedChild(child) {
+                        val childModifiers = child.modifiersOrNull()
+                        val weight = childModifiers?.weight
+                        if (weight != null) {
+                            val fill = childModifiers.weightFill ?: true
+                            Box(modifier = with(columnScope) { Modifier.weight(weight, fill) }) {
+                                PadaukRenderer(child)
+                            }
+                        } else {
                             PadaukRenderer(child)
                         }
-                    } else {
-                        PadaukRenderer(child)
                     }
                 }
             }
         }
 
         is AndroidUiNode.Row -> {
-            Row(modifier = widget.modifiers.toCompose()) {
+            Row(
+                horizontalArrangement = widget.mainAxisAlignment.toHorizontalArrangement(widget.spacing),
+                verticalAlignment = widget.crossAxisAlignment.toVertical(),
+                modifier = widget.modifiers.toCompose()
+            ) {
                 val rowScope = this
                 widget.children.forEach { child ->
-                    val childModifiers = child.modifiersOrNull()
-                    val weight = childModifiers?.weight
-                    if (weight != null) {
-                        val fill = childModifiers.weightFill ?: true
-                        Box(modifier = with(rowScope) { Modifier.weight(weight, fill) }) {
+                    KeyedChild(child) {
+                        val childModifiers = child.modifiersOrNull()
+                        val weight = childModifiers?.weight
+                        if (weight != null) {
+                            val fill = childModifiers.weightFill ?: true
+                            Box(modifier = with(rowScope) { Modifier.weight(weight, fill) }) {
+                                PadaukRenderer(child)
+                            }
+                        } else {
                             PadaukRenderer(child)
                         }
-                    } else {
-                        PadaukRenderer(child)
                     }
                 }
             }
         }
 
         is AndroidUiNode.Stack -> {
-            Box(modifier = widget.modifiers.toCompose()) {
-                widget.children.forEach { PadaukRenderer(it) }
+            Box(
+                contentAlignment = widget.alignment.toCompose(),
+                modifier = widget.modifiers.toCompose()
+            ) {
+                val boxScope = this
+                widget.children.forEach { child ->
+                    KeyedChild(child) {
+                        val align = child.modifiersOrNull()?.align
+                        if (align != null) {
+                            Box(modifier = with(boxScope) { Modifier.align(align.toCompose()) }) {
+                                PadaukRenderer(child)
+                            }
+                        } else {
+                            PadaukRenderer(child)
+                        }
+                    }
+                }
+            }
+        }
+
+        is AndroidUiNode.Spacer -> {
+            Spacer(modifier = widget.modifiers.toCompose())
+        }
+
+        is AndroidUiNode.Divider -> {
+            val thickness = widget.thickness?.dp ?: DividerDefaults.Thickness
+            val color = widget.color?.toComposeColor() ?: DividerDefaults.color
+            if (widget.vertical) {
+                VerticalDivider(
+                    modifier = widget.modifiers.toCompose(),
+                    thickness = thickness,
+                    color = color
+                )
+            } else {
+                HorizontalDivider(
+                    modifier = widget.modifiers.toCompose(),
+                    thickness = thickness,
+                    color = color
+                )
             }
         }
 
         is AndroidUiNode.Scroll -> {
             val child = widget.child.firstOrNull()
             val scroll = rememberScrollState()
+            val scrollActionId = widget.scrollActionId
+            if (scrollActionId != null) {
+                val density = LocalDensity.current
+                LaunchedEffect(scroll, scrollActionId) {
+                    // Skips the position the content starts at
+                    snapshotFlow { scroll.value }.drop(1).collect { offset ->
+                        val y = with(density) { offset.toDp().value }
+                        padaukDispatchEvent(scrollActionId, EventPayload.Scroll(ScrollPosition(0f, y)))
+                    }
+                }
+            }
             Box(
                 modifier = widget.modifiers.toCompose().verticalScroll(scroll)
             ) {
@@ -212,6 +336,27 @@ fun PadaukRenderer(widget: AndroidUiNode) {
             }
         }
 
+        is AndroidUiNode.Draggable -> {
+            val child = widget.child.firstOrNull()
+            Box(
+                modifier = widget.modifiers.toCompose().pointerInput(widget.actionId) {
+                    detectDragGestures { change, amount ->
+                        change.consume()
+                        val delta = DragDelta(amount.x.toDp().value, amount.y.toDp().value)
+                        padaukDispatchEvent(widget.actionId, EventPayload.Drag(delta))
+                    }
+                }
+            ) {
+                if (child != null) {
+                    PadaukRenderer(child)
+                }
+            }
+        }
+
+        is AndroidUiNode.LazyList -> {
+            PadaukLazyList(widget)
+        }
+
         is AndroidUiNode.Text -> {
             Text(
                 text = widget.text,
@@ -419,7 +564,7 @@ fun PadaukRenderer(widget: AndroidUiNode) {
                     modifier = Modifier
                         .padding(16.dp)
                 ) {
-                    widget.children.forEach { PadaukRenderer(it) }
+                    widget.children.forEach { child -> KeyedChild(child) { PadaukRenderer(child) } }
                 }
             }
             val onClick = widget.actionId?.let { id ->
@@ -538,6 +683,74 @@ fun PadaukRenderer(widget: AndroidUiNode) {
             )
         }
 
+        is AndroidUiNode.Switch -> {
+            val colors = SwitchDefaults.colors(
+                checkedThumbColor = widget.colorCheckedThumb?.toComposeColor()
+                    ?: MaterialTheme.colorScheme.onPrimary,
+                checkedTrackColor = widget.colorCheckedTrack?.toComposeColor()
+                    ?: MaterialTheme.colorScheme.primary,
+                uncheckedThumbColor = widget.colorUncheckedThumb?.toComposeColor()
+                    ?: MaterialTheme.colorScheme.outline,
+                uncheckedTrackColor = widget.colorUncheckedTrack?.toComposeColor()
+                    ?: MaterialTheme.colorScheme.surfaceContainerHighest
+            )
+            Switch(
+                modifier = widget.modifiers.toCompose(),
+                checked = widget.checked,
+                onCheckedChange = {
+                    Log.d("Padauk", "Switch toggle: ${widget.actionId} $it")
+                    padaukDispatchEvent(widget.actionId, EventPayload.Toggle(it))
+                },
+                enabled = widget.enabled,
+                colors = colors
+            )
+        }
+
+        is AndroidUiNode.RadioButton -> {
+            val colors = RadioButtonDefaults.colors(
+                selectedColor = widget.colorSelected?.toComposeColor()
+                    ?: MaterialTheme.colorScheme.primary,
+                unselectedColor = widget.colorUnselected?.toComposeColor()
+                    ?: MaterialTheme.colorScheme.onSurfaceVariant
+            )
+            val onClick = {
+                Log.d("Padauk", "Radio select: ${widget.actionId}")
+                padaukDispatchAction(widget.actionId)
+            }
+            val label = widget.label
+            if (label == null) {
+                RadioButton(
+                    modifier = widget.modifiers.toCompose(),
+                    selected = widget.selected,
+                    onClick = onClick,
+                    enabled = widget.enabled,
+                    colors = colors
+                )
+            } else {
+                // The whole row selects the button, as the label belongs to it
+                Row(
+                    modifier = widget.modifiers.toCompose().selectable(
+                        selected = widget.selected,
+                        enabled = widget.enabled,
+                        onClick = onClick
+                    ),
+                    verticalAlignment = CrossAxisAlignment.CENTER.toVertical()
+                ) {
+                    RadioButton(
+                        selected = widget.selected,
+                        onClick = null,
+                        enabled = widget.enabled,
+                        colors = colors
+                    )
+                    Text(label, modifier = Modifier.padding(end = 16.dp))
+                }
+            }
+        }
+
+        is AndroidUiNode.Slider -> {
+            PadaukSlider(widget)
+        }
+
         is AndroidUiNode.Chip -> {
             val onClick = {
                 Log.d("Padauk", "Chip click: ${widget.actionId}")
@@ -740,6 +953,67 @@ fun PadaukRenderer(widget: AndroidUiNode) {
                 modifier = widget.modifiers.toCompose()
             )
         }
+
+        is AndroidUiNode.TextField -> {
+            PadaukTextField(widget)
+        }
+
+        is AndroidUiNode.Snackbar -> {
+            PadaukSnackbar(widget)
+        }
+
+        // Overlays open their own window, so they draw over the screen wherever they sit in the tree.
+        is AndroidUiNode.Dialog -> {
+            val dismissActionId = widget.dismissActionId
+            // Back or a scrim tap asks Rust, which pops the route unless a back
+            // interceptor keeps it; the dialog goes when its node does.
+            Dialog(
+                onDismissRequest = { dismissActionId?.let { padaukDispatchAction(it) } },
+                properties = DialogProperties(
+                    dismissOnBackPress = dismissActionId != null,
+                    dismissOnClickOutside = dismissActionId != null
+                )
+            ) {
+                Surface(
+                    modifier = widget.modifiers.toCompose(),
+                    shape = MaterialTheme.shapes.extraLarge,
+                    tonalElevation = 6.dp
+                ) {
+                    Box(modifier = Modifier.padding(24.dp)) {
+                        if (widget.content.isNotEmpty()) {
+                            PadaukRenderer(widget.content.first())
+                        }
+                    }
+                }
+            }
+        }
+
+        is AndroidUiNode.BottomSheet -> {
+            val dismissActionId = widget.dismissActionId
+            val currentDismissActionId by rememberUpdatedState(dismissActionId)
+            // The sheet never hides itself: a swipe, scrim tap or back press asks Rust,
+            // whose back interceptors may keep it open, and it goes once its route is popped.
+            val sheetState = rememberModalBottomSheetState(
+                confirmValueChange = {
+                    if (it == SheetValue.Hidden) {
+                        currentDismissActionId?.let { id -> padaukDispatchAction(id) }
+                    }
+                    it != SheetValue.Hidden
+                }
+            )
+            ModalBottomSheet(
+                onDismissRequest = { dismissActionId?.let { padaukDispatchAction(it) } },
+                modifier = widget.modifiers.toCompose(),
+                sheetState = sheetState,
+                properties = ModalBottomSheetProperties(
+                    shouldDismissOnBackPress = dismissActionId != null
+                )
+            ) {
+                if (widget.content.isNotEmpty()) {
+                    PadaukRenderer(widget.content.first())
+                }
+            }
+        }
 //        is AndroidUiNode.TextField -> {
 //            OutlinedTextField(
 //                value = widget.value,
@@ -754,32 +1028,95 @@ fun PadaukRenderer(widget: AndroidUiNode) {
     }
 }
 
+// Keyed children keep their composition state when siblings are added, removed or moved.
+@Composable
+private fun KeyedChild(node: AndroidUiNode, content: @Composable () -> Unit) {
+    val nodeKey = node.keyOrNull()
+    if (nodeKey != null) {
+        key(nodeKey) { content() }
+    } else {
+        content()
+    }
+}
+
+private fun AndroidUiNode.keyOrNull(): String? {
+    return when (this) {
+        is AndroidUiNode.Column -> this.key
+        is AndroidUiNode.Row -> this.key
+        is AndroidUiNode.Stack -> this.key
+        is AndroidUiNode.Spacer -> this.key
+        is AndroidUiNode.Divider -> this.key
+        is AndroidUiNode.Scroll -> this.key
+        is AndroidUiNode.Draggable -> this.key
+        is AndroidUiNode.LazyList -> this.key
+        is AndroidUiNode.Scaffold -> this.key
+        is AndroidUiNode.NavigationBar -> this.key
+        is AndroidUiNode.NavigationRail -> this.key
+        is AndroidUiNode.TabRow -> this.key
+        is AndroidUiNode.AppBar -> this.key
+        is AndroidUiNode.Text -> this.key
+        is AndroidUiNode.Button -> this.key
+        is AndroidUiNode.IconButton -> this.key
+        is AndroidUiNode.Card -> this.key
+        is AndroidUiNode.Checkbox -> this.key
+        is AndroidUiNode.Switch -> this.key
+        is AndroidUiNode.RadioButton -> this.key
+        is AndroidUiNode.Slider -> this.key
+        is AndroidUiNode.Chip -> this.key
+        is AndroidUiNode.Fab -> this.key
+        is AndroidUiNode.Image -> this.key
+        is AndroidUiNode.TextField -> this.key
+        is AndroidUiNode.Snackbar -> this.key
+        is AndroidUiNode.Dialog -> this.key
+        is AndroidUiNode.BottomSheet -> this.key
+    }
+}
+
 private fun AndroidUiNode.modifiersOrNull(): Modifiers? {
     return when (this) {
         is AndroidUiNode.Column -> this.modifiers
         is AndroidUiNode.Row -> this.modifiers
         is AndroidUiNode.Stack -> this.modifiers
+        is AndroidUiNode.Spacer -> this.modifiers
+        is AndroidUiNode.Divider -> this.modifiers
         is AndroidUiNode.Scroll -> this.modifiers
+        is AndroidUiNode.Draggable -> this.modifiers
+        is AndroidUiNode.LazyList -> this.modifiers
         is AndroidUiNode.Scaffold -> this.modifiers
+        is AndroidUiNode.NavigationBar -> this.modifiers
+        is AndroidUiNode.NavigationRail -> this.modifiers
+        is AndroidUiNode.TabRow -> this.modifiers
         is AndroidUiNode.AppBar -> this.modifiers
         is AndroidUiNode.Text -> this.modifiers
         is AndroidUiNode.Button -> this.modifiers
         is AndroidUiNode.IconButton -> this.modifiers
         is AndroidUiNode.Card -> this.modifiers
         is AndroidUiNode.Checkbox -> this.modifiers
+        is AndroidUiNode.Switch -> this.modifiers
+        is AndroidUiNode.RadioButton -> this.modifiers
+        is AndroidUiNode.Slider -> this.modifiers
         is AndroidUiNode.Chip -> this.modifiers
         is AndroidUiNode.Fab -> this.modifiers
         is AndroidUiNode.Image -> this.modifiers
+        is AndroidUiNode.TextField -> this.modifiers
+        is AndroidUiNode.Snackbar -> this.modifiers
+        is AndroidUiNode.Dialog -> this.modifiers
+        is AndroidUiNode.BottomSheet -> this.modifiers
     }
 }
 
-private fun iconVector(icon: IconType) = when (icon) {
+internal fun iconVector(icon: IconType) = when (icon) {
     IconType.ADD -> Icons.Filled.Add
     IconType.CLOSE -> Icons.Filled.Close
     IconType.MENU -> Icons.Filled.Menu
     IconType.FAVORITE -> Icons.Filled.Favorite
     IconType.SEARCH -> Icons.Filled.Search
     IconType.PERSON -> Icons.Filled.Person
+    IconType.HOME -> Icons.Filled.Home
+    IconType.SETTINGS -> Icons.Filled.Settings
+    IconType.NOTIFICATIONS -> Icons.Filled.Notifications
+    IconType.EMAIL -> Icons.Filled.Email
+    IconType.STAR -> Icons.Filled.Star
 }
 
 private fun extractBackActionId(node: AndroidUiNode): String? {
@@ -679,14 +679,44 @@ internal object IntegrityCheckingUniffiLib {
     }
     external fun uniffi_padauk_checksum_func_init_logging(
     ): Short
+    external fun uniffi_padauk_checksum_func_padauk_action_metrics(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_app_on_config_changed(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_app_on_low_memory(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_app_on_pause(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_app_on_resume(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_app_on_start(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_app_on_stop(
+    ): Short
     external fun uniffi_padauk_checksum_func_padauk_dispatch_action(
     ): Short
+    external fun uniffi_padauk_checksum_func_padauk_dispatch_event(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_lazy_items(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_lazy_keys(
+    ): Short
     external fun uniffi_padauk_checksum_func_padauk_nav_can_pop(
     ): Short
     external fun uniffi_padauk_checksum_func_padauk_nav_pop(
     ): Short
+    external fun uniffi_padauk_checksum_func_padauk_nav_restore_state(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_nav_save_state(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_open_deep_link(
+    ): Short
+    external fun uniffi_padauk_checksum_func_padauk_render_patch(
+    ): Short
     external fun uniffi_padauk_checksum_func_padauk_render_root(
     ): Short
+    external fun uniffi_padauk_checksum_func_padauk_reset_render_patch(
+    ): Short
     external fun uniffi_padauk_checksum_func_register_render_callback(
     ): Short
     external fun uniffi_padauk_checksum_func_register_resource_loader(
@@ -716,14 +746,44 @@ internal object UniffiLib {
     ): Unit
     external fun uniffi_padauk_fn_func_init_logging(uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    external fun uniffi_padauk_fn_func_padauk_action_metrics(uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    external fun uniffi_padauk_fn_func_padauk_app_on_config_changed(`newConfig`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_padauk_fn_func_padauk_app_on_low_memory(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_padauk_fn_func_padauk_app_on_pause(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_padauk_fn_func_padauk_app_on_resume(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_padauk_fn_func_padauk_app_on_start(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_padauk_fn_func_padauk_app_on_stop(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
     external fun uniffi_padauk_fn_func_padauk_dispatch_action(`id`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
+    external fun uniffi_padauk_fn_func_padauk_dispatch_event(`id`: RustBuffer.ByValue,`payload`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    external fun uniffi_padauk_fn_func_padauk_lazy_items(`listId`: RustBuffer.ByValue,`start`: Int,`count`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    external fun uniffi_padauk_fn_func_padauk_lazy_keys(`listId`: RustBuffer.ByValue,`start`: Int,`count`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     external fun uniffi_padauk_fn_func_padauk_nav_can_pop(uniffi_out_err: UniffiRustCallStatus, 
     ): Byte
     external fun uniffi_padauk_fn_func_padauk_nav_pop(uniffi_out_err: UniffiRustCallStatus, 
-    ): Unit
+    ): Byte
+    external fun uniffi_padauk_fn_func_padauk_nav_restore_state(`saved`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    external fun uniffi_padauk_fn_func_padauk_nav_save_state(uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    external fun uniffi_padauk_fn_func_padauk_open_deep_link(`uri`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    external fun uniffi_padauk_fn_func_padauk_render_patch(uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
     external fun uniffi_padauk_fn_func_padauk_render_root(uniffi_out_err: UniffiRustCallStatus, 
     ): RustBuffer.ByValue
+    external fun uniffi_padauk_fn_func_padauk_reset_render_patch(uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
     external fun uniffi_padauk_fn_func_register_render_callback(`callback`: Long,uniffi_out_err: UniffiRustCallStatus, 
     ): Unit
     external fun uniffi_padauk_fn_func_register_resource_loader(`loader`: Long,uniffi_out_err: UniffiRustCallStatus, 
@@ -850,18 +910,63 @@ private fun uniffiCheckApiChecksums(lib: IntegrityCheckingUniffiLib) {
     if (lib.uniffi_padauk_checksum_func_init_logging() != 51795.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_padauk_checksum_func_padauk_action_metrics() != 50621.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_app_on_config_changed() != 38540.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_app_on_low_memory() != 31984.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_app_on_pause() != 62825.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_app_on_resume() != 63947.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_app_on_start() != 20825.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_app_on_stop() != 48490.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_padauk_checksum_func_padauk_dispatch_action() != 6256.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_padauk_checksum_func_padauk_dispatch_event() != 26829.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_lazy_items() != 45946.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_lazy_keys() != 43893.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_padauk_checksum_func_padauk_nav_can_pop() != 9472.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
-    if (lib.uniffi_padauk_checksum_func_padauk_nav_pop() != 12885.toShort()) {
+    if (lib.uniffi_padauk_checksum_func_padauk_nav_pop() != 55615.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_nav_restore_state() != 21124.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_nav_save_state() != 51889.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_open_deep_link() != 55467.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_padauk_checksum_func_padauk_render_patch() != 15048.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
     if (lib.uniffi_padauk_checksum_func_padauk_render_root() != 49349.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
+    if (lib.uniffi_padauk_checksum_func_padauk_reset_render_patch() != 17017.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
     if (lib.uniffi_padauk_checksum_func_register_render_callback() != 17765.toShort()) {
         throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
     }
@@ -1021,6 +1126,52 @@ public object FfiConverterUByte: FfiConverter<UByte, Byte> {
     }
 }
 
+/**
+ * @suppress
+ */
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
 /**
  * @suppress
  */
@@ -1145,6 +1296,64 @@ public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
 
 
 
+data class ActionMetrics (
+    /**
+     * Actions currently registered.
+     */
+    var `live`: kotlin.ULong
+    , 
+    /**
+     * Actions registered by the last completed render pass.
+     */
+    var `registeredLastPass`: kotlin.ULong
+    , 
+    /**
+     * Actions dropped at the end of the last render pass.
+     */
+    var `droppedLastPass`: kotlin.ULong
+    , 
+    /**
+     * Number of render passes so far.
+     */
+    var `generation`: kotlin.ULong
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeActionMetrics: FfiConverterRustBuffer<ActionMetrics> {
+    override fun read(buf: ByteBuffer): ActionMetrics {
+        return ActionMetrics(
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterULong.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ActionMetrics) = (
+            FfiConverterULong.allocationSize(value.`live`) +
+            FfiConverterULong.allocationSize(value.`registeredLastPass`) +
+            FfiConverterULong.allocationSize(value.`droppedLastPass`) +
+            FfiConverterULong.allocationSize(value.`generation`)
+    )
+
+    override fun write(value: ActionMetrics, buf: ByteBuffer) {
+            FfiConverterULong.write(value.`live`, buf)
+            FfiConverterULong.write(value.`registeredLastPass`, buf)
+            FfiConverterULong.write(value.`droppedLastPass`, buf)
+            FfiConverterULong.write(value.`generation`, buf)
+    }
+}
+
+
+
 data class AppBarStyleOptions (
     var `containerColor`: ColorValue?
     , 
@@ -1186,6 +1395,63 @@ public object FfiConverterTypeAppBarStyleOptions: FfiConverterRustBuffer<AppBarS
 
 
 
+/**
+ * Device configuration relevant to the UI.
+ */
+data class AppConfig (
+    var `darkTheme`: kotlin.Boolean
+    , 
+    var `fontScale`: kotlin.Float
+    , 
+    /**
+     * BCP 47 tag, e.g. `"en-US"`.
+     */
+    var `locale`: kotlin.String
+    , 
+    var `screenWidthDp`: kotlin.UInt
+    , 
+    var `screenHeightDp`: kotlin.UInt
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAppConfig: FfiConverterRustBuffer<AppConfig> {
+    override fun read(buf: ByteBuffer): AppConfig {
+        return AppConfig(
+            FfiConverterBoolean.read(buf),
+            FfiConverterFloat.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AppConfig) = (
+            FfiConverterBoolean.allocationSize(value.`darkTheme`) +
+            FfiConverterFloat.allocationSize(value.`fontScale`) +
+            FfiConverterString.allocationSize(value.`locale`) +
+            FfiConverterUInt.allocationSize(value.`screenWidthDp`) +
+            FfiConverterUInt.allocationSize(value.`screenHeightDp`)
+    )
+
+    override fun write(value: AppConfig, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`darkTheme`, buf)
+            FfiConverterFloat.write(value.`fontScale`, buf)
+            FfiConverterString.write(value.`locale`, buf)
+            FfiConverterUInt.write(value.`screenWidthDp`, buf)
+            FfiConverterUInt.write(value.`screenHeightDp`, buf)
+    }
+}
+
+
+
 data class ButtonStyleOptions (
     var `enabled`: kotlin.Boolean
     , 
@@ -1374,6 +1640,42 @@ public object FfiConverterTypeChipStyleOptions: FfiConverterRustBuffer<ChipStyle
 
 
 
+data class DragDelta (
+    var `dx`: kotlin.Float
+    , 
+    var `dy`: kotlin.Float
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeDragDelta: FfiConverterRustBuffer<DragDelta> {
+    override fun read(buf: ByteBuffer): DragDelta {
+        return DragDelta(
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: DragDelta) = (
+            FfiConverterFloat.allocationSize(value.`dx`) +
+            FfiConverterFloat.allocationSize(value.`dy`)
+    )
+
+    override fun write(value: DragDelta, buf: ByteBuffer) {
+            FfiConverterFloat.write(value.`dx`, buf)
+            FfiConverterFloat.write(value.`dy`, buf)
+    }
+}
+
+
+
 data class FabOptions (
     var `shape`: ButtonShape
     , 
@@ -1487,6 +1789,8 @@ data class Modifiers (
     , 
     var `weightFill`: kotlin.Boolean?
     , 
+    var `align`: Alignment?
+    , 
     var `borderWidth`: kotlin.Float?
     , 
     var `borderColor`: ColorValue?
@@ -1504,6 +1808,8 @@ data class Modifiers (
     var `zIndex`: kotlin.Float?
     , 
     var `enabled`: kotlin.Boolean?
+    , 
+    var `testTag`: kotlin.String?
     
 ){
     
@@ -1528,6 +1834,7 @@ public object FfiConverterTypeModifiers: FfiConverterRustBuffer<Modifiers> {
             FfiConverterBoolean.read(buf),
             FfiConverterOptionalFloat.read(buf),
             FfiConverterOptionalBoolean.read(buf),
+            FfiConverterOptionalTypeAlignment.read(buf),
             FfiConverterOptionalFloat.read(buf),
             FfiConverterOptionalTypeColorValue.read(buf),
             FfiConverterOptionalFloat.read(buf),
@@ -1537,6 +1844,7 @@ public object FfiConverterTypeModifiers: FfiConverterRustBuffer<Modifiers> {
             FfiConverterOptionalFloat.read(buf),
             FfiConverterOptionalFloat.read(buf),
             FfiConverterOptionalBoolean.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
@@ -1551,6 +1859,7 @@ public object FfiConverterTypeModifiers: FfiConverterRustBuffer<Modifiers> {
             FfiConverterBoolean.allocationSize(value.`fillMaxHeight`) +
             FfiConverterOptionalFloat.allocationSize(value.`weight`) +
             FfiConverterOptionalBoolean.allocationSize(value.`weightFill`) +
+            FfiConverterOptionalTypeAlignment.allocationSize(value.`align`) +
             FfiConverterOptionalFloat.allocationSize(value.`borderWidth`) +
             FfiConverterOptionalTypeColorValue.allocationSize(value.`borderColor`) +
             FfiConverterOptionalFloat.allocationSize(value.`alpha`) +
@@ -1559,7 +1868,8 @@ public object FfiConverterTypeModifiers: FfiConverterRustBuffer<Modifiers> {
             FfiConverterOptionalFloat.allocationSize(value.`offsetX`) +
             FfiConverterOptionalFloat.allocationSize(value.`offsetY`) +
             FfiConverterOptionalFloat.allocationSize(value.`zIndex`) +
-            FfiConverterOptionalBoolean.allocationSize(value.`enabled`)
+            FfiConverterOptionalBoolean.allocationSize(value.`enabled`) +
+            FfiConverterOptionalString.allocationSize(value.`testTag`)
     )
 
     override fun write(value: Modifiers, buf: ByteBuffer) {
@@ -1573,6 +1883,7 @@ public object FfiConverterTypeModifiers: FfiConverterRustBuffer<Modifiers> {
             FfiConverterBoolean.write(value.`fillMaxHeight`, buf)
             FfiConverterOptionalFloat.write(value.`weight`, buf)
             FfiConverterOptionalBoolean.write(value.`weightFill`, buf)
+            FfiConverterOptionalTypeAlignment.write(value.`align`, buf)
             FfiConverterOptionalFloat.write(value.`borderWidth`, buf)
             FfiConverterOptionalTypeColorValue.write(value.`borderColor`, buf)
             FfiConverterOptionalFloat.write(value.`alpha`, buf)
@@ -1582,82 +1893,402 @@ public object FfiConverterTypeModifiers: FfiConverterRustBuffer<Modifiers> {
             FfiConverterOptionalFloat.write(value.`offsetY`, buf)
             FfiConverterOptionalFloat.write(value.`zIndex`, buf)
             FfiConverterOptionalBoolean.write(value.`enabled`, buf)
+            FfiConverterOptionalString.write(value.`testTag`, buf)
     }
 }
 
 
 
-sealed class AndroidUiNode {
+/**
+ * A destination of a navigation bar, navigation rail or tab row.
+ */
+data class NavItem (
+    var `label`: kotlin.String
+    , 
+    var `icon`: IconType?
+    , 
+    var `selectedIcon`: IconType?
+    , 
+    var `badge`: Badge?
+    , 
+    var `enabled`: kotlin.Boolean
+    
+){
     
-    data class Column(
-        val `children`: List<AndroidUiNode>, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
-        
-    {
-        
 
-        companion object
-    }
     
-    data class Row(
-        val `children`: List<AndroidUiNode>, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
-        
-    {
-        
+    companion object
+}
 
-        companion object
+/**
+ * @suppress
+ */
+public object FfiConverterTypeNavItem: FfiConverterRustBuffer<NavItem> {
+    override fun read(buf: ByteBuffer): NavItem {
+        return NavItem(
+            FfiConverterString.read(buf),
+            FfiConverterOptionalTypeIconType.read(buf),
+            FfiConverterOptionalTypeIconType.read(buf),
+            FfiConverterOptionalTypeBadge.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
     }
-    
-    data class Stack(
-        val `children`: List<AndroidUiNode>, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
-        
-    {
-        
 
-        companion object
+    override fun allocationSize(value: NavItem) = (
+            FfiConverterString.allocationSize(value.`label`) +
+            FfiConverterOptionalTypeIconType.allocationSize(value.`icon`) +
+            FfiConverterOptionalTypeIconType.allocationSize(value.`selectedIcon`) +
+            FfiConverterOptionalTypeBadge.allocationSize(value.`badge`) +
+            FfiConverterBoolean.allocationSize(value.`enabled`)
+    )
+
+    override fun write(value: NavItem, buf: ByteBuffer) {
+            FfiConverterString.write(value.`label`, buf)
+            FfiConverterOptionalTypeIconType.write(value.`icon`, buf)
+            FfiConverterOptionalTypeIconType.write(value.`selectedIcon`, buf)
+            FfiConverterOptionalTypeBadge.write(value.`badge`, buf)
+            FfiConverterBoolean.write(value.`enabled`, buf)
     }
+}
+
+
+
+data class ScrollPosition (
+    var `x`: kotlin.Float
+    , 
+    var `y`: kotlin.Float
+    
+){
     
-    data class Scroll(
-        val `child`: List<AndroidUiNode>, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
-        
-    {
-        
 
-        companion object
-    }
     
-    data class Scaffold(
-        val `appBar`: List<AndroidUiNode>, 
-        val `body`: List<AndroidUiNode>, 
-        val `floatingActionButton`: List<AndroidUiNode>, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
-        
-    {
-        
+    companion object
+}
 
-        companion object
+/**
+ * @suppress
+ */
+public object FfiConverterTypeScrollPosition: FfiConverterRustBuffer<ScrollPosition> {
+    override fun read(buf: ByteBuffer): ScrollPosition {
+        return ScrollPosition(
+            FfiConverterFloat.read(buf),
+            FfiConverterFloat.read(buf),
+        )
     }
-    
-    data class AppBar(
-        val `title`: kotlin.String, 
-        val `leading`: List<AndroidUiNode>, 
-        val `style`: AppBarStyle, 
-        val `options`: AppBarStyleOptions, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
-        
-    {
-        
 
-        companion object
+    override fun allocationSize(value: ScrollPosition) = (
+            FfiConverterFloat.allocationSize(value.`x`) +
+            FfiConverterFloat.allocationSize(value.`y`)
+    )
+
+    override fun write(value: ScrollPosition, buf: ByteBuffer) {
+            FfiConverterFloat.write(value.`x`, buf)
+            FfiConverterFloat.write(value.`y`, buf)
     }
-    
-    data class Text(
+}
+
+
+
+data class TextFieldOptions (
+    var `label`: kotlin.String?
+    , 
+    var `placeholder`: kotlin.String?
+    , 
+    var `leadingIcon`: IconType?
+    , 
+    var `trailingIcon`: IconType?
+    , 
+    var `errorText`: kotlin.String?
+    , 
+    var `secure`: kotlin.Boolean
+    , 
+    var `keyboardType`: KeyboardType
+    , 
+    var `imeAction`: ImeAction
+    , 
+    var `singleLine`: kotlin.Boolean
+    , 
+    var `enabled`: kotlin.Boolean
+    
+){
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeTextFieldOptions: FfiConverterRustBuffer<TextFieldOptions> {
+    override fun read(buf: ByteBuffer): TextFieldOptions {
+        return TextFieldOptions(
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalTypeIconType.read(buf),
+            FfiConverterOptionalTypeIconType.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterTypeKeyboardType.read(buf),
+            FfiConverterTypeImeAction.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: TextFieldOptions) = (
+            FfiConverterOptionalString.allocationSize(value.`label`) +
+            FfiConverterOptionalString.allocationSize(value.`placeholder`) +
+            FfiConverterOptionalTypeIconType.allocationSize(value.`leadingIcon`) +
+            FfiConverterOptionalTypeIconType.allocationSize(value.`trailingIcon`) +
+            FfiConverterOptionalString.allocationSize(value.`errorText`) +
+            FfiConverterBoolean.allocationSize(value.`secure`) +
+            FfiConverterTypeKeyboardType.allocationSize(value.`keyboardType`) +
+            FfiConverterTypeImeAction.allocationSize(value.`imeAction`) +
+            FfiConverterBoolean.allocationSize(value.`singleLine`) +
+            FfiConverterBoolean.allocationSize(value.`enabled`)
+    )
+
+    override fun write(value: TextFieldOptions, buf: ByteBuffer) {
+            FfiConverterOptionalString.write(value.`label`, buf)
+            FfiConverterOptionalString.write(value.`placeholder`, buf)
+            FfiConverterOptionalTypeIconType.write(value.`leadingIcon`, buf)
+            FfiConverterOptionalTypeIconType.write(value.`trailingIcon`, buf)
+            FfiConverterOptionalString.write(value.`errorText`, buf)
+            FfiConverterBoolean.write(value.`secure`, buf)
+            FfiConverterTypeKeyboardType.write(value.`keyboardType`, buf)
+            FfiConverterTypeImeAction.write(value.`imeAction`, buf)
+            FfiConverterBoolean.write(value.`singleLine`, buf)
+            FfiConverterBoolean.write(value.`enabled`, buf)
+    }
+}
+
+
+
+
+enum class Alignment {
+    
+    TOP_START,
+    TOP_CENTER,
+    TOP_END,
+    CENTER_START,
+    CENTER,
+    CENTER_END,
+    BOTTOM_START,
+    BOTTOM_CENTER,
+    BOTTOM_END;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAlignment: FfiConverterRustBuffer<Alignment> {
+    override fun read(buf: ByteBuffer) = try {
+        Alignment.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: Alignment) = 4UL
+
+    override fun write(value: Alignment, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class AndroidUiNode {
+    
+    data class Column(
+        val `children`: List<AndroidUiNode>, 
+        val `mainAxisAlignment`: MainAxisAlignment, 
+        val `crossAxisAlignment`: CrossAxisAlignment, 
+        val `spacing`: kotlin.Float, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Row(
+        val `children`: List<AndroidUiNode>, 
+        val `mainAxisAlignment`: MainAxisAlignment, 
+        val `crossAxisAlignment`: CrossAxisAlignment, 
+        val `spacing`: kotlin.Float, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Stack(
+        val `children`: List<AndroidUiNode>, 
+        val `alignment`: Alignment, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Spacer(
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Divider(
+        val `vertical`: kotlin.Boolean, 
+        val `thickness`: kotlin.Float?, 
+        val `color`: ColorValue?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Scroll(
+        val `child`: List<AndroidUiNode>, 
+        val `scrollActionId`: kotlin.String?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Draggable(
+        val `child`: List<AndroidUiNode>, 
+        val `actionId`: kotlin.String, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class LazyList(
+        val `listId`: kotlin.String, 
+        val `layout`: LazyLayout, 
+        val `itemCount`: kotlin.UInt, 
+        val `firstIndex`: kotlin.UInt, 
+        val `fetches`: kotlin.UInt, 
+        val `items`: List<AndroidUiNode>, 
+        val `keyed`: kotlin.Boolean, 
+        val `itemKeys`: List<kotlin.String>, 
+        val `stickyHeaders`: List<kotlin.UInt>, 
+        val `contentPadding`: kotlin.Float, 
+        val `spacing`: kotlin.Float, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Scaffold(
+        val `appBar`: List<AndroidUiNode>, 
+        val `body`: List<AndroidUiNode>, 
+        val `floatingActionButton`: List<AndroidUiNode>, 
+        val `bottomBar`: List<AndroidUiNode>, 
+        val `snackbar`: List<AndroidUiNode>, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class NavigationBar(
+        val `items`: List<NavItem>, 
+        val `selected`: kotlin.UInt, 
+        val `actionId`: kotlin.String, 
+        val `alwaysShowLabels`: kotlin.Boolean, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class NavigationRail(
+        val `items`: List<NavItem>, 
+        val `selected`: kotlin.UInt, 
+        val `actionId`: kotlin.String, 
+        val `alwaysShowLabels`: kotlin.Boolean, 
+        val `header`: List<AndroidUiNode>, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class TabRow(
+        val `items`: List<NavItem>, 
+        val `selected`: kotlin.UInt, 
+        val `actionId`: kotlin.String, 
+        val `scrollable`: kotlin.Boolean, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class AppBar(
+        val `title`: kotlin.String, 
+        val `leading`: List<AndroidUiNode>, 
+        val `style`: AppBarStyle, 
+        val `options`: AppBarStyleOptions, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Text(
         val `text`: kotlin.String, 
         val `spSize`: kotlin.Float, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1670,7 +2301,8 @@ sealed class AndroidUiNode {
         val `content`: List<AndroidUiNode>, 
         val `style`: ButtonStyle, 
         val `options`: ButtonStyleOptions, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1683,7 +2315,8 @@ sealed class AndroidUiNode {
         val `icon`: IconType, 
         val `style`: IconButtonStyle, 
         val `options`: IconButtonOptions, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1696,7 +2329,8 @@ sealed class AndroidUiNode {
         val `style`: CardStyle, 
         val `actionId`: kotlin.String?, 
         val `options`: CardStyleOptions, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1711,7 +2345,61 @@ sealed class AndroidUiNode {
         val `colorChecked`: ColorValue?, 
         val `colorUnchecked`: ColorValue?, 
         val `colorCheckmark`: ColorValue?, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Switch(
+        val `checked`: kotlin.Boolean, 
+        val `actionId`: kotlin.String, 
+        val `enabled`: kotlin.Boolean, 
+        val `colorCheckedThumb`: ColorValue?, 
+        val `colorCheckedTrack`: ColorValue?, 
+        val `colorUncheckedThumb`: ColorValue?, 
+        val `colorUncheckedTrack`: ColorValue?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class RadioButton(
+        val `selected`: kotlin.Boolean, 
+        val `label`: kotlin.String?, 
+        val `actionId`: kotlin.String, 
+        val `enabled`: kotlin.Boolean, 
+        val `colorSelected`: ColorValue?, 
+        val `colorUnselected`: ColorValue?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Slider(
+        val `value`: kotlin.Float, 
+        val `endValue`: kotlin.Float?, 
+        val `min`: kotlin.Float, 
+        val `max`: kotlin.Float, 
+        val `steps`: kotlin.UInt, 
+        val `actionId`: kotlin.String, 
+        val `enabled`: kotlin.Boolean, 
+        val `colorThumb`: ColorValue?, 
+        val `colorActiveTrack`: ColorValue?, 
+        val `colorInactiveTrack`: ColorValue?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1728,7 +2416,8 @@ sealed class AndroidUiNode {
         val `trailingIcon`: IconType?, 
         val `closeActionId`: kotlin.String?, 
         val `options`: ChipStyleOptions, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1742,7 +2431,8 @@ sealed class AndroidUiNode {
         val `style`: FabStyle, 
         val `label`: kotlin.String?, 
         val `options`: FabOptions, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1753,7 +2443,64 @@ sealed class AndroidUiNode {
     data class Image(
         val `source`: ImageSource, 
         val `fit`: BoxFit, 
-        val `modifiers`: Modifiers) : AndroidUiNode()
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class TextField(
+        val `value`: kotlin.String, 
+        val `style`: TextFieldStyle, 
+        val `options`: TextFieldOptions, 
+        val `changeActionId`: kotlin.String, 
+        val `submitActionId`: kotlin.String?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Snackbar(
+        val `messageId`: kotlin.ULong, 
+        val `message`: kotlin.String, 
+        val `actionLabel`: kotlin.String?, 
+        val `actionId`: kotlin.String?, 
+        val `dismissActionId`: kotlin.String, 
+        val `duration`: SnackbarDuration, 
+        val `dismissButton`: kotlin.Boolean, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Dialog(
+        val `content`: List<AndroidUiNode>, 
+        val `dismissActionId`: kotlin.String?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class BottomSheet(
+        val `content`: List<AndroidUiNode>, 
+        val `dismissActionId`: kotlin.String?, 
+        val `modifiers`: Modifiers, 
+        val `key`: kotlin.String?) : AndroidUiNode()
         
     {
         
@@ -1774,60 +2521,137 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
         return when(buf.getInt()) {
             1 -> AndroidUiNode.Column(
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterTypeMainAxisAlignment.read(buf),
+                FfiConverterTypeCrossAxisAlignment.read(buf),
+                FfiConverterFloat.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
             2 -> AndroidUiNode.Row(
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterTypeMainAxisAlignment.read(buf),
+                FfiConverterTypeCrossAxisAlignment.read(buf),
+                FfiConverterFloat.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
             3 -> AndroidUiNode.Stack(
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterTypeAlignment.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            4 -> AndroidUiNode.Spacer(
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            5 -> AndroidUiNode.Divider(
+                FfiConverterBoolean.read(buf),
+                FfiConverterOptionalFloat.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            6 -> AndroidUiNode.Scroll(
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterOptionalString.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            4 -> AndroidUiNode.Scroll(
+            7 -> AndroidUiNode.Draggable(
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterString.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            5 -> AndroidUiNode.Scaffold(
+            8 -> AndroidUiNode.LazyList(
+                FfiConverterString.read(buf),
+                FfiConverterTypeLazyLayout.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterSequenceString.read(buf),
+                FfiConverterSequenceUInt.read(buf),
+                FfiConverterFloat.read(buf),
+                FfiConverterFloat.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            9 -> AndroidUiNode.Scaffold(
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            10 -> AndroidUiNode.NavigationBar(
+                FfiConverterSequenceTypeNavItem.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            11 -> AndroidUiNode.NavigationRail(
+                FfiConverterSequenceTypeNavItem.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            12 -> AndroidUiNode.TabRow(
+                FfiConverterSequenceTypeNavItem.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            6 -> AndroidUiNode.AppBar(
+            13 -> AndroidUiNode.AppBar(
                 FfiConverterString.read(buf),
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
                 FfiConverterTypeAppBarStyle.read(buf),
                 FfiConverterTypeAppBarStyleOptions.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            7 -> AndroidUiNode.Text(
+            14 -> AndroidUiNode.Text(
                 FfiConverterString.read(buf),
                 FfiConverterFloat.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            8 -> AndroidUiNode.Button(
+            15 -> AndroidUiNode.Button(
                 FfiConverterString.read(buf),
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
                 FfiConverterTypeButtonStyle.read(buf),
                 FfiConverterTypeButtonStyleOptions.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            9 -> AndroidUiNode.IconButton(
+            16 -> AndroidUiNode.IconButton(
                 FfiConverterString.read(buf),
                 FfiConverterTypeIconType.read(buf),
                 FfiConverterTypeIconButtonStyle.read(buf),
                 FfiConverterTypeIconButtonOptions.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            10 -> AndroidUiNode.Card(
+            17 -> AndroidUiNode.Card(
                 FfiConverterSequenceTypeAndroidUiNode.read(buf),
                 FfiConverterTypeCardStyle.read(buf),
                 FfiConverterOptionalString.read(buf),
                 FfiConverterTypeCardStyleOptions.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            11 -> AndroidUiNode.Checkbox(
+            18 -> AndroidUiNode.Checkbox(
                 FfiConverterBoolean.read(buf),
                 FfiConverterString.read(buf),
                 FfiConverterBoolean.read(buf),
@@ -1835,8 +2659,44 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 FfiConverterOptionalTypeColorValue.read(buf),
                 FfiConverterOptionalTypeColorValue.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            12 -> AndroidUiNode.Chip(
+            19 -> AndroidUiNode.Switch(
+                FfiConverterBoolean.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            20 -> AndroidUiNode.RadioButton(
+                FfiConverterBoolean.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            21 -> AndroidUiNode.Slider(
+                FfiConverterFloat.read(buf),
+                FfiConverterOptionalFloat.read(buf),
+                FfiConverterFloat.read(buf),
+                FfiConverterFloat.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterOptionalTypeColorValue.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            22 -> AndroidUiNode.Chip(
                 FfiConverterString.read(buf),
                 FfiConverterTypeChipStyle.read(buf),
                 FfiConverterBoolean.read(buf),
@@ -1846,65 +2706,200 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 FfiConverterOptionalString.read(buf),
                 FfiConverterTypeChipStyleOptions.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            13 -> AndroidUiNode.Fab(
+            23 -> AndroidUiNode.Fab(
                 FfiConverterString.read(buf),
                 FfiConverterTypeIconType.read(buf),
                 FfiConverterTypeFabStyle.read(buf),
                 FfiConverterOptionalString.read(buf),
                 FfiConverterTypeFabOptions.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
-            14 -> AndroidUiNode.Image(
+            24 -> AndroidUiNode.Image(
                 FfiConverterTypeImageSource.read(buf),
                 FfiConverterTypeBoxFit.read(buf),
                 FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            25 -> AndroidUiNode.TextField(
+                FfiConverterString.read(buf),
+                FfiConverterTypeTextFieldStyle.read(buf),
+                FfiConverterTypeTextFieldOptions.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            26 -> AndroidUiNode.Snackbar(
+                FfiConverterULong.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterString.read(buf),
+                FfiConverterTypeSnackbarDuration.read(buf),
+                FfiConverterBoolean.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            27 -> AndroidUiNode.Dialog(
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
+                )
+            28 -> AndroidUiNode.BottomSheet(
+                FfiConverterSequenceTypeAndroidUiNode.read(buf),
+                FfiConverterOptionalString.read(buf),
+                FfiConverterTypeModifiers.read(buf),
+                FfiConverterOptionalString.read(buf),
                 )
             else -> throw RuntimeException("invalid enum value, something is very wrong!!")
         }
-    }
-
-    override fun allocationSize(value: AndroidUiNode) = when(value) {
-        is AndroidUiNode.Column -> {
+    }
+
+    override fun allocationSize(value: AndroidUiNode) = when(value) {
+        is AndroidUiNode.Column -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`children`)
+                + FfiConverterTypeMainAxisAlignment.allocationSize(value.`mainAxisAlignment`)
+                + FfiConverterTypeCrossAxisAlignment.allocationSize(value.`crossAxisAlignment`)
+                + FfiConverterFloat.allocationSize(value.`spacing`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Row -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`children`)
+                + FfiConverterTypeMainAxisAlignment.allocationSize(value.`mainAxisAlignment`)
+                + FfiConverterTypeCrossAxisAlignment.allocationSize(value.`crossAxisAlignment`)
+                + FfiConverterFloat.allocationSize(value.`spacing`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Stack -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`children`)
+                + FfiConverterTypeAlignment.allocationSize(value.`alignment`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Spacer -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Divider -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterBoolean.allocationSize(value.`vertical`)
+                + FfiConverterOptionalFloat.allocationSize(value.`thickness`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`color`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Scroll -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`child`)
+                + FfiConverterOptionalString.allocationSize(value.`scrollActionId`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Draggable -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`child`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.LazyList -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`children`)
+                + FfiConverterString.allocationSize(value.`listId`)
+                + FfiConverterTypeLazyLayout.allocationSize(value.`layout`)
+                + FfiConverterUInt.allocationSize(value.`itemCount`)
+                + FfiConverterUInt.allocationSize(value.`firstIndex`)
+                + FfiConverterUInt.allocationSize(value.`fetches`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`items`)
+                + FfiConverterBoolean.allocationSize(value.`keyed`)
+                + FfiConverterSequenceString.allocationSize(value.`itemKeys`)
+                + FfiConverterSequenceUInt.allocationSize(value.`stickyHeaders`)
+                + FfiConverterFloat.allocationSize(value.`contentPadding`)
+                + FfiConverterFloat.allocationSize(value.`spacing`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
-        is AndroidUiNode.Row -> {
+        is AndroidUiNode.Scaffold -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`children`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`appBar`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`body`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`floatingActionButton`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`bottomBar`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`snackbar`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
-        is AndroidUiNode.Stack -> {
+        is AndroidUiNode.NavigationBar -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`children`)
+                + FfiConverterSequenceTypeNavItem.allocationSize(value.`items`)
+                + FfiConverterUInt.allocationSize(value.`selected`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterBoolean.allocationSize(value.`alwaysShowLabels`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
-        is AndroidUiNode.Scroll -> {
+        is AndroidUiNode.NavigationRail -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`child`)
+                + FfiConverterSequenceTypeNavItem.allocationSize(value.`items`)
+                + FfiConverterUInt.allocationSize(value.`selected`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterBoolean.allocationSize(value.`alwaysShowLabels`)
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`header`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
-        is AndroidUiNode.Scaffold -> {
+        is AndroidUiNode.TabRow -> {
             // Add the size for the Int that specifies the variant plus the size needed for all fields
             (
                 4UL
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`appBar`)
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`body`)
-                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`floatingActionButton`)
+                + FfiConverterSequenceTypeNavItem.allocationSize(value.`items`)
+                + FfiConverterUInt.allocationSize(value.`selected`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterBoolean.allocationSize(value.`scrollable`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.AppBar -> {
@@ -1916,6 +2911,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterTypeAppBarStyle.allocationSize(value.`style`)
                 + FfiConverterTypeAppBarStyleOptions.allocationSize(value.`options`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Text -> {
@@ -1925,6 +2921,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterString.allocationSize(value.`text`)
                 + FfiConverterFloat.allocationSize(value.`spSize`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Button -> {
@@ -1936,6 +2933,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterTypeButtonStyle.allocationSize(value.`style`)
                 + FfiConverterTypeButtonStyleOptions.allocationSize(value.`options`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.IconButton -> {
@@ -1947,6 +2945,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterTypeIconButtonStyle.allocationSize(value.`style`)
                 + FfiConverterTypeIconButtonOptions.allocationSize(value.`options`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Card -> {
@@ -1958,6 +2957,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterOptionalString.allocationSize(value.`actionId`)
                 + FfiConverterTypeCardStyleOptions.allocationSize(value.`options`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Checkbox -> {
@@ -1971,6 +2971,54 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorUnchecked`)
                 + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorCheckmark`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Switch -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterBoolean.allocationSize(value.`checked`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterBoolean.allocationSize(value.`enabled`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorCheckedThumb`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorCheckedTrack`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorUncheckedThumb`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorUncheckedTrack`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.RadioButton -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterBoolean.allocationSize(value.`selected`)
+                + FfiConverterOptionalString.allocationSize(value.`label`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterBoolean.allocationSize(value.`enabled`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorSelected`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorUnselected`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Slider -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`value`)
+                + FfiConverterOptionalFloat.allocationSize(value.`endValue`)
+                + FfiConverterFloat.allocationSize(value.`min`)
+                + FfiConverterFloat.allocationSize(value.`max`)
+                + FfiConverterUInt.allocationSize(value.`steps`)
+                + FfiConverterString.allocationSize(value.`actionId`)
+                + FfiConverterBoolean.allocationSize(value.`enabled`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorThumb`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorActiveTrack`)
+                + FfiConverterOptionalTypeColorValue.allocationSize(value.`colorInactiveTrack`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Chip -> {
@@ -1986,6 +3034,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterOptionalString.allocationSize(value.`closeActionId`)
                 + FfiConverterTypeChipStyleOptions.allocationSize(value.`options`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Fab -> {
@@ -1998,6 +3047,7 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterOptionalString.allocationSize(value.`label`)
                 + FfiConverterTypeFabOptions.allocationSize(value.`options`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
         is AndroidUiNode.Image -> {
@@ -2007,6 +3057,55 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 + FfiConverterTypeImageSource.allocationSize(value.`source`)
                 + FfiConverterTypeBoxFit.allocationSize(value.`fit`)
                 + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.TextField -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`value`)
+                + FfiConverterTypeTextFieldStyle.allocationSize(value.`style`)
+                + FfiConverterTypeTextFieldOptions.allocationSize(value.`options`)
+                + FfiConverterString.allocationSize(value.`changeActionId`)
+                + FfiConverterOptionalString.allocationSize(value.`submitActionId`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Snackbar -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterULong.allocationSize(value.`messageId`)
+                + FfiConverterString.allocationSize(value.`message`)
+                + FfiConverterOptionalString.allocationSize(value.`actionLabel`)
+                + FfiConverterOptionalString.allocationSize(value.`actionId`)
+                + FfiConverterString.allocationSize(value.`dismissActionId`)
+                + FfiConverterTypeSnackbarDuration.allocationSize(value.`duration`)
+                + FfiConverterBoolean.allocationSize(value.`dismissButton`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.Dialog -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`content`)
+                + FfiConverterOptionalString.allocationSize(value.`dismissActionId`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
+            )
+        }
+        is AndroidUiNode.BottomSheet -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceTypeAndroidUiNode.allocationSize(value.`content`)
+                + FfiConverterOptionalString.allocationSize(value.`dismissActionId`)
+                + FfiConverterTypeModifiers.allocationSize(value.`modifiers`)
+                + FfiConverterOptionalString.allocationSize(value.`key`)
             )
         }
     }
@@ -2016,80 +3115,171 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
             is AndroidUiNode.Column -> {
                 buf.putInt(1)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`children`, buf)
+                FfiConverterTypeMainAxisAlignment.write(value.`mainAxisAlignment`, buf)
+                FfiConverterTypeCrossAxisAlignment.write(value.`crossAxisAlignment`, buf)
+                FfiConverterFloat.write(value.`spacing`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Row -> {
                 buf.putInt(2)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`children`, buf)
+                FfiConverterTypeMainAxisAlignment.write(value.`mainAxisAlignment`, buf)
+                FfiConverterTypeCrossAxisAlignment.write(value.`crossAxisAlignment`, buf)
+                FfiConverterFloat.write(value.`spacing`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Stack -> {
                 buf.putInt(3)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`children`, buf)
+                FfiConverterTypeAlignment.write(value.`alignment`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
-            is AndroidUiNode.Scroll -> {
+            is AndroidUiNode.Spacer -> {
                 buf.putInt(4)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Divider -> {
+                buf.putInt(5)
+                FfiConverterBoolean.write(value.`vertical`, buf)
+                FfiConverterOptionalFloat.write(value.`thickness`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`color`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Scroll -> {
+                buf.putInt(6)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`child`, buf)
+                FfiConverterOptionalString.write(value.`scrollActionId`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Draggable -> {
+                buf.putInt(7)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`child`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.LazyList -> {
+                buf.putInt(8)
+                FfiConverterString.write(value.`listId`, buf)
+                FfiConverterTypeLazyLayout.write(value.`layout`, buf)
+                FfiConverterUInt.write(value.`itemCount`, buf)
+                FfiConverterUInt.write(value.`firstIndex`, buf)
+                FfiConverterUInt.write(value.`fetches`, buf)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`items`, buf)
+                FfiConverterBoolean.write(value.`keyed`, buf)
+                FfiConverterSequenceString.write(value.`itemKeys`, buf)
+                FfiConverterSequenceUInt.write(value.`stickyHeaders`, buf)
+                FfiConverterFloat.write(value.`contentPadding`, buf)
+                FfiConverterFloat.write(value.`spacing`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Scaffold -> {
-                buf.putInt(5)
+                buf.putInt(9)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`appBar`, buf)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`body`, buf)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`floatingActionButton`, buf)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`bottomBar`, buf)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`snackbar`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.NavigationBar -> {
+                buf.putInt(10)
+                FfiConverterSequenceTypeNavItem.write(value.`items`, buf)
+                FfiConverterUInt.write(value.`selected`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterBoolean.write(value.`alwaysShowLabels`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.NavigationRail -> {
+                buf.putInt(11)
+                FfiConverterSequenceTypeNavItem.write(value.`items`, buf)
+                FfiConverterUInt.write(value.`selected`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterBoolean.write(value.`alwaysShowLabels`, buf)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`header`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.TabRow -> {
+                buf.putInt(12)
+                FfiConverterSequenceTypeNavItem.write(value.`items`, buf)
+                FfiConverterUInt.write(value.`selected`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterBoolean.write(value.`scrollable`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.AppBar -> {
-                buf.putInt(6)
+                buf.putInt(13)
                 FfiConverterString.write(value.`title`, buf)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`leading`, buf)
                 FfiConverterTypeAppBarStyle.write(value.`style`, buf)
                 FfiConverterTypeAppBarStyleOptions.write(value.`options`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Text -> {
-                buf.putInt(7)
+                buf.putInt(14)
                 FfiConverterString.write(value.`text`, buf)
                 FfiConverterFloat.write(value.`spSize`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Button -> {
-                buf.putInt(8)
+                buf.putInt(15)
                 FfiConverterString.write(value.`actionId`, buf)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`content`, buf)
                 FfiConverterTypeButtonStyle.write(value.`style`, buf)
                 FfiConverterTypeButtonStyleOptions.write(value.`options`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.IconButton -> {
-                buf.putInt(9)
+                buf.putInt(16)
                 FfiConverterString.write(value.`actionId`, buf)
                 FfiConverterTypeIconType.write(value.`icon`, buf)
                 FfiConverterTypeIconButtonStyle.write(value.`style`, buf)
                 FfiConverterTypeIconButtonOptions.write(value.`options`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Card -> {
-                buf.putInt(10)
+                buf.putInt(17)
                 FfiConverterSequenceTypeAndroidUiNode.write(value.`children`, buf)
                 FfiConverterTypeCardStyle.write(value.`style`, buf)
                 FfiConverterOptionalString.write(value.`actionId`, buf)
                 FfiConverterTypeCardStyleOptions.write(value.`options`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Checkbox -> {
-                buf.putInt(11)
+                buf.putInt(18)
                 FfiConverterBoolean.write(value.`checked`, buf)
                 FfiConverterString.write(value.`actionId`, buf)
                 FfiConverterBoolean.write(value.`enabled`, buf)
@@ -2097,10 +3287,52 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 FfiConverterOptionalTypeColorValue.write(value.`colorUnchecked`, buf)
                 FfiConverterOptionalTypeColorValue.write(value.`colorCheckmark`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Switch -> {
+                buf.putInt(19)
+                FfiConverterBoolean.write(value.`checked`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterBoolean.write(value.`enabled`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorCheckedThumb`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorCheckedTrack`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorUncheckedThumb`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorUncheckedTrack`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.RadioButton -> {
+                buf.putInt(20)
+                FfiConverterBoolean.write(value.`selected`, buf)
+                FfiConverterOptionalString.write(value.`label`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterBoolean.write(value.`enabled`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorSelected`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorUnselected`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Slider -> {
+                buf.putInt(21)
+                FfiConverterFloat.write(value.`value`, buf)
+                FfiConverterOptionalFloat.write(value.`endValue`, buf)
+                FfiConverterFloat.write(value.`min`, buf)
+                FfiConverterFloat.write(value.`max`, buf)
+                FfiConverterUInt.write(value.`steps`, buf)
+                FfiConverterString.write(value.`actionId`, buf)
+                FfiConverterBoolean.write(value.`enabled`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorThumb`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorActiveTrack`, buf)
+                FfiConverterOptionalTypeColorValue.write(value.`colorInactiveTrack`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Chip -> {
-                buf.putInt(12)
+                buf.putInt(22)
                 FfiConverterString.write(value.`label`, buf)
                 FfiConverterTypeChipStyle.write(value.`style`, buf)
                 FfiConverterBoolean.write(value.`selected`, buf)
@@ -2110,23 +3342,66 @@ public object FfiConverterTypeAndroidUiNode : FfiConverterRustBuffer<AndroidUiNo
                 FfiConverterOptionalString.write(value.`closeActionId`, buf)
                 FfiConverterTypeChipStyleOptions.write(value.`options`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Fab -> {
-                buf.putInt(13)
+                buf.putInt(23)
                 FfiConverterString.write(value.`actionId`, buf)
                 FfiConverterTypeIconType.write(value.`icon`, buf)
                 FfiConverterTypeFabStyle.write(value.`style`, buf)
                 FfiConverterOptionalString.write(value.`label`, buf)
                 FfiConverterTypeFabOptions.write(value.`options`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
             is AndroidUiNode.Image -> {
-                buf.putInt(14)
+                buf.putInt(24)
                 FfiConverterTypeImageSource.write(value.`source`, buf)
                 FfiConverterTypeBoxFit.write(value.`fit`, buf)
                 FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.TextField -> {
+                buf.putInt(25)
+                FfiConverterString.write(value.`value`, buf)
+                FfiConverterTypeTextFieldStyle.write(value.`style`, buf)
+                FfiConverterTypeTextFieldOptions.write(value.`options`, buf)
+                FfiConverterString.write(value.`changeActionId`, buf)
+                FfiConverterOptionalString.write(value.`submitActionId`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Snackbar -> {
+                buf.putInt(26)
+                FfiConverterULong.write(value.`messageId`, buf)
+                FfiConverterString.write(value.`message`, buf)
+                FfiConverterOptionalString.write(value.`actionLabel`, buf)
+                FfiConverterOptionalString.write(value.`actionId`, buf)
+                FfiConverterString.write(value.`dismissActionId`, buf)
+                FfiConverterTypeSnackbarDuration.write(value.`duration`, buf)
+                FfiConverterBoolean.write(value.`dismissButton`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.Dialog -> {
+                buf.putInt(27)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`content`, buf)
+                FfiConverterOptionalString.write(value.`dismissActionId`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
+                Unit
+            }
+            is AndroidUiNode.BottomSheet -> {
+                buf.putInt(28)
+                FfiConverterSequenceTypeAndroidUiNode.write(value.`content`, buf)
+                FfiConverterOptionalString.write(value.`dismissActionId`, buf)
+                FfiConverterTypeModifiers.write(value.`modifiers`, buf)
+                FfiConverterOptionalString.write(value.`key`, buf)
                 Unit
             }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
@@ -2158,10 +3433,150 @@ public object FfiConverterTypeAppBarStyle: FfiConverterRustBuffer<AppBarStyle> {
         throw RuntimeException("invalid enum value, something is very wrong!!", e)
     }
 
-    override fun allocationSize(value: AppBarStyle) = 4UL
-
-    override fun write(value: AppBarStyle, buf: ByteBuffer) {
-        buf.putInt(value.ordinal + 1)
+    override fun allocationSize(value: AppBarStyle) = 4UL
+
+    override fun write(value: AppBarStyle, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class AppLifecycle {
+    
+    /**
+     * The activity hasn't been started yet.
+     */
+    CREATED,
+    /**
+     * Visible, but not in the foreground.
+     */
+    STARTED,
+    /**
+     * In the foreground and receiving input.
+     */
+    RESUMED,
+    /**
+     * Partly hidden or losing focus.
+     */
+    PAUSED,
+    /**
+     * No longer visible (backgrounded).
+     */
+    STOPPED;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeAppLifecycle: FfiConverterRustBuffer<AppLifecycle> {
+    override fun read(buf: ByteBuffer) = try {
+        AppLifecycle.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: AppLifecycle) = 4UL
+
+    override fun write(value: AppLifecycle, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class Badge {
+    
+    object Dot : Badge()
+    
+    
+    data class Count(
+        val `count`: kotlin.UInt) : Badge()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Text(
+        val `text`: kotlin.String) : Badge()
+        
+    {
+        
+
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeBadge : FfiConverterRustBuffer<Badge>{
+    override fun read(buf: ByteBuffer): Badge {
+        return when(buf.getInt()) {
+            1 -> Badge.Dot
+            2 -> Badge.Count(
+                FfiConverterUInt.read(buf),
+                )
+            3 -> Badge.Text(
+                FfiConverterString.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: Badge) = when(value) {
+        is Badge.Dot -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is Badge.Count -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterUInt.allocationSize(value.`count`)
+            )
+        }
+        is Badge.Text -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`text`)
+            )
+        }
+    }
+
+    override fun write(value: Badge, buf: ByteBuffer) {
+        when(value) {
+            is Badge.Dot -> {
+                buf.putInt(1)
+                Unit
+            }
+            is Badge.Count -> {
+                buf.putInt(2)
+                FfiConverterUInt.write(value.`count`, buf)
+                Unit
+            }
+            is Badge.Text -> {
+                buf.putInt(3)
+                FfiConverterString.write(value.`text`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
 }
 
@@ -2483,6 +3898,243 @@ public object FfiConverterTypeColorValue : FfiConverterRustBuffer<ColorValue>{
 
 
 
+enum class CrossAxisAlignment {
+    
+    START,
+    CENTER,
+    END;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeCrossAxisAlignment: FfiConverterRustBuffer<CrossAxisAlignment> {
+    override fun read(buf: ByteBuffer) = try {
+        CrossAxisAlignment.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: CrossAxisAlignment) = 4UL
+
+    override fun write(value: CrossAxisAlignment, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * What an event carries from the renderer to its handler, sent with
+ * `padauk_dispatch_event`.
+ */
+sealed class EventPayload {
+    
+    data class Text(
+        val `value`: kotlin.String) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Value(
+        val `value`: kotlin.Float) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Range(
+        val `start`: kotlin.Float, 
+        val `end`: kotlin.Float) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Toggle(
+        val `checked`: kotlin.Boolean) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Selection(
+        val `index`: kotlin.UInt) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Scroll(
+        val `position`: ScrollPosition) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+    data class Drag(
+        val `delta`: DragDelta) : EventPayload()
+        
+    {
+        
+
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeEventPayload : FfiConverterRustBuffer<EventPayload>{
+    override fun read(buf: ByteBuffer): EventPayload {
+        return when(buf.getInt()) {
+            1 -> EventPayload.Text(
+                FfiConverterString.read(buf),
+                )
+            2 -> EventPayload.Value(
+                FfiConverterFloat.read(buf),
+                )
+            3 -> EventPayload.Range(
+                FfiConverterFloat.read(buf),
+                FfiConverterFloat.read(buf),
+                )
+            4 -> EventPayload.Toggle(
+                FfiConverterBoolean.read(buf),
+                )
+            5 -> EventPayload.Selection(
+                FfiConverterUInt.read(buf),
+                )
+            6 -> EventPayload.Scroll(
+                FfiConverterTypeScrollPosition.read(buf),
+                )
+            7 -> EventPayload.Drag(
+                FfiConverterTypeDragDelta.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: EventPayload) = when(value) {
+        is EventPayload.Text -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterString.allocationSize(value.`value`)
+            )
+        }
+        is EventPayload.Value -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`value`)
+            )
+        }
+        is EventPayload.Range -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`start`)
+                + FfiConverterFloat.allocationSize(value.`end`)
+            )
+        }
+        is EventPayload.Toggle -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterBoolean.allocationSize(value.`checked`)
+            )
+        }
+        is EventPayload.Selection -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterUInt.allocationSize(value.`index`)
+            )
+        }
+        is EventPayload.Scroll -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeScrollPosition.allocationSize(value.`position`)
+            )
+        }
+        is EventPayload.Drag -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeDragDelta.allocationSize(value.`delta`)
+            )
+        }
+    }
+
+    override fun write(value: EventPayload, buf: ByteBuffer) {
+        when(value) {
+            is EventPayload.Text -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`value`, buf)
+                Unit
+            }
+            is EventPayload.Value -> {
+                buf.putInt(2)
+                FfiConverterFloat.write(value.`value`, buf)
+                Unit
+            }
+            is EventPayload.Range -> {
+                buf.putInt(3)
+                FfiConverterFloat.write(value.`start`, buf)
+                FfiConverterFloat.write(value.`end`, buf)
+                Unit
+            }
+            is EventPayload.Toggle -> {
+                buf.putInt(4)
+                FfiConverterBoolean.write(value.`checked`, buf)
+                Unit
+            }
+            is EventPayload.Selection -> {
+                buf.putInt(5)
+                FfiConverterUInt.write(value.`index`, buf)
+                Unit
+            }
+            is EventPayload.Scroll -> {
+                buf.putInt(6)
+                FfiConverterTypeScrollPosition.write(value.`position`, buf)
+                Unit
+            }
+            is EventPayload.Drag -> {
+                buf.putInt(7)
+                FfiConverterTypeDragDelta.write(value.`delta`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+
 enum class FabStyle {
     
     SMALL,
@@ -2514,6 +4166,90 @@ public object FfiConverterTypeFabStyle: FfiConverterRustBuffer<FabStyle> {
 
 
 
+sealed class GridCells {
+    
+    /**
+     * This many columns.
+     */
+    data class Fixed(
+        val `count`: kotlin.UInt) : GridCells()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * As many columns as fit, each at least `min_size` dp wide.
+     */
+    data class Adaptive(
+        val `minSize`: kotlin.Float) : GridCells()
+        
+    {
+        
+
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeGridCells : FfiConverterRustBuffer<GridCells>{
+    override fun read(buf: ByteBuffer): GridCells {
+        return when(buf.getInt()) {
+            1 -> GridCells.Fixed(
+                FfiConverterUInt.read(buf),
+                )
+            2 -> GridCells.Adaptive(
+                FfiConverterFloat.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: GridCells) = when(value) {
+        is GridCells.Fixed -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterUInt.allocationSize(value.`count`)
+            )
+        }
+        is GridCells.Adaptive -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterFloat.allocationSize(value.`minSize`)
+            )
+        }
+    }
+
+    override fun write(value: GridCells, buf: ByteBuffer) {
+        when(value) {
+            is GridCells.Fixed -> {
+                buf.putInt(1)
+                FfiConverterUInt.write(value.`count`, buf)
+                Unit
+            }
+            is GridCells.Adaptive -> {
+                buf.putInt(2)
+                FfiConverterFloat.write(value.`minSize`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
 
 enum class IconButtonStyle {
     
@@ -2554,7 +4290,12 @@ enum class IconType {
     MENU,
     FAVORITE,
     SEARCH,
-    PERSON;
+    PERSON,
+    HOME,
+    SETTINGS,
+    NOTIFICATIONS,
+    EMAIL,
+    STAR;
     companion object
 }
 
@@ -2706,6 +4447,40 @@ public object FfiConverterTypeImageSource : FfiConverterRustBuffer<ImageSource>{
 
 
 
+
+enum class ImeAction {
+    
+    DEFAULT,
+    DONE,
+    NEXT,
+    SEARCH,
+    SEND,
+    GO;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeImeAction: FfiConverterRustBuffer<ImeAction> {
+    override fun read(buf: ByteBuffer) = try {
+        ImeAction.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ImeAction) = 4UL
+
+    override fun write(value: ImeAction, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
 sealed class IosUiNode {
     
     data class VStack(
@@ -2857,12 +4632,131 @@ public object FfiConverterTypeIosUiNode : FfiConverterRustBuffer<IosUiNode>{
 
 
 
+enum class KeyboardType {
+    
+    TEXT,
+    NUMBER,
+    DECIMAL,
+    PHONE,
+    EMAIL,
+    URI,
+    PASSWORD;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeKeyboardType: FfiConverterRustBuffer<KeyboardType> {
+    override fun read(buf: ByteBuffer) = try {
+        KeyboardType.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: KeyboardType) = 4UL
+
+    override fun write(value: KeyboardType, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class LazyLayout {
+    
+    object Column : LazyLayout()
+    
+    
+    object Row : LazyLayout()
+    
+    
+    data class Grid(
+        val `cells`: GridCells) : LazyLayout()
+        
+    {
+        
+
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeLazyLayout : FfiConverterRustBuffer<LazyLayout>{
+    override fun read(buf: ByteBuffer): LazyLayout {
+        return when(buf.getInt()) {
+            1 -> LazyLayout.Column
+            2 -> LazyLayout.Row
+            3 -> LazyLayout.Grid(
+                FfiConverterTypeGridCells.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: LazyLayout) = when(value) {
+        is LazyLayout.Column -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is LazyLayout.Row -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+            )
+        }
+        is LazyLayout.Grid -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterTypeGridCells.allocationSize(value.`cells`)
+            )
+        }
+    }
+
+    override fun write(value: LazyLayout, buf: ByteBuffer) {
+        when(value) {
+            is LazyLayout.Column -> {
+                buf.putInt(1)
+                Unit
+            }
+            is LazyLayout.Row -> {
+                buf.putInt(2)
+                Unit
+            }
+            is LazyLayout.Grid -> {
+                buf.putInt(3)
+                FfiConverterTypeGridCells.write(value.`cells`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+}
+
+
+
+
+
+
 enum class MainAxisAlignment {
     
     START,
     CENTER,
     END,
-    SPACE_BETWEEN;
+    SPACE_BETWEEN,
+    SPACE_AROUND,
+    SPACE_EVENLY;
     companion object
 }
 
@@ -2944,32 +4838,252 @@ public object FfiConverterTypePlatformError : FfiConverterRustBuffer<PlatformExc
             is PlatformException.Generic -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
-                + FfiConverterString.allocationSize(value.`details`)
+                + FfiConverterString.allocationSize(value.`details`)
+            )
+        }
+    }
+
+    override fun write(value: PlatformException, buf: ByteBuffer) {
+        when(value) {
+            is PlatformException.NotFound -> {
+                buf.putInt(1)
+                FfiConverterString.write(value.`name`, buf)
+                Unit
+            }
+            is PlatformException.Generic -> {
+                buf.putInt(2)
+                FfiConverterString.write(value.`details`, buf)
+                Unit
+            }
+        }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
+    }
+
+}
+
+
+
+
+enum class SnackbarDuration {
+    
+    SHORT,
+    LONG,
+    INDEFINITE;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeSnackbarDuration: FfiConverterRustBuffer<SnackbarDuration> {
+    override fun read(buf: ByteBuffer) = try {
+        SnackbarDuration.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: SnackbarDuration) = 4UL
+
+    override fun write(value: SnackbarDuration, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+
+enum class TextFieldStyle {
+    
+    FILLED,
+    OUTLINED;
+    companion object
+}
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeTextFieldStyle: FfiConverterRustBuffer<TextFieldStyle> {
+    override fun read(buf: ByteBuffer) = try {
+        TextFieldStyle.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: TextFieldStyle) = 4UL
+
+    override fun write(value: TextFieldStyle, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+sealed class UiPatch {
+    
+    /**
+     * Insert `node` as child `index` of the list container at `path`.
+     */
+    data class Insert(
+        val `path`: List<kotlin.UInt>, 
+        val `index`: kotlin.UInt, 
+        val `node`: AndroidUiNode) : UiPatch()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * Remove child `index` of the list container at `path`.
+     */
+    data class Remove(
+        val `path`: List<kotlin.UInt>, 
+        val `index`: kotlin.UInt) : UiPatch()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * Replace the node at `path` (and its subtree) with `node`.
+     */
+    data class Replace(
+        val `path`: List<kotlin.UInt>, 
+        val `node`: AndroidUiNode) : UiPatch()
+        
+    {
+        
+
+        companion object
+    }
+    
+    /**
+     * The node at `path` changed its own properties. `node` is sent with empty
+     * child slots; the renderer keeps the children it already has.
+     */
+    data class UpdateProps(
+        val `path`: List<kotlin.UInt>, 
+        val `node`: AndroidUiNode) : UiPatch()
+        
+    {
+        
+
+        companion object
+    }
+    
+
+    
+    companion object
+}
+
+/**
+ * @suppress
+ */
+public object FfiConverterTypeUiPatch : FfiConverterRustBuffer<UiPatch>{
+    override fun read(buf: ByteBuffer): UiPatch {
+        return when(buf.getInt()) {
+            1 -> UiPatch.Insert(
+                FfiConverterSequenceUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                FfiConverterTypeAndroidUiNode.read(buf),
+                )
+            2 -> UiPatch.Remove(
+                FfiConverterSequenceUInt.read(buf),
+                FfiConverterUInt.read(buf),
+                )
+            3 -> UiPatch.Replace(
+                FfiConverterSequenceUInt.read(buf),
+                FfiConverterTypeAndroidUiNode.read(buf),
+                )
+            4 -> UiPatch.UpdateProps(
+                FfiConverterSequenceUInt.read(buf),
+                FfiConverterTypeAndroidUiNode.read(buf),
+                )
+            else -> throw RuntimeException("invalid enum value, something is very wrong!!")
+        }
+    }
+
+    override fun allocationSize(value: UiPatch) = when(value) {
+        is UiPatch.Insert -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceUInt.allocationSize(value.`path`)
+                + FfiConverterUInt.allocationSize(value.`index`)
+                + FfiConverterTypeAndroidUiNode.allocationSize(value.`node`)
+            )
+        }
+        is UiPatch.Remove -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceUInt.allocationSize(value.`path`)
+                + FfiConverterUInt.allocationSize(value.`index`)
+            )
+        }
+        is UiPatch.Replace -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceUInt.allocationSize(value.`path`)
+                + FfiConverterTypeAndroidUiNode.allocationSize(value.`node`)
+            )
+        }
+        is UiPatch.UpdateProps -> {
+            // Add the size for the Int that specifies the variant plus the size needed for all fields
+            (
+                4UL
+                + FfiConverterSequenceUInt.allocationSize(value.`path`)
+                + FfiConverterTypeAndroidUiNode.allocationSize(value.`node`)
             )
         }
     }
 
-    override fun write(value: PlatformException, buf: ByteBuffer) {
+    override fun write(value: UiPatch, buf: ByteBuffer) {
         when(value) {
-            is PlatformException.NotFound -> {
+            is UiPatch.Insert -> {
                 buf.putInt(1)
-                FfiConverterString.write(value.`name`, buf)
+                FfiConverterSequenceUInt.write(value.`path`, buf)
+                FfiConverterUInt.write(value.`index`, buf)
+                FfiConverterTypeAndroidUiNode.write(value.`node`, buf)
                 Unit
             }
-            is PlatformException.Generic -> {
+            is UiPatch.Remove -> {
                 buf.putInt(2)
-                FfiConverterString.write(value.`details`, buf)
+                FfiConverterSequenceUInt.write(value.`path`, buf)
+                FfiConverterUInt.write(value.`index`, buf)
+                Unit
+            }
+            is UiPatch.Replace -> {
+                buf.putInt(3)
+                FfiConverterSequenceUInt.write(value.`path`, buf)
+                FfiConverterTypeAndroidUiNode.write(value.`node`, buf)
+                Unit
+            }
+            is UiPatch.UpdateProps -> {
+                buf.putInt(4)
+                FfiConverterSequenceUInt.write(value.`path`, buf)
+                FfiConverterTypeAndroidUiNode.write(value.`node`, buf)
                 Unit
             }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
-
 }
 
 
 
 
 
+
+
 public interface RenderCallback {
     
     fun `onUpdate`()
@@ -3192,6 +5306,70 @@ public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?>
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeAlignment: FfiConverterRustBuffer<Alignment?> {
+    override fun read(buf: ByteBuffer): Alignment? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeAlignment.read(buf)
+    }
+
+    override fun allocationSize(value: Alignment?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeAlignment.allocationSize(value)
+        }
+    }
+
+    override fun write(value: Alignment?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeAlignment.write(value, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterOptionalTypeBadge: FfiConverterRustBuffer<Badge?> {
+    override fun read(buf: ByteBuffer): Badge? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeBadge.read(buf)
+    }
+
+    override fun allocationSize(value: Badge?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeBadge.allocationSize(value)
+        }
+    }
+
+    override fun write(value: Badge?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeBadge.write(value, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
@@ -3256,6 +5434,90 @@ public object FfiConverterOptionalTypeIconType: FfiConverterRustBuffer<IconType?
 
 
 
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceUInt: FfiConverterRustBuffer<List<kotlin.UInt>> {
+    override fun read(buf: ByteBuffer): List<kotlin.UInt> {
+        val len = buf.getInt()
+        return List<kotlin.UInt>(len) {
+            FfiConverterUInt.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.UInt>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterUInt.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.UInt>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterUInt.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
+    override fun read(buf: ByteBuffer): List<kotlin.String> {
+        val len = buf.getInt()
+        return List<kotlin.String>(len) {
+            FfiConverterString.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
+        }
+    }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeNavItem: FfiConverterRustBuffer<List<NavItem>> {
+    override fun read(buf: ByteBuffer): List<NavItem> {
+        val len = buf.getInt()
+        return List<NavItem>(len) {
+            FfiConverterTypeNavItem.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<NavItem>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeNavItem.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<NavItem>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeNavItem.write(it, buf)
+        }
+    }
+}
+
+
+
+
 /**
  * @suppress
  */
@@ -3307,6 +5569,34 @@ public object FfiConverterSequenceTypeIosUiNode: FfiConverterRustBuffer<List<Ios
             FfiConverterTypeIosUiNode.write(it, buf)
         }
     }
+}
+
+
+
+
+/**
+ * @suppress
+ */
+public object FfiConverterSequenceTypeUiPatch: FfiConverterRustBuffer<List<UiPatch>> {
+    override fun read(buf: ByteBuffer): List<UiPatch> {
+        val len = buf.getInt()
+        return List<UiPatch>(len) {
+            FfiConverterTypeUiPatch.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<UiPatch>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeUiPatch.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<UiPatch>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeUiPatch.write(it, buf)
+        }
+    }
 } fun `initLogging`()
         = 
     uniffiRustCall() { _status ->
@@ -3316,6 +5606,70 @@ public object FfiConverterSequenceTypeIosUiNode: FfiConverterRustBuffer<List<Ios
 }
     
     
+ fun `padaukActionMetrics`(): ActionMetrics {
+            return FfiConverterTypeActionMetrics.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_action_metrics(
+    
+        _status)
+}
+    )
+    }
+    
+ fun `padaukAppOnConfigChanged`(`newConfig`: AppConfig)
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_app_on_config_changed(
+    
+        FfiConverterTypeAppConfig.lower(`newConfig`),_status)
+}
+    
+    
+ fun `padaukAppOnLowMemory`()
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_app_on_low_memory(
+    
+        _status)
+}
+    
+    
+ fun `padaukAppOnPause`()
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_app_on_pause(
+    
+        _status)
+}
+    
+    
+ fun `padaukAppOnResume`()
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_app_on_resume(
+    
+        _status)
+}
+    
+    
+ fun `padaukAppOnStart`()
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_app_on_start(
+    
+        _status)
+}
+    
+    
+ fun `padaukAppOnStop`()
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_app_on_stop(
+    
+        _status)
+}
+    
+    
  fun `padaukDispatchAction`(`id`: kotlin.String)
         = 
     uniffiRustCall() { _status ->
@@ -3325,6 +5679,38 @@ public object FfiConverterSequenceTypeIosUiNode: FfiConverterRustBuffer<List<Ios
 }
     
     
+
+        /**
+         * Dispatch an event with its payload, e.g. the new text of a text field.
+         */ fun `padaukDispatchEvent`(`id`: kotlin.String, `payload`: EventPayload)
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_dispatch_event(
+    
+        FfiConverterString.lower(`id`),FfiConverterTypeEventPayload.lower(`payload`),_status)
+}
+    
+    
+ fun `padaukLazyItems`(`listId`: kotlin.String, `start`: kotlin.UInt, `count`: kotlin.UInt): List<AndroidUiNode> {
+            return FfiConverterSequenceTypeAndroidUiNode.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_lazy_items(
+    
+        FfiConverterString.lower(`listId`),FfiConverterUInt.lower(`start`),FfiConverterUInt.lower(`count`),_status)
+}
+    )
+    }
+    
+ fun `padaukLazyKeys`(`listId`: kotlin.String, `start`: kotlin.UInt, `count`: kotlin.UInt): List<kotlin.String> {
+            return FfiConverterSequenceString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_lazy_keys(
+    
+        FfiConverterString.lower(`listId`),FfiConverterUInt.lower(`start`),FfiConverterUInt.lower(`count`),_status)
+}
+    )
+    }
+    
  fun `padaukNavCanPop`(): kotlin.Boolean {
             return FfiConverterBoolean.lift(
     uniffiRustCall() { _status ->
@@ -3335,14 +5721,78 @@ public object FfiConverterSequenceTypeIosUiNode: FfiConverterRustBuffer<List<Ios
     )
     }
     
- fun `padaukNavPop`()
-        = 
+
+        /**
+         * Handle system back. Returns `false` if nothing took it (e.g. the root
+         * route's interceptor let it through): the host should then handle it itself,
+         * e.g. by closing the app.
+         */ fun `padaukNavPop`(): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
     uniffiRustCall() { _status ->
     UniffiLib.uniffi_padauk_fn_func_padauk_nav_pop(
     
         _status)
 }
+    )
+    }
+    
+
+        /**
+         * Restore stacks saved by `padauk_nav_save_state` after the process was
+         * killed. Returns whether they were restored.
+         */ fun `padaukNavRestoreState`(`saved`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_nav_restore_state(
+    
+        FfiConverterString.lower(`saved`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * The navigator stacks, for the host to keep in its saved instance state.
+         * Empty if saving them panicked.
+         */ fun `padaukNavSaveState`(): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_nav_save_state(
+    
+        _status)
+}
+    )
+    }
+    
+
+        /**
+         * Open a deep link (e.g. an intent's data URI): the stack is replaced by
+         * `Router::stack_for(uri)`, as its guards allow. Returns whether a route
+         * matched the whole path; without a `Router` installed, the stack is left
+         * as it is.
+         */ fun `padaukOpenDeepLink`(`uri`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_open_deep_link(
+    
+        FfiConverterString.lower(`uri`),_status)
+}
+    )
+    }
+    
+
+        /**
+         * Rebuild the current screen and return only what changed since the last render.
+         * The first call (or the first after `padauk_reset_render_patch`) replaces the root.
+         */ fun `padaukRenderPatch`(): List<UiPatch> {
+            return FfiConverterSequenceTypeUiPatch.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_render_patch(
     
+        _status)
+}
+    )
+    }
     
  fun `padaukRenderRoot`(): AndroidUiNode {
             return FfiConverterTypeAndroidUiNode.lift(
@@ -3354,6 +5804,18 @@ public object FfiConverterSequenceTypeIosUiNode: FfiConverterRustBuffer<List<Ios
     )
     }
     
+
+        /**
+         * Drop the retained tree, e.g. when the renderer's own copy has been discarded.
+         */ fun `padaukResetRenderPatch`()
+        = 
+    uniffiRustCall() { _status ->
+    UniffiLib.uniffi_padauk_fn_func_padauk_reset_render_patch(
+    
+        _status)
+}
+    
+    
  fun `registerRenderCallback`(`callback`: RenderCallback)
         = 
     uniffiRustCall() { _status ->
@@ -4,24 +4,33 @@ import android.annotation.SuppressLint
 import android.graphics.Color.parseColor
 import androidx.compose.foundation.background
 import androidx.compose.foundation.border
+import androidx.compose.foundation.layout.Arrangement
 import androidx.compose.foundation.layout.height
 import androidx.compose.foundation.layout.fillMaxHeight
 import androidx.compose.foundation.layout.fillMaxWidth
 import androidx.compose.foundation.layout.offset
 import androidx.compose.foundation.layout.padding
 import androidx.compose.foundation.layout.width
+import androidx.compose.ui.Alignment
 import androidx.compose.ui.Modifier
 import androidx.compose.ui.draw.alpha
 import androidx.compose.ui.draw.clip
 import androidx.compose.ui.graphics.Color
 import androidx.compose.ui.graphics.RectangleShape
+import androidx.compose.ui.platform.testTag
 import androidx.compose.foundation.shape.RoundedCornerShape
 import androidx.compose.ui.semantics.disabled
 import androidx.compose.ui.semantics.semantics
+import androidx.compose.ui.unit.Density
+import androidx.compose.ui.unit.Dp
+import androidx.compose.ui.unit.LayoutDirection
 import androidx.compose.ui.unit.dp
 import androidx.compose.ui.zIndex
 import rs.padauk.core.Modifiers
 import rs.padauk.core.ColorValue
+import rs.padauk.core.CrossAxisAlignment
+import rs.padauk.core.MainAxisAlignment
+import rs.padauk.core.Alignment as PadaukAlignment
 
 
 @SuppressLint("ModifierFactoryExtensionFunction")
@@ -79,6 +88,8 @@ fun Modifiers.toCompose(): Modifier {
         m = m.semantics { disabled() }
     }
 
+    this.testTag?.let { m = m.testTag(it) }
+
     return m
 }
 
@@ -102,3 +113,92 @@ fun ColorValue.toComposeColor(): Color {
         is ColorValue.Hex -> this.value.toComposeColor()
     }
 }
+
+// With the Space* arrangements, spacing is the least gap between children.
+fun MainAxisAlignment.toHorizontalArrangement(spacing: Float): Arrangement.Horizontal {
+    return when (this) {
+        MainAxisAlignment.START -> Arrangement.spacedBy(spacing.dp, Alignment.Start)
+        MainAxisAlignment.CENTER -> Arrangement.spacedBy(spacing.dp, Alignment.CenterHorizontally)
+        MainAxisAlignment.END -> Arrangement.spacedBy(spacing.dp, Alignment.End)
+        MainAxisAlignment.SPACE_BETWEEN -> spacedOut(Arrangement.SpaceBetween, spacing)
+        MainAxisAlignment.SPACE_AROUND -> spacedOut(Arrangement.SpaceAround, spacing)
+        MainAxisAlignment.SPACE_EVENLY -> spacedOut(Arrangement.SpaceEvenly, spacing)
+    }
+}
+
+fun MainAxisAlignment.toVerticalArrangement(spacing: Float): Arrangement.Vertical {
+    return when (this) {
+        MainAxisAlignment.START -> Arrangement.spacedBy(spacing.dp, Alignment.Top)
+        MainAxisAlignment.CENTER -> Arrangement.spacedBy(spacing.dp, Alignment.CenterVertically)
+        MainAxisAlignment.END -> Arrangement.spacedBy(spacing.dp, Alignment.Bottom)
+        MainAxisAlignment.SPACE_BETWEEN -> spacedOut(Arrangement.SpaceBetween, spacing)
+        MainAxisAlignment.SPACE_AROUND -> spacedOut(Arrangement.SpaceAround, spacing)
+        MainAxisAlignment.SPACE_EVENLY -> spacedOut(Arrangement.SpaceEvenly, spacing)
+    }
+}
+
+private fun spacedOut(base: Arrangement.HorizontalOrVertical, spacing: Float): Arrangement.HorizontalOrVertical {
+    return if (spacing > 0f) SpacedOut(base, spacing.dp) else base
+}
+
+// A Space* arrangement that keeps `spacing` between children: the gaps are taken
+// out of the room first, and what's left is spread out as `base` would.
+private class SpacedOut(
+    private val base: Arrangement.HorizontalOrVertical,
+    override val spacing: Dp
+) : Arrangement.HorizontalOrVertical {
+    override fun Density.arrange(totalSize: Int, sizes: IntArray, outPositions: IntArray) {
+        val gap = spacing.roundToPx()
+        with(base) { arrange(totalSize - gaps(sizes, gap), sizes, outPositions) }
+        for (i in outPositions.indices) {
+            outPositions[i] += i * gap
+        }
+    }
+
+    override fun Density.arrange(
+        totalSize: Int,
+        sizes: IntArray,
+        layoutDirection: LayoutDirection,
+        outPositions: IntArray
+    ) {
+        val gap = spacing.roundToPx()
+        with(base) { arrange(totalSize - gaps(sizes, gap), sizes, layoutDirection, outPositions) }
+        // Right to left, the first child is the rightmost
+        for (i in outPositions.indices) {
+            val before = if (layoutDirection == LayoutDirection.Ltr) i else outPositions.lastIndex - i
+            outPositions[i] += before * gap
+        }
+    }
+
+    private fun gaps(sizes: IntArray, gap: Int): Int = gap * (sizes.size - 1).coerceAtLeast(0)
+}
+
+fun CrossAxisAlignment.toHorizontal(): Alignment.Horizontal {
+    return when (this) {
+        CrossAxisAlignment.START -> Alignment.Start
+        CrossAxisAlignment.CENTER -> Alignment.CenterHorizontally
+        CrossAxisAlignment.END -> Alignment.End
+    }
+}
+
+fun CrossAxisAlignment.toVertical(): Alignment.Vertical {
+    return when (this) {
+        CrossAxisAlignment.START -> Alignment.Top
+        CrossAxisAlignment.CENTER -> Alignment.CenterVertically
+        CrossAxisAlignment.END -> Alignment.Bottom
+    }
+}
+
+fun PadaukAlignment.toCompose(): Alignment {
+    return when (this) {
+        PadaukAlignment.TOP_START -> Alignment.TopStart
+        PadaukAlignment.TOP_CENTER -> Alignment.TopCenter
+        PadaukAlignment.TOP_END -> Alignment.TopEnd
+        PadaukAlignment.CENTER_START -> Alignment.CenterStart
+        PadaukAlignment.CENTER -> Alignment.Center
+        PadaukAlignment.CENTER_END -> Alignment.CenterEnd
+        PadaukAlignment.BOTTOM_START -> Alignment.BottomStart
+        PadaukAlignment.BOTTOM_CENTER -> Alignment.BottomCenter
+        PadaukAlignment.BOTTOM_END -> Alignment.BottomEnd
+    }
+}
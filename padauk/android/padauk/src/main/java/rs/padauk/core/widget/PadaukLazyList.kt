@@ -0,0 +1,153 @@
+package rs.padauk.core.widget
+
+import androidx.compose.foundation.ExperimentalFoundationApi
+import androidx.compose.foundation.layout.Arrangement
+import androidx.compose.foundation.layout.PaddingValues
+import androidx.compose.foundation.lazy.LazyColumn
+import androidx.compose.foundation.lazy.LazyListScope
+import androidx.compose.foundation.lazy.LazyRow
+import androidx.compose.foundation.lazy.grid.GridItemSpan
+import androidx.compose.foundation.lazy.grid.LazyVerticalGrid
+import androidx.compose.foundation.lazy.grid.rememberLazyGridState
+import androidx.compose.foundation.lazy.rememberLazyListState
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.remember
+import androidx.compose.ui.unit.dp
+import rs.padauk.core.AndroidUiNode
+import rs.padauk.core.GridCells
+import rs.padauk.core.LazyLayout
+import rs.padauk.core.PadaukRenderer
+import rs.padauk.core.padaukLazyItems
+import rs.padauk.core.padaukLazyKeys
+import androidx.compose.foundation.lazy.grid.GridCells as ComposeGridCells
+
+// Items fetched at once when one scrolls into view without having been built
+private const val PAGE_SIZE = 30
+
+@Composable
+fun PadaukLazyList(widget: AndroidUiNode.LazyList) {
+    val count = widget.itemCount.toInt()
+    // Keys come with the built items; others are fetched a page at a time as
+    // the list asks for them
+    val keys = remember(widget) {
+        val known = HashMap<Int, String>()
+        widget.itemKeys.forEachIndexed { i, key -> known[widget.firstIndex.toInt() + i] = key }
+        known
+    }
+    val keyOf: ((Int) -> Any)? = if (widget.keyed) {
+        { index ->
+            keys[index] ?: run {
+                val start = index / PAGE_SIZE * PAGE_SIZE
+                val end = minOf(count, start + PAGE_SIZE)
+                val fetched = padaukLazyKeys(widget.listId, start.toUInt(), (end - start).toUInt())
+                fetched.forEachIndexed { i, key -> keys[start + i] = key }
+                // The list left the screen: any key unique to the index will do
+                keys[index] ?: "#$index"
+            }
+        }
+    } else null
+    val headers = widget.stickyHeaders.map { it.toInt() }
+    // Items of this build of the list: the window Rust built with it, then
+    // whatever is fetched while scrolling. A new build starts over; one after
+    // a fetch always differs (by `fetches`), as the render pass it came from
+    // dropped the actions of fetched items outside its window.
+    val items = remember(widget) {
+        val built = HashMap<Int, AndroidUiNode?>()
+        widget.items.forEachIndexed { i, node -> built[widget.firstIndex.toInt() + i] = node }
+        built
+    }
+    // Fetches from the first item on screen, so the next build of the list has all of them
+    val itemAt = { index: Int, firstVisible: Int ->
+        if (!items.containsKey(index)) {
+            val start = minOf(index, firstVisible)
+            val end = minOf(count, maxOf(index + 1, firstVisible + PAGE_SIZE))
+            val fetched = padaukLazyItems(widget.listId, start.toUInt(), (end - start).toUInt())
+            for (i in start until end) {
+                items[i] = fetched.getOrNull(i - start)
+            }
+        }
+        items[index]
+    }
+    val padding = PaddingValues(widget.contentPadding.dp)
+    val spacing = Arrangement.spacedBy(widget.spacing.dp)
+
+    when (val layout = widget.layout) {
+        is LazyLayout.Column -> {
+            val state = rememberLazyListState()
+            LazyColumn(
+                modifier = widget.modifiers.toCompose(),
+                state = state,
+                contentPadding = padding,
+                verticalArrangement = spacing
+            ) {
+                lazyItems(count, headers, keyOf) { index ->
+                    itemAt(index, state.firstVisibleItemIndex)?.let { PadaukRenderer(it) }
+                }
+            }
+        }
+
+        is LazyLayout.Row -> {
+            val state = rememberLazyListState()
+            LazyRow(
+                modifier = widget.modifiers.toCompose(),
+                state = state,
+                contentPadding = padding,
+                horizontalArrangement = spacing
+            ) {
+                lazyItems(count, headers, keyOf) { index ->
+                    itemAt(index, state.firstVisibleItemIndex)?.let { PadaukRenderer(it) }
+                }
+            }
+        }
+
+        is LazyLayout.Grid -> {
+            val state = rememberLazyGridState()
+            val columns = when (val cells = layout.cells) {
+                is GridCells.Fixed -> ComposeGridCells.Fixed(cells.count.toInt())
+                is GridCells.Adaptive -> ComposeGridCells.Adaptive(cells.minSize.dp)
+            }
+            val headerSet = headers.toSet()
+            LazyVerticalGrid(
+                columns = columns,
+                modifier = widget.modifiers.toCompose(),
+                state = state,
+                contentPadding = padding,
+                verticalArrangement = spacing,
+                horizontalArrangement = spacing
+            ) {
+                // Grids can't pin headers; they take a whole line instead
+                items(
+                    count = count,
+                    key = keyOf,
+                    span = { index -> GridItemSpan(if (index in headerSet) maxLineSpan else 1) }
+                ) { index ->
+                    itemAt(index, state.firstVisibleItemIndex)?.let { PadaukRenderer(it) }
+                }
+            }
+        }
+    }
+}
+
+// Items between the headers go in as ranges, so a long list costs a few entries
+@OptIn(ExperimentalFoundationApi::class)
+private fun LazyListScope.lazyItems(
+    count: Int,
+    headers: List<Int>,
+    keyOf: ((Int) -> Any)?,
+    content: @Composable (Int) -> Unit
+) {
+    var next = 0
+    for (header in headers.filter { it < count } + count) {
+        val offset = next
+        if (header > offset) {
+            items(
+                count = header - offset,
+                key = keyOf?.let { keyOf -> { i: Int -> keyOf(offset + i) } }
+            ) { i -> content(offset + i) }
+        }
+        if (header < count) {
+            stickyHeader(key = keyOf?.invoke(header)) { content(header) }
+        }
+        next = header + 1
+    }
+}
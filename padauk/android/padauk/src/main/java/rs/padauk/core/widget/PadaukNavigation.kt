@@ -0,0 +1,130 @@
+package rs.padauk.core.widget
+
+import androidx.compose.foundation.layout.ColumnScope
+import androidx.compose.material3.Badge
+import androidx.compose.material3.BadgedBox
+import androidx.compose.material3.Icon
+import androidx.compose.material3.NavigationBar
+import androidx.compose.material3.NavigationBarItem
+import androidx.compose.material3.NavigationRail
+import androidx.compose.material3.NavigationRailItem
+import androidx.compose.material3.ScrollableTabRow
+import androidx.compose.material3.Tab
+import androidx.compose.material3.TabRow
+import androidx.compose.material3.Text
+import androidx.compose.runtime.Composable
+import rs.padauk.core.AndroidUiNode
+import rs.padauk.core.EventPayload
+import rs.padauk.core.NavItem
+import rs.padauk.core.PadaukRenderer
+import rs.padauk.core.iconVector
+import rs.padauk.core.padaukDispatchEvent
+import rs.padauk.core.Badge as PadaukBadge
+
+@Composable
+fun PadaukNavigationBar(widget: AndroidUiNode.NavigationBar) {
+    NavigationBar(modifier = widget.modifiers.toCompose()) {
+        widget.items.forEachIndexed { index, item ->
+            val selected = index == widget.selected.toInt()
+            NavigationBarItem(
+                selected = selected,
+                onClick = { select(widget.actionId, index) },
+                icon = { ItemIcon(item, selected) },
+                label = { Text(item.label) },
+                enabled = item.enabled,
+                alwaysShowLabel = widget.alwaysShowLabels
+            )
+        }
+    }
+}
+
+@Composable
+fun PadaukNavigationRail(widget: AndroidUiNode.NavigationRail) {
+    val header: (@Composable ColumnScope.() -> Unit)? = widget.header.firstOrNull()?.let { node ->
+        { PadaukRenderer(node) }
+    }
+    NavigationRail(modifier = widget.modifiers.toCompose(), header = header) {
+        widget.items.forEachIndexed { index, item ->
+            val selected = index == widget.selected.toInt()
+            NavigationRailItem(
+                selected = selected,
+                onClick = { select(widget.actionId, index) },
+                icon = { ItemIcon(item, selected) },
+                label = { Text(item.label) },
+                enabled = item.enabled,
+                alwaysShowLabel = widget.alwaysShowLabels
+            )
+        }
+    }
+}
+
+@Composable
+fun PadaukTabRow(widget: AndroidUiNode.TabRow) {
+    val selectedIndex = widget.selected.toInt()
+    val tabs: @Composable () -> Unit = {
+        widget.items.forEachIndexed { index, item ->
+            val selected = index == selectedIndex
+            // Without an icon, the badge goes on the label
+            val icon: (@Composable () -> Unit)? = item.icon?.let { { ItemIcon(item, selected) } }
+            Tab(
+                selected = selected,
+                onClick = { select(widget.actionId, index) },
+                enabled = item.enabled,
+                text = {
+                    if (icon == null) {
+                        WithBadge(item.badge) { Text(item.label) }
+                    } else {
+                        Text(item.label)
+                    }
+                },
+                icon = icon
+            )
+        }
+    }
+    if (widget.scrollable) {
+        ScrollableTabRow(
+            selectedTabIndex = selectedIndex,
+            modifier = widget.modifiers.toCompose(),
+            tabs = tabs
+        )
+    } else {
+        TabRow(
+            selectedTabIndex = selectedIndex,
+            modifier = widget.modifiers.toCompose(),
+            tabs = tabs
+        )
+    }
+}
+
+private fun select(actionId: String, index: Int) {
+    padaukDispatchEvent(actionId, EventPayload.Selection(index.toUInt()))
+}
+
+@Composable
+private fun ItemIcon(item: NavItem, selected: Boolean) {
+    val icon = (if (selected) item.selectedIcon else null) ?: item.icon
+    WithBadge(item.badge) {
+        if (icon != null) {
+            Icon(iconVector(icon), contentDescription = item.label)
+        }
+    }
+}
+
+@Composable
+private fun WithBadge(badge: PadaukBadge?, content: @Composable () -> Unit) {
+    if (badge == null) {
+        content()
+        return
+    }
+    BadgedBox(badge = {
+        when (badge) {
+            is PadaukBadge.Dot -> Badge()
+            is PadaukBadge.Count -> Badge {
+                Text(if (badge.count > 99u) "99+" else badge.count.toString())
+            }
+            is PadaukBadge.Text -> Badge { Text(badge.text) }
+        }
+    }) {
+        content()
+    }
+}
@@ -0,0 +1,64 @@
+package rs.padauk.core.widget
+
+import androidx.compose.material3.MaterialTheme
+import androidx.compose.material3.RangeSlider
+import androidx.compose.material3.Slider
+import androidx.compose.material3.SliderDefaults
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.LaunchedEffect
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.mutableStateOf
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.setValue
+import rs.padauk.core.AndroidUiNode
+import rs.padauk.core.EventPayload
+import rs.padauk.core.padaukDispatchEvent
+
+@Composable
+fun PadaukSlider(widget: AndroidUiNode.Slider) {
+    val colors = SliderDefaults.colors(
+        thumbColor = widget.colorThumb?.toComposeColor()
+            ?: MaterialTheme.colorScheme.primary,
+        activeTrackColor = widget.colorActiveTrack?.toComposeColor()
+            ?: MaterialTheme.colorScheme.primary,
+        inactiveTrackColor = widget.colorInactiveTrack?.toComposeColor()
+            ?: MaterialTheme.colorScheme.surfaceContainerHighest
+    )
+    val range = widget.min..widget.max
+    val steps = widget.steps.toInt()
+    val endValue = widget.endValue
+
+    if (endValue == null) {
+        // The thumb follows the finger without waiting for Rust; the local
+        // value follows the state when Rust changes it.
+        var value by remember { mutableStateOf(widget.value) }
+        LaunchedEffect(widget.value) { value = widget.value }
+        Slider(
+            value = value,
+            onValueChange = {
+                value = it
+                padaukDispatchEvent(widget.actionId, EventPayload.Value(it))
+            },
+            modifier = widget.modifiers.toCompose(),
+            enabled = widget.enabled,
+            valueRange = range,
+            steps = steps,
+            colors = colors
+        )
+    } else {
+        var values by remember { mutableStateOf(widget.value..endValue) }
+        LaunchedEffect(widget.value, endValue) { values = widget.value..endValue }
+        RangeSlider(
+            value = values,
+            onValueChange = {
+                values = it
+                padaukDispatchEvent(widget.actionId, EventPayload.Range(it.start, it.endInclusive))
+            },
+            modifier = widget.modifiers.toCompose(),
+            enabled = widget.enabled,
+            valueRange = range,
+            steps = steps,
+            colors = colors
+        )
+    }
+}
@@ -0,0 +1,62 @@
+package rs.padauk.core.widget
+
+import androidx.compose.material3.Snackbar
+import androidx.compose.material3.SnackbarHostState
+import androidx.compose.material3.SnackbarResult
+import androidx.compose.material3.Text
+import androidx.compose.material3.TextButton
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.LaunchedEffect
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.rememberUpdatedState
+import rs.padauk.core.AndroidUiNode
+import rs.padauk.core.SnackbarDuration
+import rs.padauk.core.padaukDispatchAction
+import androidx.compose.material3.SnackbarDuration as ComposeSnackbarDuration
+
+// Shows the scaffold's message in its host, once per message id, and reports
+// how it went away. A message that's no longer sent is taken off the screen.
+@Composable
+fun PadaukSnackbarEffect(hostState: SnackbarHostState, widget: AndroidUiNode.Snackbar?) {
+    val current by rememberUpdatedState(widget)
+    LaunchedEffect(widget?.messageId) {
+        val message = current ?: return@LaunchedEffect
+        val result = hostState.showSnackbar(
+            message = message.message,
+            actionLabel = message.actionLabel,
+            withDismissAction = message.dismissButton,
+            duration = message.duration.toCompose()
+        )
+        // The ids are those of the latest build of the message
+        val latest = current ?: return@LaunchedEffect
+        when (result) {
+            SnackbarResult.ActionPerformed -> latest.actionId?.let { padaukDispatchAction(it) }
+            SnackbarResult.Dismissed -> padaukDispatchAction(latest.dismissActionId)
+        }
+    }
+}
+
+// A snackbar outside a scaffold: drawn in place, without a timer
+@Composable
+fun PadaukSnackbar(widget: AndroidUiNode.Snackbar) {
+    val actionId = widget.actionId
+    val label = widget.actionLabel
+    Snackbar(
+        modifier = widget.modifiers.toCompose(),
+        action = if (actionId != null && label != null) {
+            { TextButton(onClick = { padaukDispatchAction(actionId) }) { Text(label) } }
+        } else {
+            null
+        }
+    ) {
+        Text(widget.message)
+    }
+}
+
+private fun SnackbarDuration.toCompose(): ComposeSnackbarDuration {
+    return when (this) {
+        SnackbarDuration.SHORT -> ComposeSnackbarDuration.Short
+        SnackbarDuration.LONG -> ComposeSnackbarDuration.Long
+        SnackbarDuration.INDEFINITE -> ComposeSnackbarDuration.Indefinite
+    }
+}
@@ -0,0 +1,149 @@
+package rs.padauk.core.widget
+
+import androidx.compose.foundation.text.KeyboardActions
+import androidx.compose.foundation.text.KeyboardOptions
+import androidx.compose.material3.Icon
+import androidx.compose.material3.OutlinedTextField
+import androidx.compose.material3.Text
+import androidx.compose.material3.TextField
+import androidx.compose.runtime.Composable
+import androidx.compose.runtime.LaunchedEffect
+import androidx.compose.runtime.SideEffect
+import androidx.compose.runtime.getValue
+import androidx.compose.runtime.mutableStateOf
+import androidx.compose.runtime.remember
+import androidx.compose.runtime.setValue
+import androidx.compose.ui.text.TextRange
+import androidx.compose.ui.text.input.PasswordVisualTransformation
+import androidx.compose.ui.text.input.TextFieldValue
+import androidx.compose.ui.text.input.VisualTransformation
+import rs.padauk.core.AndroidUiNode
+import rs.padauk.core.EventPayload
+import rs.padauk.core.ImeAction
+import rs.padauk.core.KeyboardType
+import rs.padauk.core.TextFieldStyle
+import rs.padauk.core.iconVector
+import rs.padauk.core.padaukDispatchAction
+import rs.padauk.core.padaukDispatchEvent
+import androidx.compose.ui.text.input.ImeAction as ComposeImeAction
+import androidx.compose.ui.text.input.KeyboardType as ComposeKeyboardType
+
+@Composable
+fun PadaukTextField(widget: AndroidUiNode.TextField) {
+    val options = widget.options
+    // The field edits its own copy (with the cursor and selection) so typing
+    // doesn't wait for Rust; the copy follows the state when Rust changes it.
+    var fieldValue by remember { mutableStateOf(TextFieldValue(widget.value, TextRange(widget.value.length))) }
+    // Text sent to Rust that no build has answered yet
+    var dispatched by remember { mutableStateOf<String?>(null) }
+    val resync = {
+        if (fieldValue.text != widget.value) {
+            fieldValue = TextFieldValue(widget.value, TextRange(widget.value.length))
+        }
+    }
+    LaunchedEffect(widget.value) { resync() }
+    // The build after an edit holds what the state kept of it. It may be the
+    // value from before (e.g. over a max length), which the key above misses.
+    if (dispatched != null) {
+        SideEffect {
+            dispatched = null
+            resync()
+        }
+    }
+
+    val onValueChange = { next: TextFieldValue ->
+        val changed = next.text != fieldValue.text
+        fieldValue = next
+        if (changed) {
+            dispatched = next.text
+            padaukDispatchEvent(widget.changeActionId, EventPayload.Text(next.text))
+        }
+    }
+    val label: (@Composable () -> Unit)? = options.label?.let { { Text(it) } }
+    val placeholder: (@Composable () -> Unit)? = options.placeholder?.let { { Text(it) } }
+    val leading: (@Composable () -> Unit)? = options.leadingIcon?.let { icon ->
+        { Icon(iconVector(icon), contentDescription = null) }
+    }
+    val trailing: (@Composable () -> Unit)? = options.trailingIcon?.let { icon ->
+        { Icon(iconVector(icon), contentDescription = null) }
+    }
+    val supporting: (@Composable () -> Unit)? = options.errorText?.let { { Text(it) } }
+    val keyboardType = if (options.secure && options.keyboardType == KeyboardType.TEXT) {
+        KeyboardType.PASSWORD
+    } else {
+        options.keyboardType
+    }
+    val keyboardOptions = KeyboardOptions(
+        keyboardType = keyboardType.toCompose(),
+        imeAction = options.imeAction.toCompose()
+    )
+    val submitActionId = widget.submitActionId
+    val keyboardActions = KeyboardActions(onAny = {
+        submitActionId?.let { padaukDispatchAction(it) }
+        // Keep the default behaviour, e.g. moving the focus on Next
+        defaultKeyboardAction(options.imeAction.toCompose())
+    })
+    val visualTransformation = if (options.secure) {
+        PasswordVisualTransformation()
+    } else {
+        VisualTransformation.None
+    }
+
+    when (widget.style) {
+        TextFieldStyle.FILLED -> TextField(
+            value = fieldValue,
+            onValueChange = onValueChange,
+            modifier = widget.modifiers.toCompose(),
+            enabled = options.enabled,
+            label = label,
+            placeholder = placeholder,
+            leadingIcon = leading,
+            trailingIcon = trailing,
+            supportingText = supporting,
+            isError = options.errorText != null,
+            visualTransformation = visualTransformation,
+            keyboardOptions = keyboardOptions,
+            keyboardActions = keyboardActions,
+            singleLine = options.singleLine
+        )
+        TextFieldStyle.OUTLINED -> OutlinedTextField(
+            value = fieldValue,
+            onValueChange = onValueChange,
+            modifier = widget.modifiers.toCompose(),
+            enabled = options.enabled,
+            label = label,
+            placeholder = placeholder,
+            leadingIcon = leading,
+            trailingIcon = trailing,
+            supportingText = supporting,
+            isError = options.errorText != null,
+            visualTransformation = visualTransformation,
+            keyboardOptions = keyboardOptions,
+            keyboardActions = keyboardActions,
+            singleLine = options.singleLine
+        )
+    }
+}
+
+private fun KeyboardType.toCompose(): ComposeKeyboardType {
+    return when (this) {
+        KeyboardType.TEXT -> ComposeKeyboardType.Text
+        KeyboardType.NUMBER -> ComposeKeyboardType.Number
+        KeyboardType.DECIMAL -> ComposeKeyboardType.Decimal
+        KeyboardType.PHONE -> ComposeKeyboardType.Phone
+        KeyboardType.EMAIL -> ComposeKeyboardType.Email
+        KeyboardType.URI -> ComposeKeyboardType.Uri
+        KeyboardType.PASSWORD -> ComposeKeyboardType.Password
+    }
+}
+
+private fun ImeAction.toCompose(): ComposeImeAction {
+    return when (this) {
+        ImeAction.DEFAULT -> ComposeImeAction.Default
+        ImeAction.DONE -> ComposeImeAction.Done
+        ImeAction.NEXT -> ComposeImeAction.Next
+        ImeAction.SEARCH -> ComposeImeAction.Search
+        ImeAction.SEND -> ComposeImeAction.Send
+        ImeAction.GO -> ComposeImeAction.Go
+    }
+}
@@ -2,13 +2,21 @@ pub use crate::ui::widget::{UiNode, Widget};
 
 uniffi::setup_scaffolding!();
 
+pub mod lifecycle;
 pub mod native;
+pub mod panics;
+pub mod runtime;
+#[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+pub mod testing;
 pub mod ui;
 
 pub mod prelude {
     pub use crate::PadaukApp;
+    pub use crate::lifecycle::{AppConfig, AppLifecycle};
+    pub use crate::panics::PanicReport;
     pub use crate::native::log;
     pub use crate::ui::app_bar::{AppBarStyle, AppBarStyleOptions};
+    pub use crate::ui::back_handler::{BackDecision, use_back_handler};
     pub use crate::ui::button::{
         ButtonShape, ButtonStyle, ButtonStyleOptions, FabOptions, FabStyle, IconButtonOptions,
         IconButtonStyle, IconType,
@@ -16,18 +24,46 @@ pub mod prelude {
     pub use crate::ui::card::{CardShape, CardStyle, CardStyleOptions};
     pub use crate::ui::chip::{ChipShape, ChipStyle, ChipStyleOptions};
     pub use crate::ui::color::{ColorValue, color_hex, color_rgb, color_rgba};
-    pub use crate::ui::navigation::{Navigator, Route};
+    pub use crate::ui::event_registry::{DragDelta, EventData, EventPayload, ScrollPosition};
+    pub use crate::ui::hooks::{remember, use_state};
+    pub use crate::ui::layout::{Alignment, CrossAxisAlignment, MainAxisAlignment};
+    pub use crate::ui::lazy_list::{GridCells, LazyList, lazy_column, lazy_grid, lazy_row};
+    pub use crate::ui::navigation::{
+        Navigator, NavigatorHandle, Route, RouteParams, Router, TabBarStyle, navigator, tab,
+        tab_host,
+    };
+    pub use crate::ui::navigation_bar::{
+        Badge, NavItem, NavigationBar, NavigationRail, TabRow, nav_item, navigation_bar,
+        navigation_rail, tab_item, tab_row,
+    };
+    pub use crate::ui::navigator_observer::{NavigatorObserver, RouteChange};
+    pub use crate::runtime::{TaskHandle, spawn, spawn_for_route};
+    pub use crate::ui::reactive::{Computed, Effect, computed, effect};
+    pub use crate::ui::render_callback::batch;
+    pub use crate::ui::route_guard::GuardDecision;
+    pub use crate::ui::route_registry::RouteRegistry;
+    pub use crate::ui::route_result::RouteResult;
+    pub use crate::ui::selection::{
+        RadioButton, RadioGroup, Slider, Switch, radio_button, radio_group, range_slider, slider,
+        switch,
+    };
+    pub use crate::ui::snackbar::{Messenger, Snackbar, SnackbarDuration, SnackbarId, snackbar};
     pub use crate::ui::state::{State, state};
+    pub use crate::ui::text_field::{
+        ImeAction, KeyboardType, TextField, TextFieldOptions, TextFieldStyle, outlined_text_field,
+        text_field,
+    };
     pub use crate::ui::widget::*;
     pub use crate::ui::widget::{
         app_bar, app_bar_center_aligned, app_bar_large, app_bar_medium, assist_chip, card,
-        checkbox, elevated_button, elevated_card, fab, fab_extended, fab_large, fab_small,
-        filled_button, filled_icon_button, filled_tonal_button, filled_tonal_icon_button,
-        filter_chip, icon_button, input_chip, outlined_button, outlined_card, outlined_icon_button,
-        scaffold, scroll, suggestion_chip, text_button,
+        checkbox, draggable, elevated_button, elevated_card, fab, fab_extended, fab_large,
+        fab_small, filled_button, filled_icon_button, filled_tonal_button,
+        filled_tonal_icon_button, filter_chip, icon_button, input_chip, outlined_button,
+        outlined_card, outlined_icon_button, scaffold, scroll, suggestion_chip, text_button,
     };
 }
 
+pub use crate::runtime::{spawn, spawn_for_route};
 pub use crate::ui::widget::*;
 pub use padauk_macros::main;
 
@@ -44,6 +80,33 @@ pub trait PadaukApp: Send + Sync + 'static {
     /// Define the starting screen for the Navigator.
     /// The framework will initialize the Navigator automatically.
     fn initial_route(&self) -> crate::ui::navigation::Route;
+
+    /// Called when a panic in app code (a build, an action, a task) is caught.
+    /// The default logs it with its backtrace.
+    fn on_panic(&self, report: &crate::panics::PanicReport) {
+        crate::panics::log_report(report);
+    }
+
+    // Lifecycle hooks, called from the host activity's callbacks.
+    // `lifecycle::lifecycle()` and `lifecycle::config()` hold the same information as states.
+
+    /// The app became visible.
+    fn on_start(&self) {}
+
+    /// The app came to the foreground.
+    fn on_resume(&self) {}
+
+    /// The app is leaving the foreground.
+    fn on_pause(&self) {}
+
+    /// The app is no longer visible, e.g. it was backgrounded.
+    fn on_stop(&self) {}
+
+    /// The system is low on memory; drop what can be rebuilt.
+    fn on_low_memory(&self) {}
+
+    /// Theme, font scale, locale or screen size changed.
+    fn on_config_changed(&self, _config: &crate::lifecycle::AppConfig) {}
 }
 
 static APP_INSTANCE: OnceLock<Box<dyn PadaukApp>> = OnceLock::new();
@@ -56,13 +119,35 @@ pub fn start_app<A: PadaukApp>(app: A) {
 
 #[uniffi::export]
 pub fn padauk_render_root() -> UiNode {
-    // 1. Try to render via Navigator first
-    if let Some(nav_widget) = crate::ui::navigation::Navigator::render_current() {
-        nav_widget.build()
-    } else {
-        // 2. Fallback to the App's manual render() method if Navigator isn't initialized
-        // APP_INSTANCE.get().map(|app| app.render().build())
-        log::warn!("padauk_render_root: Navigator isn't initialized.");
-        text("Navigator isn't initialized.").build()
-    }
+    let root = build_root_or_error();
+    // The renderer now holds this full tree; later patches are relative to it.
+    #[cfg(not(target_os = "ios"))]
+    crate::ui::reconciler::commit(root.clone());
+    root
+}
+
+/// `build_root`, showing an error screen instead if the build panics.
+pub(crate) fn build_root_or_error() -> UiNode {
+    crate::panics::catch("render", build_root).unwrap_or_else(|report| {
+        crate::panics::report(&report);
+        column(vec![
+            text("Something went wrong.").into_widget(),
+            text(&report.message).into_widget(),
+        ])
+        .build()
+    })
+}
+
+pub(crate) fn build_root() -> UiNode {
+    crate::ui::build_context::render_pass(|| {
+        // 1. Try to render via Navigator first
+        if let Some(nav_widget) = crate::ui::navigation::Navigator::render_current() {
+            nav_widget.build()
+        } else {
+            // 2. Fallback to the App's manual render() method if Navigator isn't initialized
+            // APP_INSTANCE.get().map(|app| app.render().build())
+            log::warn!("padauk_render_root: Navigator isn't initialized.");
+            text("Navigator isn't initialized.").build()
+        }
+    })
 }
@@ -0,0 +1,132 @@
+//! App lifecycle, as reported by the host activity.
+//!
+//! The host calls the `padauk_app_on_*` exports from its own lifecycle callbacks.
+//! Each one updates `lifecycle()` / `config()` and calls the matching
+//! `PadaukApp` hook, so the app can either implement the hooks or observe the
+//! states (e.g. with an `effect`).
+
+use log::debug;
+use std::sync::OnceLock;
+
+use crate::panics;
+use crate::ui::render_callback::batch;
+use crate::ui::state::State;
+
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppLifecycle {
+    /// The activity hasn't been started yet.
+    Created,
+    /// Visible, but not in the foreground.
+    Started,
+    /// In the foreground and receiving input.
+    Resumed,
+    /// Partly hidden or losing focus.
+    Paused,
+    /// No longer visible (backgrounded).
+    Stopped,
+}
+
+/// Device configuration relevant to the UI.
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct AppConfig {
+    pub dark_theme: bool,
+    pub font_scale: f32,
+    /// BCP 47 tag, e.g. `"en-US"`.
+    pub locale: String,
+    pub screen_width_dp: u32,
+    pub screen_height_dp: u32,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            dark_theme: false,
+            font_scale: 1.0,
+            locale: "en-US".to_string(),
+            screen_width_dp: 0,
+            screen_height_dp: 0,
+        }
+    }
+}
+
+static LIFECYCLE: OnceLock<State<AppLifecycle>> = OnceLock::new();
+static CONFIG: OnceLock<State<AppConfig>> = OnceLock::new();
+
+/// Where the app is in its lifecycle.
+pub fn lifecycle() -> State<AppLifecycle> {
+    LIFECYCLE
+        .get_or_init(|| State::new(AppLifecycle::Created))
+        .clone()
+}
+
+/// The last configuration reported by the host.
+pub fn config() -> State<AppConfig> {
+    CONFIG.get_or_init(|| State::new(AppConfig::default())).clone()
+}
+
+// Deliver a lifecycle event: update the states, then call the app's hook.
+fn deliver(event: &str, update: impl FnOnce(), hook: impl FnOnce(&dyn crate::PadaukApp)) {
+    debug!("Lifecycle: {}", event);
+    let result = panics::catch(&format!("lifecycle {}", event), || {
+        batch(|| {
+            update();
+            if let Some(app) = crate::APP_INSTANCE.get() {
+                hook(app.as_ref());
+            }
+        })
+    });
+    if let Err(report) = result {
+        panics::report(&report);
+    }
+}
+
+#[uniffi::export]
+pub fn padauk_app_on_start() {
+    deliver(
+        "start",
+        || lifecycle().set(AppLifecycle::Started),
+        |app| app.on_start(),
+    );
+}
+
+#[uniffi::export]
+pub fn padauk_app_on_resume() {
+    deliver(
+        "resume",
+        || lifecycle().set(AppLifecycle::Resumed),
+        |app| app.on_resume(),
+    );
+}
+
+#[uniffi::export]
+pub fn padauk_app_on_pause() {
+    deliver(
+        "pause",
+        || lifecycle().set(AppLifecycle::Paused),
+        |app| app.on_pause(),
+    );
+}
+
+#[uniffi::export]
+pub fn padauk_app_on_stop() {
+    deliver(
+        "stop",
+        || lifecycle().set(AppLifecycle::Stopped),
+        |app| app.on_stop(),
+    );
+}
+
+#[uniffi::export]
+pub fn padauk_app_on_low_memory() {
+    deliver("low_memory", || {}, |app| app.on_low_memory());
+}
+
+#[uniffi::export]
+pub fn padauk_app_on_config_changed(new_config: AppConfig) {
+    let hook_config = new_config.clone();
+    deliver(
+        "config_changed",
+        || config().set(new_config),
+        |app| app.on_config_changed(&hook_config),
+    );
+}
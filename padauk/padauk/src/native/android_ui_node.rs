@@ -1,7 +1,6 @@
 use crate::ui::{
     app_bar::{AppBarStyle, AppBarStyleOptions},
     button::{
-        ButtonShape,
         ButtonStyle,
         ButtonStyleOptions,
         FabOptions,
@@ -10,31 +9,92 @@ use crate::ui::{
         IconButtonStyle,
         IconType,
     },
-    card::{CardShape, CardStyle, CardStyleOptions},
+    card::{CardStyle, CardStyleOptions},
     chip::{ChipStyle, ChipStyleOptions},
     image::{BoxFit, ImageSource},
+    layout::{Alignment, CrossAxisAlignment, MainAxisAlignment},
+    lazy_list::LazyLayout,
     modifier::Modifiers,
+    navigation_bar::NavItem,
+    snackbar::SnackbarDuration,
+    text_field::{TextFieldOptions, TextFieldStyle},
 };
 
 // Android-specific definitions
-#[derive(uniffi::Enum, Clone)]
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
 pub enum AndroidUiNode {
     // --- Layouts ---
     Column {
         children: Vec<AndroidUiNode>,
+        main_axis_alignment: MainAxisAlignment,
+        cross_axis_alignment: CrossAxisAlignment,
+        // Gap between children, in dp
+        spacing: f32,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Row {
         children: Vec<AndroidUiNode>,
+        main_axis_alignment: MainAxisAlignment,
+        cross_axis_alignment: CrossAxisAlignment,
+        spacing: f32,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Stack {
         children: Vec<AndroidUiNode>,
+        // For children without their own `align` modifier
+        alignment: Alignment,
         modifiers: Modifiers,
+        key: Option<String>,
+    },
+    // Empty space; sized by its modifiers (weight, width, height)
+    Spacer {
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    Divider {
+        vertical: bool,
+        thickness: Option<f32>,
+        color: Option<crate::ui::color::ColorValue>,
+        modifiers: Modifiers,
+        key: Option<String>,
     },
     Scroll {
         child: Vec<AndroidUiNode>,
+        // Dispatched with the ScrollPosition as the content scrolls
+        scroll_action_id: Option<String>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    // Dispatches a DragDelta for each movement of a drag that starts on `child`
+    Draggable {
+        child: Vec<AndroidUiNode>,
+        action_id: String,
         modifiers: Modifiers,
+        key: Option<String>,
+    },
+    // Only a window of the items is built: `items` holds items
+    // `first_index..first_index + items.len()`. Others are fetched with
+    // padauk_lazy_items(list_id, ..) as they scroll into view.
+    LazyList {
+        list_id: String,
+        layout: LazyLayout,
+        item_count: u32,
+        first_index: u32,
+        // Bumped by each padauk_lazy_items call. Items fetched for an older
+        // value may have lost their actions to a render pass since: drop them.
+        fetches: u32,
+        items: Vec<AndroidUiNode>,
+        // Whether items have keys; others are fetched with padauk_lazy_keys
+        keyed: bool,
+        // Keys of `items`, or empty if the items aren't keyed
+        item_keys: Vec<String>,
+        sticky_headers: Vec<u32>,
+        content_padding: f32,
+        spacing: f32,
+        modifiers: Modifiers,
+        key: Option<String>,
     },
 
     // --- Phase 1: Structural Components ---
@@ -44,7 +104,39 @@ pub enum AndroidUiNode {
         app_bar: Vec<AndroidUiNode>,
         body: Vec<AndroidUiNode>,
         floating_action_button: Vec<AndroidUiNode>,
+        // Under the body, e.g. a NavigationBar
+        bottom_bar: Vec<AndroidUiNode>,
+        // The Snackbar to show, if any
+        snackbar: Vec<AndroidUiNode>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    // Items are dispatched by index (EventPayload::Selection) through action_id
+    NavigationBar {
+        items: Vec<NavItem>,
+        selected: u32,
+        action_id: String,
+        always_show_labels: bool,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    NavigationRail {
+        items: Vec<NavItem>,
+        selected: u32,
+        action_id: String,
+        always_show_labels: bool,
+        // Above the items, e.g. a Fab
+        header: Vec<AndroidUiNode>,
         modifiers: Modifiers,
+        key: Option<String>,
+    },
+    TabRow {
+        items: Vec<NavItem>,
+        selected: u32,
+        action_id: String,
+        scrollable: bool,
+        modifiers: Modifiers,
+        key: Option<String>,
     },
     AppBar {
         title: String,
@@ -53,6 +145,7 @@ pub enum AndroidUiNode {
         options: AppBarStyleOptions,
         // Future: actions: Vec<UiNode>,
         modifiers: Modifiers,
+        key: Option<String>,
     },
 
     // --- Primitives ---
@@ -60,6 +153,7 @@ pub enum AndroidUiNode {
         text: String,
         sp_size: f32, // Android uses SP for fonts
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Button {
         action_id: String,
@@ -67,6 +161,7 @@ pub enum AndroidUiNode {
         style: ButtonStyle,
         options: ButtonStyleOptions,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     IconButton {
         action_id: String,
@@ -74,6 +169,7 @@ pub enum AndroidUiNode {
         style: IconButtonStyle,
         options: IconButtonOptions,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Card {
         children: Vec<AndroidUiNode>,
@@ -81,6 +177,7 @@ pub enum AndroidUiNode {
         action_id: Option<String>,
         options: CardStyleOptions,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Checkbox {
         checked: bool,
@@ -90,6 +187,47 @@ pub enum AndroidUiNode {
         color_unchecked: Option<crate::ui::color::ColorValue>,
         color_checkmark: Option<crate::ui::color::ColorValue>,
         modifiers: Modifiers,
+        key: Option<String>,
+    },
+    Switch {
+        checked: bool,
+        action_id: String,
+        enabled: bool,
+        color_checked_thumb: Option<crate::ui::color::ColorValue>,
+        color_checked_track: Option<crate::ui::color::ColorValue>,
+        color_unchecked_thumb: Option<crate::ui::color::ColorValue>,
+        color_unchecked_track: Option<crate::ui::color::ColorValue>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    RadioButton {
+        selected: bool,
+        // Drawn next to the button, and selects it too when tapped
+        label: Option<String>,
+        action_id: String,
+        enabled: bool,
+        color_selected: Option<crate::ui::color::ColorValue>,
+        color_unselected: Option<crate::ui::color::ColorValue>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    Slider {
+        value: f32,
+        // Set for a range slider, whose start is `value`
+        end_value: Option<f32>,
+        min: f32,
+        max: f32,
+        // Values the thumb stops at between min and max; 0 = continuous
+        steps: u32,
+        // Dispatched while dragging, with the new value (EventPayload::Value),
+        // or both ends of a range slider (EventPayload::Range)
+        action_id: String,
+        enabled: bool,
+        color_thumb: Option<crate::ui::color::ColorValue>,
+        color_active_track: Option<crate::ui::color::ColorValue>,
+        color_inactive_track: Option<crate::ui::color::ColorValue>,
+        modifiers: Modifiers,
+        key: Option<String>,
     },
     Chip {
         label: String,
@@ -101,6 +239,7 @@ pub enum AndroidUiNode {
         close_action_id: Option<String>,
         options: ChipStyleOptions,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Fab {
         action_id: String,
@@ -109,10 +248,403 @@ pub enum AndroidUiNode {
         label: Option<String>,
         options: FabOptions,
         modifiers: Modifiers,
+        key: Option<String>,
     },
     Image {
         source: ImageSource,
         fit: BoxFit,
         modifiers: Modifiers,
+        key: Option<String>,
+    },
+    TextField {
+        value: String,
+        style: TextFieldStyle,
+        options: TextFieldOptions,
+        // Dispatched with the new text (EventPayload::Text) on each edit
+        change_action_id: String,
+        // Dispatched when the keyboard's action key is pressed
+        submit_action_id: Option<String>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+
+    // Shown by the scaffold that holds it, once per message_id: showing the
+    // same message again after a redraw doesn't restart its timer.
+    Snackbar {
+        message_id: u64,
+        message: String,
+        action_label: Option<String>,
+        action_id: Option<String>,
+        // Dispatched when the message times out or is swiped away or closed
+        dismiss_action_id: String,
+        duration: SnackbarDuration,
+        dismiss_button: bool,
+        modifiers: Modifiers,
+        key: Option<String>,
     },
+
+    // --- Overlays: drawn in their own window over the screen ---
+    Dialog {
+        content: Vec<AndroidUiNode>,
+        // Dispatched on a scrim tap or back press. None = can't be dismissed that way.
+        dismiss_action_id: Option<String>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+    BottomSheet {
+        content: Vec<AndroidUiNode>,
+        // Dispatched on a scrim tap, back press or swipe down.
+        dismiss_action_id: Option<String>,
+        modifiers: Modifiers,
+        key: Option<String>,
+    },
+}
+
+impl AndroidUiNode {
+    /// Name of the variant, e.g. `"Button"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AndroidUiNode::Column { .. } => "Column",
+            AndroidUiNode::Row { .. } => "Row",
+            AndroidUiNode::Stack { .. } => "Stack",
+            AndroidUiNode::Spacer { .. } => "Spacer",
+            AndroidUiNode::Divider { .. } => "Divider",
+            AndroidUiNode::Scroll { .. } => "Scroll",
+            AndroidUiNode::Draggable { .. } => "Draggable",
+            AndroidUiNode::LazyList { .. } => "LazyList",
+            AndroidUiNode::Scaffold { .. } => "Scaffold",
+            AndroidUiNode::NavigationBar { .. } => "NavigationBar",
+            AndroidUiNode::NavigationRail { .. } => "NavigationRail",
+            AndroidUiNode::TabRow { .. } => "TabRow",
+            AndroidUiNode::AppBar { .. } => "AppBar",
+            AndroidUiNode::Text { .. } => "Text",
+            AndroidUiNode::Button { .. } => "Button",
+            AndroidUiNode::IconButton { .. } => "IconButton",
+            AndroidUiNode::Card { .. } => "Card",
+            AndroidUiNode::Checkbox { .. } => "Checkbox",
+            AndroidUiNode::Switch { .. } => "Switch",
+            AndroidUiNode::RadioButton { .. } => "RadioButton",
+            AndroidUiNode::Slider { .. } => "Slider",
+            AndroidUiNode::Chip { .. } => "Chip",
+            AndroidUiNode::Fab { .. } => "Fab",
+            AndroidUiNode::Image { .. } => "Image",
+            AndroidUiNode::TextField { .. } => "TextField",
+            AndroidUiNode::Snackbar { .. } => "Snackbar",
+            AndroidUiNode::Dialog { .. } => "Dialog",
+            AndroidUiNode::BottomSheet { .. } => "BottomSheet",
+        }
+    }
+
+    pub fn modifiers(&self) -> &Modifiers {
+        match self {
+            AndroidUiNode::Column { modifiers, .. }
+            | AndroidUiNode::Row { modifiers, .. }
+            | AndroidUiNode::Stack { modifiers, .. }
+            | AndroidUiNode::Spacer { modifiers, .. }
+            | AndroidUiNode::Divider { modifiers, .. }
+            | AndroidUiNode::Scroll { modifiers, .. }
+            | AndroidUiNode::Draggable { modifiers, .. }
+            | AndroidUiNode::LazyList { modifiers, .. }
+            | AndroidUiNode::Scaffold { modifiers, .. }
+            | AndroidUiNode::NavigationBar { modifiers, .. }
+            | AndroidUiNode::NavigationRail { modifiers, .. }
+            | AndroidUiNode::TabRow { modifiers, .. }
+            | AndroidUiNode::AppBar { modifiers, .. }
+            | AndroidUiNode::Text { modifiers, .. }
+            | AndroidUiNode::Button { modifiers, .. }
+            | AndroidUiNode::IconButton { modifiers, .. }
+            | AndroidUiNode::Card { modifiers, .. }
+            | AndroidUiNode::Checkbox { modifiers, .. }
+            | AndroidUiNode::Switch { modifiers, .. }
+            | AndroidUiNode::RadioButton { modifiers, .. }
+            | AndroidUiNode::Slider { modifiers, .. }
+            | AndroidUiNode::Chip { modifiers, .. }
+            | AndroidUiNode::Fab { modifiers, .. }
+            | AndroidUiNode::Image { modifiers, .. }
+            | AndroidUiNode::TextField { modifiers, .. }
+            | AndroidUiNode::Snackbar { modifiers, .. }
+            | AndroidUiNode::Dialog { modifiers, .. }
+            | AndroidUiNode::BottomSheet { modifiers, .. } => modifiers,
+        }
+    }
+
+    /// The key the node was given with `.key(...)`, if any.
+    pub fn key(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Column { key, .. }
+            | AndroidUiNode::Row { key, .. }
+            | AndroidUiNode::Stack { key, .. }
+            | AndroidUiNode::Spacer { key, .. }
+            | AndroidUiNode::Divider { key, .. }
+            | AndroidUiNode::Scroll { key, .. }
+            | AndroidUiNode::Draggable { key, .. }
+            | AndroidUiNode::LazyList { key, .. }
+            | AndroidUiNode::Scaffold { key, .. }
+            | AndroidUiNode::NavigationBar { key, .. }
+            | AndroidUiNode::NavigationRail { key, .. }
+            | AndroidUiNode::TabRow { key, .. }
+            | AndroidUiNode::AppBar { key, .. }
+            | AndroidUiNode::Text { key, .. }
+            | AndroidUiNode::Button { key, .. }
+            | AndroidUiNode::IconButton { key, .. }
+            | AndroidUiNode::Card { key, .. }
+            | AndroidUiNode::Checkbox { key, .. }
+            | AndroidUiNode::Switch { key, .. }
+            | AndroidUiNode::RadioButton { key, .. }
+            | AndroidUiNode::Slider { key, .. }
+            | AndroidUiNode::Chip { key, .. }
+            | AndroidUiNode::Fab { key, .. }
+            | AndroidUiNode::Image { key, .. }
+            | AndroidUiNode::TextField { key, .. }
+            | AndroidUiNode::Snackbar { key, .. }
+            | AndroidUiNode::Dialog { key, .. }
+            | AndroidUiNode::BottomSheet { key, .. } => key.as_deref(),
+        }
+    }
+
+    pub fn set_key(&mut self, value: Option<String>) {
+        match self {
+            AndroidUiNode::Column { key, .. }
+            | AndroidUiNode::Row { key, .. }
+            | AndroidUiNode::Stack { key, .. }
+            | AndroidUiNode::Spacer { key, .. }
+            | AndroidUiNode::Divider { key, .. }
+            | AndroidUiNode::Scroll { key, .. }
+            | AndroidUiNode::Draggable { key, .. }
+            | AndroidUiNode::LazyList { key, .. }
+            | AndroidUiNode::Scaffold { key, .. }
+            | AndroidUiNode::NavigationBar { key, .. }
+            | AndroidUiNode::NavigationRail { key, .. }
+            | AndroidUiNode::TabRow { key, .. }
+            | AndroidUiNode::AppBar { key, .. }
+            | AndroidUiNode::Text { key, .. }
+            | AndroidUiNode::Button { key, .. }
+            | AndroidUiNode::IconButton { key, .. }
+            | AndroidUiNode::Card { key, .. }
+            | AndroidUiNode::Checkbox { key, .. }
+            | AndroidUiNode::Switch { key, .. }
+            | AndroidUiNode::RadioButton { key, .. }
+            | AndroidUiNode::Slider { key, .. }
+            | AndroidUiNode::Chip { key, .. }
+            | AndroidUiNode::Fab { key, .. }
+            | AndroidUiNode::Image { key, .. }
+            | AndroidUiNode::TextField { key, .. }
+            | AndroidUiNode::Snackbar { key, .. }
+            | AndroidUiNode::Dialog { key, .. }
+            | AndroidUiNode::BottomSheet { key, .. } => *key = value,
+        }
+    }
+
+    /// All child nodes, slot by slot in declaration order.
+    pub fn children(&self) -> Vec<&AndroidUiNode> {
+        self.child_slots().into_iter().flatten().collect()
+    }
+
+    /// The child vectors of the node, in declaration order.
+    pub fn child_slots(&self) -> Vec<&Vec<AndroidUiNode>> {
+        match self {
+            AndroidUiNode::Column { children, .. }
+            | AndroidUiNode::Row { children, .. }
+            | AndroidUiNode::Stack { children, .. }
+            | AndroidUiNode::Card { children, .. } => vec![children],
+            AndroidUiNode::Scroll { child, .. } | AndroidUiNode::Draggable { child, .. } => {
+                vec![child]
+            }
+            AndroidUiNode::LazyList { items, .. } => vec![items],
+            AndroidUiNode::Scaffold {
+                app_bar,
+                body,
+                floating_action_button,
+                bottom_bar,
+                snackbar,
+                ..
+            } => vec![app_bar, body, floating_action_button, bottom_bar, snackbar],
+            AndroidUiNode::AppBar { leading, .. } => vec![leading],
+            AndroidUiNode::NavigationRail { header, .. } => vec![header],
+            AndroidUiNode::Button { content, .. }
+            | AndroidUiNode::Dialog { content, .. }
+            | AndroidUiNode::BottomSheet { content, .. } => vec![content],
+            AndroidUiNode::Text { .. }
+            | AndroidUiNode::NavigationBar { .. }
+            | AndroidUiNode::TabRow { .. }
+            | AndroidUiNode::Spacer { .. }
+            | AndroidUiNode::Divider { .. }
+            | AndroidUiNode::IconButton { .. }
+            | AndroidUiNode::Checkbox { .. }
+            | AndroidUiNode::Switch { .. }
+            | AndroidUiNode::RadioButton { .. }
+            | AndroidUiNode::Slider { .. }
+            | AndroidUiNode::Chip { .. }
+            | AndroidUiNode::Fab { .. }
+            | AndroidUiNode::Image { .. }
+            | AndroidUiNode::TextField { .. }
+            | AndroidUiNode::Snackbar { .. } => vec![],
+        }
+    }
+
+    pub fn child_slots_mut(&mut self) -> Vec<&mut Vec<AndroidUiNode>> {
+        match self {
+            AndroidUiNode::Column { children, .. }
+            | AndroidUiNode::Row { children, .. }
+            | AndroidUiNode::Stack { children, .. }
+            | AndroidUiNode::Card { children, .. } => vec![children],
+            AndroidUiNode::Scroll { child, .. } | AndroidUiNode::Draggable { child, .. } => {
+                vec![child]
+            }
+            AndroidUiNode::LazyList { items, .. } => vec![items],
+            AndroidUiNode::Scaffold {
+                app_bar,
+                body,
+                floating_action_button,
+                bottom_bar,
+                snackbar,
+                ..
+            } => vec![app_bar, body, floating_action_button, bottom_bar, snackbar],
+            AndroidUiNode::AppBar { leading, .. } => vec![leading],
+            AndroidUiNode::NavigationRail { header, .. } => vec![header],
+            AndroidUiNode::Button { content, .. }
+            | AndroidUiNode::Dialog { content, .. }
+            | AndroidUiNode::BottomSheet { content, .. } => vec![content],
+            AndroidUiNode::Text { .. }
+            | AndroidUiNode::NavigationBar { .. }
+            | AndroidUiNode::TabRow { .. }
+            | AndroidUiNode::Spacer { .. }
+            | AndroidUiNode::Divider { .. }
+            | AndroidUiNode::IconButton { .. }
+            | AndroidUiNode::Checkbox { .. }
+            | AndroidUiNode::Switch { .. }
+            | AndroidUiNode::RadioButton { .. }
+            | AndroidUiNode::Slider { .. }
+            | AndroidUiNode::Chip { .. }
+            | AndroidUiNode::Fab { .. }
+            | AndroidUiNode::Image { .. }
+            | AndroidUiNode::TextField { .. }
+            | AndroidUiNode::Snackbar { .. } => vec![],
+        }
+    }
+
+    /// Whether the node holds a single, freely sized list of children
+    /// (as opposed to fixed slots such as a scaffold's app bar and body).
+    pub fn has_child_list(&self) -> bool {
+        matches!(
+            self,
+            AndroidUiNode::Column { .. }
+                | AndroidUiNode::Row { .. }
+                | AndroidUiNode::Stack { .. }
+                | AndroidUiNode::LazyList { .. }
+                | AndroidUiNode::Card { .. }
+        )
+    }
+
+    /// The text the node displays itself (not the text of its children).
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Text { text, .. } => Some(text),
+            AndroidUiNode::AppBar { title, .. } => Some(title),
+            AndroidUiNode::Chip { label, .. } => Some(label),
+            AndroidUiNode::Fab { label, .. } => label.as_deref(),
+            AndroidUiNode::TextField { options, .. } => options.label.as_deref(),
+            AndroidUiNode::Snackbar { message, .. } => Some(message),
+            AndroidUiNode::RadioButton { label, .. } => label.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The action dispatched when the node is clicked or toggled.
+    pub fn action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Button { action_id, .. }
+            | AndroidUiNode::IconButton { action_id, .. }
+            | AndroidUiNode::Checkbox { action_id, .. }
+            | AndroidUiNode::RadioButton { action_id, .. }
+            | AndroidUiNode::Chip { action_id, .. }
+            | AndroidUiNode::Fab { action_id, .. } => Some(action_id),
+            AndroidUiNode::Card { action_id, .. } | AndroidUiNode::Snackbar { action_id, .. } => {
+                action_id.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    /// The event dispatched with the new text when the node is edited.
+    pub fn text_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::TextField {
+                change_action_id, ..
+            } => Some(change_action_id),
+            _ => None,
+        }
+    }
+
+    /// The event dispatched with the new checked state when the node is toggled.
+    pub fn toggle_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Switch { action_id, .. } => Some(action_id),
+            _ => None,
+        }
+    }
+
+    /// The event dispatched with the new value when the node is dragged.
+    pub fn value_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Slider { action_id, .. } => Some(action_id),
+            _ => None,
+        }
+    }
+
+    /// The event dispatched with the position of the node's content as it
+    /// scrolls.
+    pub fn scroll_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Scroll {
+                scroll_action_id, ..
+            } => scroll_action_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The event dispatched with the movement of a drag on the node.
+    pub fn drag_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Draggable { action_id, .. } => Some(action_id),
+            _ => None,
+        }
+    }
+
+    /// The event dispatched with the index of the item tapped.
+    pub fn selection_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::NavigationBar { action_id, .. }
+            | AndroidUiNode::NavigationRail { action_id, .. }
+            | AndroidUiNode::TabRow { action_id, .. } => Some(action_id),
+            _ => None,
+        }
+    }
+
+    /// The action dispatched when the node's input is submitted.
+    pub fn submit_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::TextField {
+                submit_action_id, ..
+            } => submit_action_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The action dispatched when an overlay is dismissed from outside its
+    /// content, or a snackbar goes away on its own.
+    pub fn dismiss_action_id(&self) -> Option<&str> {
+        match self {
+            AndroidUiNode::Dialog {
+                dismiss_action_id, ..
+            }
+            | AndroidUiNode::BottomSheet {
+                dismiss_action_id, ..
+            } => dismiss_action_id.as_deref(),
+            AndroidUiNode::Snackbar {
+                dismiss_action_id, ..
+            } => Some(dismiss_action_id),
+            _ => None,
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use crate::ui::modifier::Modifiers;
 
 // iOS-specific definitions
-#[derive(uniffi::Enum, Clone)]
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
 pub enum IosUiNode {
     // Matches SwiftUI naming
     VStack {
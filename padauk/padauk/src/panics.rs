@@ -0,0 +1,117 @@
+//! Catching panics before they reach the native side.
+//!
+//! Every exported entry point that runs app code (building the tree, dispatching
+//! actions, polling tasks) goes through `catch`. A caught panic is reported to
+//! `PadaukApp::on_panic` with its message, location and backtrace.
+
+use log::error;
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::{Cell, RefCell};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::ui::{build_context, hooks, navigation, reactive};
+
+/// A panic caught by Padauk.
+#[derive(Clone, Debug)]
+pub struct PanicReport {
+    /// What was running, e.g. `"render"` or `"action /body/0#click"`.
+    pub context: String,
+    pub message: String,
+    /// `file:line:column` of the panic, if known.
+    pub location: Option<String>,
+    pub backtrace: String,
+}
+
+// Location and backtrace of the last panic on this thread, filled in by the
+// panic hook while a `catch` is running.
+struct Captured {
+    location: Option<String>,
+    backtrace: String,
+}
+
+thread_local! {
+    static CATCHING: Cell<usize> = const { Cell::new(0) };
+    static CAPTURED: RefCell<Option<Captured>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+fn install_hook() {
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) > 0 {
+                CAPTURED.with(|captured| {
+                    *captured.borrow_mut() = Some(Captured {
+                        location: info.location().map(|l| l.to_string()),
+                        backtrace: Backtrace::force_capture().to_string(),
+                    });
+                });
+            }
+            previous(info);
+        }));
+    });
+}
+
+/// Run `f`, turning a panic into a `PanicReport`. The thread-local build state
+/// (widget path, tracking scopes, current route and navigator) is rewound to where it was.
+pub(crate) fn catch<R>(context: &str, f: impl FnOnce() -> R) -> Result<R, PanicReport> {
+    install_hook();
+    let depth = build_context::depth();
+    let scopes = reactive::scope_depth();
+    let route = hooks::current_route();
+    let navigators = navigation::scope_depth();
+
+    CATCHING.with(|c| c.set(c.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(c.get() - 1));
+
+    result.map_err(|payload| {
+        build_context::truncate(depth);
+        reactive::truncate_scopes(scopes);
+        hooks::set_current_route(route);
+        navigation::truncate_scope(navigators);
+        let captured = CAPTURED.with(|captured| captured.borrow_mut().take());
+        PanicReport {
+            context: context.to_string(),
+            message: payload_message(payload.as_ref()),
+            location: captured.as_ref().and_then(|c| c.location.clone()),
+            backtrace: captured.map(|c| c.backtrace).unwrap_or_default(),
+        }
+    })
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Hand a caught panic to the app's `on_panic`, or log it if there is no app.
+pub(crate) fn report(report: &PanicReport) {
+    match crate::APP_INSTANCE.get() {
+        Some(app) => {
+            // The app's own hook mustn't take the process down either.
+            if panic::catch_unwind(AssertUnwindSafe(|| app.on_panic(report))).is_err() {
+                error!("PadaukApp::on_panic panicked.");
+            }
+        }
+        None => log_report(report),
+    }
+}
+
+pub(crate) fn log_report(report: &PanicReport) {
+    error!(
+        "Panic during {}: {} at {}\n{}",
+        report.context,
+        report.message,
+        report.location.as_deref().unwrap_or("<unknown>"),
+        report.backtrace
+    );
+}
@@ -0,0 +1,423 @@
+//! A small executor for async work started from the UI, e.g. in a click handler.
+//!
+//! `spawn` runs a future on the runtime's worker thread; it can update `State`
+//! when it's done, which redraws as usual. Each poll of a task runs in a `batch`,
+//! so the states it sets between two awaits redraw once. `spawn_for_route` ties
+//! a task to the route on display: it is cancelled when that route is popped.
+//!
+//! Tests can switch to a manual executor with `use_manual_executor` and drive
+//! tasks with `tick` / `run_until_idle` instead of a background thread.
+
+use log::{debug, info};
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+
+use crate::panics;
+use crate::ui::navigation::{self, Navigator};
+use crate::ui::render_callback::batch;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+struct Task {
+    id: u64,
+    // Route the task is tied to, if any
+    route: Option<u64>,
+    // Nested navigator it was spawned in; `Navigator` calls act on it
+    scope: Option<String>,
+    // `None` once the task has finished or been cancelled
+    future: Mutex<Option<BoxFuture>>,
+    queued: AtomicBool,
+    cancelled: AtomicBool,
+    finished: AtomicBool,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        schedule(self);
+    }
+}
+
+#[derive(Default)]
+struct Queue {
+    ready: VecDeque<Arc<Task>>,
+    worker_started: bool,
+}
+
+struct Runtime {
+    queue: Mutex<Queue>,
+    available: Condvar,
+    // Unfinished tasks, by id
+    tasks: Mutex<HashMap<u64, Arc<Task>>>,
+    manual: AtomicBool,
+}
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(1);
+
+fn get_runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| Runtime {
+        queue: Mutex::new(Queue::default()),
+        available: Condvar::new(),
+        tasks: Mutex::new(HashMap::new()),
+        manual: AtomicBool::new(false),
+    })
+}
+
+/// Handle to a spawned task.
+#[derive(Clone)]
+pub struct TaskHandle {
+    task: Arc<Task>,
+}
+
+impl TaskHandle {
+    /// Stop the task. It won't be polled again; a poll in progress completes first.
+    pub fn cancel(&self) {
+        cancel_task(&self.task);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.task.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.task.finished.load(Ordering::SeqCst)
+    }
+}
+
+/// Run `future` on the runtime.
+pub fn spawn(future: impl Future<Output = ()> + Send + 'static) -> TaskHandle {
+    spawn_task(None, Box::pin(future))
+}
+
+/// Run `future` on the runtime until it completes or the route on display
+/// is removed from the `Navigator`, whichever comes first.
+pub fn spawn_for_route(future: impl Future<Output = ()> + Send + 'static) -> TaskHandle {
+    spawn_task(Navigator::current_route_id(), Box::pin(future))
+}
+
+/// Run the blocking closure `f` on its own thread; the returned future
+/// resolves to its result.
+pub fn blocking<R: Send + 'static>(
+    f: impl FnOnce() -> R + Send + 'static,
+) -> impl Future<Output = R> + Send + 'static {
+    let slot = Arc::new(Mutex::new(BlockingSlot {
+        result: None,
+        waker: None,
+    }));
+    let writer = slot.clone();
+    thread::spawn(move || {
+        let result = f();
+        let mut slot = writer.lock().unwrap();
+        slot.result = Some(result);
+        if let Some(waker) = slot.waker.take() {
+            waker.wake();
+        }
+    });
+    BlockingFuture { slot }
+}
+
+struct BlockingSlot<R> {
+    result: Option<R>,
+    waker: Option<Waker>,
+}
+
+struct BlockingFuture<R> {
+    slot: Arc<Mutex<BlockingSlot<R>>>,
+}
+
+impl<R> Future for BlockingFuture<R> {
+    type Output = R;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<R> {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn spawn_task(route: Option<u64>, future: BoxFuture) -> TaskHandle {
+    let task = Arc::new(Task {
+        id: NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed),
+        route,
+        scope: navigation::current_scope(),
+        future: Mutex::new(Some(future)),
+        queued: AtomicBool::new(false),
+        cancelled: AtomicBool::new(false),
+        finished: AtomicBool::new(false),
+    });
+    debug!("Spawn task {} (route {:?})", task.id, route);
+    get_runtime()
+        .tasks
+        .lock()
+        .unwrap()
+        .insert(task.id, task.clone());
+    schedule(task.clone());
+    TaskHandle { task }
+}
+
+fn schedule(task: Arc<Task>) {
+    if task.cancelled.load(Ordering::SeqCst)
+        || task.finished.load(Ordering::SeqCst)
+        || task.queued.swap(true, Ordering::SeqCst)
+    {
+        return;
+    }
+    let runtime = get_runtime();
+    let mut queue = runtime.queue.lock().unwrap();
+    queue.ready.push_back(task);
+    if !runtime.manual.load(Ordering::SeqCst) && !queue.worker_started {
+        queue.worker_started = true;
+        thread::Builder::new()
+            .name("padauk-runtime".to_string())
+            .spawn(worker)
+            .expect("failed to start the padauk runtime thread");
+        info!("Runtime worker started.");
+    }
+    runtime.available.notify_one();
+}
+
+fn worker() {
+    let runtime = get_runtime();
+    loop {
+        let task = {
+            let mut queue = runtime.queue.lock().unwrap();
+            loop {
+                // In manual mode the queue belongs to `tick`.
+                if !runtime.manual.load(Ordering::SeqCst)
+                    && let Some(task) = queue.ready.pop_front()
+                {
+                    break task;
+                }
+                queue = runtime.available.wait(queue).unwrap();
+            }
+        };
+        poll_task(&task);
+    }
+}
+
+fn poll_task(task: &Arc<Task>) {
+    task.queued.store(false, Ordering::SeqCst);
+    let mut slot = task.future.lock().unwrap();
+    let Some(future) = slot.as_mut() else {
+        return;
+    };
+    if task.cancelled.load(Ordering::SeqCst) {
+        *slot = None;
+        return;
+    }
+
+    let waker = Waker::from(task.clone());
+    let mut cx = Context::from_waker(&waker);
+    // States set between two awaits redraw once.
+    let poll = panics::catch(&format!("task {}", task.id), || {
+        navigation::with_scope(task.scope.clone(), || {
+            batch(|| future.as_mut().poll(&mut cx))
+        })
+    });
+
+    let done = match poll {
+        Ok(poll) => poll.is_ready(),
+        // A task that panicked is done.
+        Err(report) => {
+            panics::report(&report);
+            true
+        }
+    };
+    if done {
+        *slot = None;
+        task.finished.store(true, Ordering::SeqCst);
+        get_runtime().tasks.lock().unwrap().remove(&task.id);
+        debug!("Task {} finished.", task.id);
+    } else if task.cancelled.load(Ordering::SeqCst) {
+        // Cancelled from inside its own poll
+        *slot = None;
+    }
+}
+
+fn cancel_task(task: &Arc<Task>) {
+    if task.cancelled.swap(true, Ordering::SeqCst) || task.finished.load(Ordering::SeqCst) {
+        return;
+    }
+    debug!("Cancel task {}", task.id);
+    get_runtime().tasks.lock().unwrap().remove(&task.id);
+    // If the task is being polled, the poller drops it afterwards.
+    if let Ok(mut slot) = task.future.try_lock() {
+        *slot = None;
+    }
+}
+
+/// Cancel every task tied to route `route_id` (called when it leaves the stack).
+pub(crate) fn cancel_route(route_id: u64) {
+    let tasks: Vec<Arc<Task>> = get_runtime()
+        .tasks
+        .lock()
+        .unwrap()
+        .values()
+        .filter(|task| task.route == Some(route_id))
+        .cloned()
+        .collect();
+    for task in tasks {
+        cancel_task(&task);
+    }
+}
+
+/// Cancel every unfinished task.
+pub fn cancel_all() {
+    let tasks: Vec<Arc<Task>> = get_runtime()
+        .tasks
+        .lock()
+        .unwrap()
+        .values()
+        .cloned()
+        .collect();
+    for task in tasks {
+        cancel_task(&task);
+    }
+    get_runtime().queue.lock().unwrap().ready.clear();
+}
+
+/// Stop polling tasks on the worker thread; `tick` and `run_until_idle` poll them instead.
+pub fn use_manual_executor() {
+    get_runtime().manual.store(true, Ordering::SeqCst);
+}
+
+/// Poll every task that is ready now, on the calling thread. Returns how many were polled.
+pub fn tick() -> usize {
+    let ready: Vec<Arc<Task>> = get_runtime()
+        .queue
+        .lock()
+        .unwrap()
+        .ready
+        .drain(..)
+        .collect();
+    for task in &ready {
+        poll_task(task);
+    }
+    ready.len()
+}
+
+/// `tick` until no task is ready. Tasks waiting on something outside the
+/// runtime (e.g. `blocking`) stay pending.
+pub fn run_until_idle() -> usize {
+    let mut polled = 0;
+    loop {
+        let n = tick();
+        if n == 0 {
+            return polled;
+        }
+        polled += n;
+    }
+}
+
+/// Number of tasks that have neither finished nor been cancelled.
+pub fn pending_tasks() -> usize {
+    get_runtime().tasks.lock().unwrap().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::panics::PanicReport;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_text};
+
+    // Reports caught panics; set as the app once, for every test
+    struct Reporter;
+
+    static REPORTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    impl PadaukApp for Reporter {
+        fn initial_route(&self) -> Route {
+            Route::new("home", || text("Home"))
+        }
+
+        fn on_panic(&self, report: &PanicReport) {
+            panics::log_report(report);
+            REPORTS.lock().unwrap().push(report.message.clone());
+        }
+    }
+
+    struct App(State<i32>);
+
+    impl PadaukApp for App {
+        fn initial_route(&self) -> Route {
+            let count = self.0.clone();
+            Route::new("home", move || text(&format!("Count: {}", count.get())))
+        }
+    }
+
+    // Ready on its second poll
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    #[test]
+    fn route_tasks_are_cancelled_when_their_route_is_popped() {
+        let mut tester = WidgetTester::mount_app(App(state(0)));
+        Navigator::push(Route::new("details", || text("Details")));
+        tester.pump();
+        let for_route = spawn_for_route(std::future::pending());
+        let plain = spawn(std::future::pending());
+
+        assert!(tester.back());
+        assert!(for_route.is_cancelled());
+        assert!(!plain.is_cancelled());
+        assert_eq!(pending_tasks(), 1);
+    }
+
+    #[test]
+    fn states_set_between_two_awaits_redraw_once() {
+        let count = state(0);
+        let mut tester = WidgetTester::mount_app(App(count.clone()));
+        let task = spawn(async move {
+            count.set(1);
+            count.set(2);
+            YieldOnce(false).await;
+            count.set(3);
+        });
+
+        assert_eq!(tester.run_tasks(), 2);
+        assert!(task.is_finished());
+        assert_eq!(tester.redraw_count(), 2);
+        assert!(tester.exists(&by_text("Count: 3")));
+    }
+
+    #[test]
+    fn a_task_that_panics_is_reported_and_finished() {
+        let mut tester = WidgetTester::mount_app(App(state(0)));
+        let _ = crate::APP_INSTANCE.set(Box::new(Reporter));
+        let task = spawn(async { panic!("task failed on purpose") });
+        let next = spawn(async {});
+
+        tester.run_tasks();
+        assert!(task.is_finished());
+        assert!(next.is_finished());
+        assert!(
+            REPORTS
+                .lock()
+                .unwrap()
+                .contains(&"task failed on purpose".to_string())
+        );
+    }
+}
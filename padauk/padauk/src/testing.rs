@@ -0,0 +1,562 @@
+//! Headless harness for testing widgets and apps with plain `cargo test`.
+//!
+//! A `WidgetTester` mounts a `PadaukApp` or a single `Widget`, stands in for the
+//! native renderer registered through `register_render_callback`, and lets tests
+//! find nodes, tap them and inspect the rebuilt tree and the `Navigator` stack.
+//!
+//! Spawned tasks run on a manual executor: call `run_tasks` to drive them.
+//!
+//! Framework state (actions, remembered values, tasks, navigator with its
+//! router, observers and guards, lazy lists, render callback) is global, so
+//! only one tester can be alive at a time; `mount`/`mount_app` block until the
+//! previous tester has been dropped.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, Once};
+
+use crate::runtime;
+use crate::ui::build_context;
+use crate::ui::event_registry::{
+    DragDelta, EventPayload, ScrollPosition, clear_actions, padauk_dispatch_action,
+    padauk_dispatch_event,
+};
+use crate::ui::hooks::clear_hooks;
+use crate::ui::lazy_list::{clear_lazy_lists, lazy_items};
+use crate::ui::navigation::{Navigator, clear_navigation, padauk_nav_can_pop, padauk_nav_pop};
+use crate::ui::navigator_observer::clear_observers;
+use crate::ui::render_callback::{RenderCallback, register_render_callback};
+use crate::ui::route_guard::clear_guards;
+use crate::ui::snackbar::clear_messages;
+use crate::{PadaukApp, UiNode, Widget, padauk_render_root};
+
+static TEST_LOCK: Mutex<()> = Mutex::new(());
+static REDRAWS: AtomicUsize = AtomicUsize::new(0);
+static CALLBACK: Once = Once::new();
+
+// Items of a lazy list "on screen" after `scroll_to`
+const LAZY_WINDOW: usize = 20;
+
+// Records redraw requests instead of recomposing.
+struct TestRenderCallback;
+
+impl RenderCallback for TestRenderCallback {
+    fn on_update(&self) {
+        REDRAWS.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// How to look up nodes in the rendered tree.
+#[derive(Clone, Debug)]
+pub enum Finder {
+    /// Nodes whose own text (text, title or label) equals the string.
+    Text(String),
+    /// Nodes of the given variant, e.g. `"Button"`.
+    Kind(&'static str),
+    /// Nodes tagged with `.test_tag(...)`.
+    TestTag(String),
+}
+
+impl Finder {
+    fn matches(&self, node: &UiNode) -> bool {
+        match self {
+            Finder::Text(text) => node.text() == Some(text.as_str()),
+            Finder::Kind(kind) => node.kind() == *kind,
+            Finder::TestTag(tag) => node.modifiers().test_tag.as_deref() == Some(tag.as_str()),
+        }
+    }
+}
+
+pub fn by_text(text: impl Into<String>) -> Finder {
+    Finder::Text(text.into())
+}
+
+pub fn by_kind(kind: &'static str) -> Finder {
+    Finder::Kind(kind)
+}
+
+pub fn by_test_tag(tag: impl Into<String>) -> Finder {
+    Finder::TestTag(tag.into())
+}
+
+/// A node matched by a `Finder`, with its position in the tree.
+#[derive(Clone, Debug)]
+pub struct FoundNode {
+    /// Child indices from the root (see `UiNode::children`).
+    pub path: Vec<usize>,
+    pub node: UiNode,
+}
+
+enum TestRoot {
+    App,
+    Widget(Box<dyn Widget>),
+}
+
+pub struct WidgetTester {
+    root: TestRoot,
+    tree: UiNode,
+    redraws_at_mount: usize,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl WidgetTester {
+    /// Mount an app: the navigator is reset to `app.initial_route()`.
+    pub fn mount_app<A: PadaukApp>(app: A) -> Self {
+        let guard = Self::prepare();
+        Navigator::reset(app.initial_route());
+        Self::start(TestRoot::App, guard)
+    }
+
+    /// Mount a single widget as the root of the tree.
+    pub fn mount(widget: impl Widget + 'static) -> Self {
+        let guard = Self::prepare();
+        Self::start(TestRoot::Widget(Box::new(widget)), guard)
+    }
+
+    fn prepare() -> MutexGuard<'static, ()> {
+        // A failed test poisons the lock; the state it guards is reset below anyway.
+        let guard = TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        CALLBACK.call_once(|| register_render_callback(Box::new(TestRenderCallback)));
+        clear_actions();
+        clear_hooks();
+        clear_observers();
+        clear_guards();
+        clear_navigation();
+        clear_messages();
+        clear_lazy_lists();
+        runtime::use_manual_executor();
+        runtime::cancel_all();
+        guard
+    }
+
+    fn start(root: TestRoot, guard: MutexGuard<'static, ()>) -> Self {
+        let tree = Self::render(&root);
+        Self {
+            root,
+            tree,
+            redraws_at_mount: REDRAWS.load(Ordering::SeqCst),
+            _guard: guard,
+        }
+    }
+
+    fn render(root: &TestRoot) -> UiNode {
+        match root {
+            TestRoot::App => padauk_render_root(),
+            TestRoot::Widget(widget) => build_context::render_pass(|| widget.build()),
+        }
+    }
+
+    /// Rebuild the tree, as the renderer does after `on_update`.
+    pub fn pump(&mut self) {
+        self.tree = Self::render(&self.root);
+    }
+
+    /// Poll spawned tasks until none is ready, then rebuild the tree.
+    /// Returns how many polls ran.
+    pub fn run_tasks(&mut self) -> usize {
+        let polled = runtime::run_until_idle();
+        self.pump();
+        polled
+    }
+
+    pub fn tree(&self) -> &UiNode {
+        &self.tree
+    }
+
+    /// Number of redraws requested since the tester was mounted.
+    pub fn redraw_count(&self) -> usize {
+        REDRAWS.load(Ordering::SeqCst) - self.redraws_at_mount
+    }
+
+    /// All matching nodes, in depth-first order.
+    pub fn find_all(&self, finder: &Finder) -> Vec<FoundNode> {
+        let mut found = Vec::new();
+        collect(&self.tree, &mut vec![], finder, &mut found);
+        found
+    }
+
+    /// The single matching node. Panics if there are none or several.
+    pub fn find(&self, finder: &Finder) -> FoundNode {
+        let mut found = self.find_all(finder);
+        match found.len() {
+            1 => found.remove(0),
+            n => panic!(
+                "Expected exactly one node for {:?}, found {}.\n{}",
+                finder,
+                n,
+                self.dump()
+            ),
+        }
+    }
+
+    pub fn exists(&self, finder: &Finder) -> bool {
+        !self.find_all(finder).is_empty()
+    }
+
+    pub fn count(&self, finder: &Finder) -> usize {
+        self.find_all(finder).len()
+    }
+
+    /// Tap the single matching node, then pump.
+    ///
+    /// Taps bubble up: tapping a button's label dispatches the button's action.
+    /// A tapped switch is toggled to the other state.
+    pub fn tap(&mut self, finder: &Finder) {
+        let found = self.find(finder);
+        if let UiNode::Switch {
+            checked, action_id, ..
+        } = &found.node
+        {
+            padauk_dispatch_event(
+                action_id.clone(),
+                EventPayload::Toggle { checked: !checked },
+            );
+            self.pump();
+            return;
+        }
+        let action_id = self
+            .action_for(&found.path, UiNode::action_id)
+            .unwrap_or_else(|| panic!("No tappable node at {:?}.\n{}", finder, self.dump()));
+        padauk_dispatch_action(action_id);
+        self.pump();
+    }
+
+    /// Replace the text of the single matching text field, as typing would,
+    /// then pump.
+    pub fn enter_text(&mut self, finder: &Finder, text: impl Into<String>) {
+        let found = self.find(finder);
+        let action_id = found
+            .node
+            .text_action_id()
+            .unwrap_or_else(|| panic!("No text field at {:?}.\n{}", finder, self.dump()));
+        padauk_dispatch_event(
+            action_id.to_string(),
+            EventPayload::Text { value: text.into() },
+        );
+        self.pump();
+    }
+
+    /// Drag the single matching slider to `value`, then pump.
+    pub fn set_value(&mut self, finder: &Finder, value: f32) {
+        self.drag_slider(finder, EventPayload::Value { value });
+    }
+
+    /// Drag the thumbs of the single matching range slider to `start` and
+    /// `end`, then pump.
+    pub fn set_range(&mut self, finder: &Finder, start: f32, end: f32) {
+        self.drag_slider(finder, EventPayload::Range { start, end });
+    }
+
+    /// Tap item `index` of the single matching navigation bar, rail or tab
+    /// row, then pump.
+    pub fn select(&mut self, finder: &Finder, index: usize) {
+        let found = self.find(finder);
+        let action_id = found
+            .node
+            .selection_action_id()
+            .unwrap_or_else(|| panic!("No navigation items at {:?}.\n{}", finder, self.dump()));
+        padauk_dispatch_event(
+            action_id.to_string(),
+            EventPayload::Selection {
+                index: index as u32,
+            },
+        );
+        self.pump();
+    }
+
+    fn drag_slider(&mut self, finder: &Finder, payload: EventPayload) {
+        let found = self.find(finder);
+        let action_id = found
+            .node
+            .value_action_id()
+            .unwrap_or_else(|| panic!("No slider at {:?}.\n{}", finder, self.dump()));
+        padauk_dispatch_event(action_id.to_string(), payload);
+        self.pump();
+    }
+
+    /// Let the snackbar on screen go away on its own, as when it times out,
+    /// then pump. Returns `false` if there's none.
+    pub fn dismiss_snackbar(&mut self) -> bool {
+        let Some(found) = self.find_all(&by_kind("Snackbar")).pop() else {
+            return false;
+        };
+        let action_id = found.node.dismiss_action_id().map(str::to_string);
+        if let Some(action_id) = action_id {
+            padauk_dispatch_action(action_id);
+        }
+        self.pump();
+        true
+    }
+
+    /// Press the keyboard's action key on the single matching text field, then pump.
+    pub fn submit(&mut self, finder: &Finder) {
+        let found = self.find(finder);
+        let action_id = found
+            .node
+            .submit_action_id()
+            .unwrap_or_else(|| panic!("Nothing to submit at {:?}.\n{}", finder, self.dump()));
+        padauk_dispatch_action(action_id.to_string());
+        self.pump();
+    }
+
+    // The action `of` the node at `path` or, failing that, of its nearest
+    // ancestor that has one
+    fn action_for(&self, path: &[usize], of: fn(&UiNode) -> Option<&str>) -> Option<String> {
+        let mut chain = vec![&self.tree];
+        for &index in path {
+            let next = chain.last()?.children()[index];
+            chain.push(next);
+        }
+        chain
+            .iter()
+            .rev()
+            .find_map(|node| of(node).map(str::to_string))
+    }
+
+    /// Drag by `dx`, `dy` dp from the single matching node, then pump. Like
+    /// taps, drags bubble up to the nearest draggable.
+    pub fn drag(&mut self, finder: &Finder, dx: f32, dy: f32) {
+        let found = self.find(finder);
+        let action_id = self
+            .action_for(&found.path, UiNode::drag_action_id)
+            .unwrap_or_else(|| panic!("No draggable node at {:?}.\n{}", finder, self.dump()));
+        padauk_dispatch_event(
+            action_id,
+            EventPayload::Drag {
+                delta: DragDelta { dx, dy },
+            },
+        );
+        self.pump();
+    }
+
+    /// Scroll the content of the single matching scroll view to `y` dp from
+    /// its top, then pump. Does nothing to a scroll view without `on_scroll`.
+    pub fn scroll_to_offset(&mut self, finder: &Finder, y: f32) {
+        let found = self.find(finder);
+        let UiNode::Scroll {
+            scroll_action_id, ..
+        } = &found.node
+        else {
+            panic!("No scroll view at {:?}.\n{}", finder, self.dump());
+        };
+        if let Some(action_id) = scroll_action_id {
+            padauk_dispatch_event(
+                action_id.clone(),
+                EventPayload::Scroll {
+                    position: ScrollPosition { x: 0.0, y },
+                },
+            );
+        }
+        self.pump();
+    }
+
+    /// Scroll the single matching lazy list so item `index` is the first on
+    /// screen, then pump: the items the renderer would show are built into
+    /// the tree.
+    pub fn scroll_to(&mut self, finder: &Finder, index: usize) {
+        let found = self.find(finder);
+        let UiNode::LazyList { list_id, .. } = &found.node else {
+            panic!("No lazy list at {:?}.\n{}", finder, self.dump());
+        };
+        lazy_items(list_id, index, LAZY_WINDOW);
+        self.pump();
+    }
+
+    /// Simulate the system back button. Returns whether the app handled it,
+    /// popping a route or leaving it to a back interceptor; `false` means the
+    /// host would close the app.
+    ///
+    /// While a dialog or bottom sheet is shown, back goes to it as on a
+    /// device: it's dismissed if it can be (and its interceptors agree), and
+    /// nothing else happens.
+    pub fn back(&mut self) -> bool {
+        if let Some(overlay) = last_overlay(&self.tree) {
+            let Some(action_id) = overlay.dismiss_action_id() else {
+                return false;
+            };
+            padauk_dispatch_action(action_id.to_string());
+            self.pump();
+            return true;
+        }
+        if !padauk_nav_can_pop() {
+            return false;
+        }
+        let handled = padauk_nav_pop();
+        self.pump();
+        handled
+    }
+
+    /// Names of the routes on the navigator stack, bottom first.
+    pub fn route_names(&self) -> Vec<String> {
+        Navigator::route_names()
+    }
+
+    pub fn current_route(&self) -> Option<String> {
+        Navigator::route_names().pop()
+    }
+
+    /// Indented outline of the tree, for failure messages.
+    pub fn dump(&self) -> String {
+        let mut out = String::new();
+        dump_node(&self.tree, 0, &mut out);
+        out
+    }
+}
+
+fn collect(node: &UiNode, path: &mut Vec<usize>, finder: &Finder, found: &mut Vec<FoundNode>) {
+    if finder.matches(node) {
+        found.push(FoundNode {
+            path: path.clone(),
+            node: node.clone(),
+        });
+    }
+    for (index, child) in node.children().into_iter().enumerate() {
+        path.push(index);
+        collect(child, path, finder, found);
+        path.pop();
+    }
+}
+
+fn dump_node(node: &UiNode, depth: usize, out: &mut String) {
+    out.push_str(&"  ".repeat(depth));
+    out.push_str(node.kind());
+    if let Some(text) = node.text() {
+        out.push_str(&format!(" {:?}", text));
+    }
+    if let Some(tag) = &node.modifiers().test_tag {
+        out.push_str(&format!(" #{}", tag));
+    }
+    out.push('\n');
+    for child in node.children() {
+        dump_node(child, depth + 1, out);
+    }
+}
+
+// The overlay drawn last (so on top), if any
+fn last_overlay(node: &UiNode) -> Option<&UiNode> {
+    let nested = node.children().into_iter().rev().find_map(last_overlay);
+    let is_overlay = matches!(node.kind(), "Dialog" | "BottomSheet");
+    nested.or(is_overlay.then_some(node))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::children;
+    use crate::prelude::*;
+    use crate::ui::navigation::padauk_open_deep_link;
+
+    struct Counter {
+        count: State<i32>,
+    }
+
+    impl Widget for Counter {
+        fn build(&self) -> UiNode {
+            let count = self.count.clone();
+            column(children![
+                text(&format!("Count: {}", self.count.get())).test_tag("count"),
+                button("Increment", move || count.update(|c| *c += 1)),
+                button("Details", || {
+                    Navigator::push(Route::new("details", || text("Details")))
+                }),
+            ])
+            .build()
+        }
+    }
+
+    struct CounterApp;
+
+    impl PadaukApp for CounterApp {
+        fn initial_route(&self) -> Route {
+            let count = state(0);
+            Route::new("home", move || Counter {
+                count: count.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn finds_nodes_by_text_kind_and_tag() {
+        let tester = WidgetTester::mount(Counter { count: state(3) });
+        assert!(tester.exists(&by_text("Count: 3")));
+        assert!(!tester.exists(&by_text("Count: 4")));
+        assert_eq!(tester.count(&by_kind("Button")), 2);
+        let found = tester.find(&by_test_tag("count"));
+        assert_eq!(found.node.text(), Some("Count: 3"));
+        assert_eq!(found.path, vec![0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected exactly one node")]
+    fn find_panics_on_several_matches() {
+        let tester = WidgetTester::mount(Counter { count: state(0) });
+        tester.find(&by_kind("Button"));
+    }
+
+    #[test]
+    fn tap_dispatches_the_action_and_rebuilds() {
+        let count = state(0);
+        let mut tester = WidgetTester::mount(Counter {
+            count: count.clone(),
+        });
+        tester.tap(&by_text("Increment"));
+        assert_eq!(count.get_untracked(), 1);
+        assert!(tester.exists(&by_text("Count: 1")));
+        assert_eq!(tester.redraw_count(), 1);
+    }
+
+    #[test]
+    fn pump_picks_up_changes_made_outside_taps() {
+        let count = state(0);
+        let mut tester = WidgetTester::mount(Counter {
+            count: count.clone(),
+        });
+        count.set(5);
+        assert!(tester.exists(&by_text("Count: 0")));
+        tester.pump();
+        assert!(tester.exists(&by_text("Count: 5")));
+    }
+
+    #[test]
+    fn mount_app_drives_the_navigator() {
+        let mut tester = WidgetTester::mount_app(CounterApp);
+        assert_eq!(tester.route_names(), vec!["home"]);
+        assert!(!tester.back());
+
+        tester.tap(&by_text("Details"));
+        assert_eq!(tester.current_route().as_deref(), Some("details"));
+        assert!(tester.exists(&by_text("Details")));
+
+        assert!(tester.back());
+        assert_eq!(tester.route_names(), vec!["home"]);
+        assert!(tester.exists(&by_text("Count: 0")));
+    }
+
+    #[test]
+    fn mount_app_resets_the_stack_of_a_previous_test() {
+        {
+            let mut tester = WidgetTester::mount_app(CounterApp);
+            tester.tap(&by_text("Details"));
+        }
+        let tester = WidgetTester::mount_app(CounterApp);
+        assert_eq!(tester.route_names(), vec!["home"]);
+    }
+
+    #[test]
+    fn mount_drops_the_router_and_stack_of_a_previous_test() {
+        {
+            let _tester = WidgetTester::mount_app(CounterApp);
+            Router::new().route("/home", |_| text("Home")).install();
+        }
+        let tester = WidgetTester::mount(Counter { count: state(0) });
+        assert!(tester.route_names().is_empty());
+        assert!(!padauk_open_deep_link("app://host/home".to_string()));
+    }
+
+    #[test]
+    fn dump_outlines_the_tree() {
+        let tester = WidgetTester::mount(Counter { count: state(0) });
+        let dump = tester.dump();
+        assert!(dump.starts_with("Column\n"));
+        assert!(dump.contains("  Text \"Count: 0\" #count\n"));
+    }
+}
@@ -1,4 +1,4 @@
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum AppBarStyle {
     Small,
     CenterAligned,
@@ -6,19 +6,9 @@ pub enum AppBarStyle {
     Large,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, Default, PartialEq)]
 pub struct AppBarStyleOptions {
     pub container_color: Option<crate::ui::color::ColorValue>,
     pub title_color: Option<crate::ui::color::ColorValue>,
     pub nav_icon_color: Option<crate::ui::color::ColorValue>,
 }
-
-impl Default for AppBarStyleOptions {
-    fn default() -> Self {
-        Self {
-            container_color: None,
-            title_color: None,
-            nav_icon_color: None,
-        }
-    }
-}
@@ -0,0 +1,70 @@
+use crate::ui::{hooks, navigation};
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// What a back interceptor did with a back press.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackDecision {
+    /// Go on with the default: pop the route.
+    Pop,
+    /// Handled here (e.g. by asking "Discard changes?"); don't pop.
+    Handled,
+}
+
+pub type BackInterceptor = Arc<dyn Fn() -> BackDecision + Send + Sync>;
+
+// An interceptor registered by a widget of a route's page
+#[derive(Clone)]
+struct Entry {
+    route: Option<u64>,
+    // Navigator the page belongs to; the interceptor runs in it
+    scope: Option<String>,
+    interceptor: BackInterceptor,
+}
+
+// Interceptors registered by the last complete render pass, in build order
+static INTERCEPTORS: OnceLock<Mutex<Vec<Entry>>> = OnceLock::new();
+
+fn get_interceptors() -> &'static Mutex<Vec<Entry>> {
+    INTERCEPTORS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+thread_local! {
+    static PASS: RefCell<Vec<Entry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Intercept back presses while the calling widget is built on the top route
+/// of its navigator: the system back button and gesture, and the back button
+/// `Scaffold` puts in the app bar. Call it from `build`.
+///
+/// Interceptors of the innermost widgets run first, then the route's own
+/// (`Route::on_back`); the first to return `BackDecision::Handled` stops the pop.
+pub fn use_back_handler(interceptor: impl Fn() -> BackDecision + Send + Sync + 'static) {
+    let entry = Entry {
+        route: hooks::current_route(),
+        scope: navigation::current_scope(),
+        interceptor: Arc::new(interceptor),
+    };
+    PASS.with(|pass| pass.borrow_mut().push(entry));
+}
+
+pub(crate) fn begin_render_pass() {
+    PASS.with(|pass| pass.borrow_mut().clear());
+}
+
+pub(crate) fn end_render_pass() {
+    let pass = PASS.with(|pass| std::mem::take(&mut *pass.borrow_mut()));
+    *get_interceptors().lock().unwrap() = pass;
+}
+
+/// Interceptors registered by the page of `route`, innermost first, with the
+/// navigator each runs in.
+pub(crate) fn interceptors_for(route: u64) -> Vec<(Option<String>, BackInterceptor)> {
+    let interceptors = get_interceptors().lock().unwrap();
+    interceptors
+        .iter()
+        .rev()
+        .filter(|entry| entry.route == Some(route))
+        .map(|entry| (entry.scope.clone(), entry.interceptor.clone()))
+        .collect()
+}
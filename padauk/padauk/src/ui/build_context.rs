@@ -0,0 +1,95 @@
+use crate::ui::{back_handler, event_registry, hooks, lazy_list, navigation, reactive, snackbar};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+
+// Position of the widget currently being built, as a list of segments from the root.
+// Containers push a segment for each child they build, so a widget rebuilt at the
+// same place in the tree sees the same path on every render pass.
+thread_local! {
+    static PATH: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Build a full tree. Actions registered by the previous pass that this pass
+/// did not register again are dropped at the end, as are remembered values
+/// of widgets the pass no longer built.
+///
+/// If `build` panics, nothing is dropped: the previous pass stays in effect.
+pub fn render_pass<R>(build: impl FnOnce() -> R) -> R {
+    let saved = PATH.with(|path| std::mem::take(&mut *path.borrow_mut()));
+    event_registry::begin_render_pass();
+    hooks::begin_render_pass();
+    reactive::begin_render_pass();
+    navigation::begin_render_pass();
+    back_handler::begin_render_pass();
+    lazy_list::begin_render_pass();
+    snackbar::begin_render_pass();
+    let result = panic::catch_unwind(AssertUnwindSafe(build));
+    match &result {
+        Ok(_) => {
+            lazy_list::end_render_pass();
+            back_handler::end_render_pass();
+            navigation::end_render_pass();
+            reactive::end_render_pass();
+            hooks::end_render_pass();
+            event_registry::end_render_pass();
+        }
+        Err(_) => reactive::abort_render_pass(),
+    }
+    PATH.with(|path| *path.borrow_mut() = saved);
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+/// Build the child at `index` of the current widget. A keyed child is
+/// identified by its key instead, so it keeps its path when it moves.
+pub fn child<R>(index: usize, key: Option<&str>, build: impl FnOnce() -> R) -> R {
+    match key {
+        Some(key) => scoped(format!("@{}", key), build),
+        None => scoped(index.to_string(), build),
+    }
+}
+
+/// Build the child in the named slot (e.g. a scaffold's `"body"`).
+pub fn slot<R>(name: &str, build: impl FnOnce() -> R) -> R {
+    scoped(name.to_string(), build)
+}
+
+fn scoped<R>(segment: String, build: impl FnOnce() -> R) -> R {
+    PATH.with(|path| path.borrow_mut().push(segment));
+    let result = build();
+    PATH.with(|path| path.borrow_mut().pop());
+    result
+}
+
+/// Build more of the tree at a position recorded with `segments`, after the
+/// render pass that recorded it (e.g. list items built on demand).
+pub(crate) fn at<R>(segments: Vec<String>, build: impl FnOnce() -> R) -> R {
+    let saved = PATH.with(|path| std::mem::replace(&mut *path.borrow_mut(), segments));
+    let result = build();
+    PATH.with(|path| *path.borrow_mut() = saved);
+    result
+}
+
+pub(crate) fn segments() -> Vec<String> {
+    PATH.with(|path| path.borrow().clone())
+}
+
+// Number of segments in the current path, so a caught panic can rewind to it.
+pub(crate) fn depth() -> usize {
+    PATH.with(|path| path.borrow().len())
+}
+
+pub(crate) fn truncate(depth: usize) {
+    PATH.with(|path| path.borrow_mut().truncate(depth));
+}
+
+/// The current position, e.g. `"/body/0/2"`. The root is `"/"`.
+pub fn current_path() -> String {
+    PATH.with(|path| {
+        let path = path.borrow();
+        if path.is_empty() {
+            "/".to_string()
+        } else {
+            path.iter().map(|segment| format!("/{}", segment)).collect()
+        }
+    })
+}
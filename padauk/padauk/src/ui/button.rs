@@ -1,4 +1,4 @@
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum ButtonStyle {
     Filled,
     FilledTonal,
@@ -7,14 +7,14 @@ pub enum ButtonStyle {
     Text,
 }
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum ButtonShape {
     Default,
     Rounded,
     Pill,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
 pub struct ButtonStyleOptions {
     pub enabled: bool,
     pub shape: ButtonShape,
@@ -41,7 +41,7 @@ impl Default for ButtonStyleOptions {
     }
 }
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum IconButtonStyle {
     Standard,
     Filled,
@@ -49,7 +49,7 @@ pub enum IconButtonStyle {
     Outlined,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
 pub struct IconButtonOptions {
     pub enabled: bool,
     pub shape: ButtonShape,
@@ -68,7 +68,7 @@ impl Default for IconButtonOptions {
     }
 }
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum FabStyle {
     Small,
     Normal,
@@ -76,7 +76,7 @@ pub enum FabStyle {
     Extended,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
 pub struct FabOptions {
     pub shape: ButtonShape,
     pub container_color: Option<crate::ui::color::ColorValue>,
@@ -95,7 +95,7 @@ impl Default for FabOptions {
     }
 }
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum IconType {
     Add,
     Close,
@@ -103,4 +103,9 @@ pub enum IconType {
     Favorite,
     Search,
     Person,
+    Home,
+    Settings,
+    Notifications,
+    Email,
+    Star,
 }
@@ -1,20 +1,20 @@
 use crate::ui::color::ColorValue;
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum CardStyle {
     Filled,
     Elevated,
     Outlined,
 }
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum CardShape {
     Default,
     Rounded,
     Pill,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
 pub struct CardStyleOptions {
     pub enabled: bool,
     pub shape: CardShape,
@@ -1,6 +1,6 @@
 use crate::ui::color::ColorValue;
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum ChipStyle {
     Assist,
     Filter,
@@ -8,13 +8,13 @@ pub enum ChipStyle {
     Suggestion,
 }
 
-#[derive(uniffi::Enum, Clone, Copy, Debug)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
 pub enum ChipShape {
     Default,
     Pill,
 }
 
-#[derive(uniffi::Record, Clone, Debug)]
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
 pub struct ChipStyleOptions {
     pub enabled: bool,
     pub shape: ChipShape,
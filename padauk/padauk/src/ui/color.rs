@@ -1,4 +1,4 @@
-#[derive(uniffi::Enum, Clone, Debug)]
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
 pub enum ColorValue {
     Rgb { r: u8, g: u8, b: u8, a: u8 },
     Hex { value: String },
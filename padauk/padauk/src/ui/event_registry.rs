@@ -1,34 +1,333 @@
-use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
 use log::{debug, warn};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::panics;
+use crate::ui::render_callback::batch;
+use crate::ui::{build_context, navigation};
+
+pub type ActionHandler = Arc<dyn Fn() + Send + Sync>;
+
+/// Handler of an event that carries a `T`, e.g. the new text of a field.
+pub type EventHandler<T> = Arc<dyn Fn(T) + Send + Sync>;
+
+// Position of a scrolling container, in dp from its start
+#[derive(uniffi::Record, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ScrollPosition {
+    pub x: f32,
+    pub y: f32,
+}
+
+// Movement of a drag since the last event, in dp
+#[derive(uniffi::Record, Clone, Copy, Debug, Default, PartialEq)]
+pub struct DragDelta {
+    pub dx: f32,
+    pub dy: f32,
+}
+
+/// What an event carries from the renderer to its handler, sent with
+/// `padauk_dispatch_event`.
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
+pub enum EventPayload {
+    Text { value: String },
+    Value { value: f32 },
+    Range { start: f32, end: f32 },
+    Toggle { checked: bool },
+    Selection { index: u32 },
+    Scroll { position: ScrollPosition },
+    Drag { delta: DragDelta },
+}
+
+/// A type an event handler can take, read out of the event's payload.
+pub trait EventData: Sized + 'static {
+    /// Gives the payload back if it's of another kind.
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload>;
+}
+
+impl EventData for EventPayload {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        Ok(payload)
+    }
+}
+
+impl EventData for String {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Text { value } => Ok(value),
+            other => Err(other),
+        }
+    }
+}
+
+impl EventData for f32 {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Value { value } => Ok(value),
+            other => Err(other),
+        }
+    }
+}
+
+impl EventData for (f32, f32) {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Range { start, end } => Ok((start, end)),
+            other => Err(other),
+        }
+    }
+}
+
+impl EventData for bool {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Toggle { checked } => Ok(checked),
+            other => Err(other),
+        }
+    }
+}
+
+impl EventData for usize {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Selection { index } => Ok(index as usize),
+            other => Err(other),
+        }
+    }
+}
+
+impl EventData for ScrollPosition {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Scroll { position } => Ok(position),
+            other => Err(other),
+        }
+    }
+}
+
+impl EventData for DragDelta {
+    fn from_payload(payload: EventPayload) -> Result<Self, EventPayload> {
+        match payload {
+            EventPayload::Drag { delta } => Ok(delta),
+            other => Err(other),
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Handler {
+    Action(ActionHandler),
+    // Reads its data out of the payload
+    Event(Arc<dyn Fn(EventPayload) + Send + Sync>),
+}
+
+struct ActionEntry {
+    handler: Handler,
+    // Nested navigator whose page registered the action; the handler runs in it
+    scope: Option<String>,
+    // Render pass that last registered this action
+    generation: u64,
+}
 
-// Define the type for our registry
-type ActionMap = Mutex<HashMap<String, Box<dyn Fn() + Send + Sync>>>;
+#[derive(Default)]
+struct Registry {
+    actions: HashMap<String, ActionEntry>,
+    generation: u64,
+    registered_last_pass: u64,
+    dropped_last_pass: u64,
+}
+
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct ActionMetrics {
+    /// Actions currently registered.
+    pub live: u64,
+    /// Actions registered by the last completed render pass.
+    pub registered_last_pass: u64,
+    /// Actions dropped at the end of the last render pass.
+    pub dropped_last_pass: u64,
+    /// Number of render passes so far.
+    pub generation: u64,
+}
 
 // Use OnceLock to handle the static initialization safely
-static ACTIONS: OnceLock<ActionMap> = OnceLock::new();
+static ACTIONS: OnceLock<Mutex<Registry>> = OnceLock::new();
 
 // Helper to get the registry, initializing it if necessary
-fn get_actions() -> &'static ActionMap {
-    ACTIONS.get_or_init(|| Mutex::new(HashMap::new()))
+fn get_registry() -> &'static Mutex<Registry> {
+    ACTIONS.get_or_init(|| Mutex::new(Registry::default()))
 }
 
 pub fn register_action(id: String, f: impl Fn() + Send + Sync + 'static) {
+    register_handler(id, Arc::new(f));
+}
+
+pub fn register_handler(id: String, handler: ActionHandler) {
+    debug!("Register action: {}", id);
+    let mut registry = get_registry().lock().unwrap();
+    let generation = registry.generation;
+    registry.actions.insert(
+        id,
+        ActionEntry {
+            handler: Handler::Action(handler),
+            scope: navigation::current_scope(),
+            generation,
+        },
+    );
+}
+
+/// Register `handler` under an id derived from the building widget's position,
+/// so the id stays the same across rebuilds. `name` tells apart several actions
+/// of one widget (e.g. a chip's `"click"` and `"close"`).
+pub fn register_scoped_action(name: &str, handler: ActionHandler) -> String {
+    register_scoped(name, Handler::Action(handler))
+}
+
+/// Like `register_scoped_action`, for an event dispatched with a payload by
+/// `padauk_dispatch_event` (e.g. a text field's `"change"`, with the new
+/// text). Payloads of another kind than `T` are logged and dropped.
+pub fn register_scoped_event_action<T: EventData>(name: &str, handler: EventHandler<T>) -> String {
+    register_scoped(
+        name,
+        Handler::Event(Arc::new(
+            move |payload: EventPayload| match T::from_payload(payload) {
+                Ok(data) => handler(data),
+                Err(payload) => warn!(
+                    "Event {:?} doesn't fit a {} handler.",
+                    payload,
+                    std::any::type_name::<T>()
+                ),
+            },
+        )),
+    )
+}
+
+fn register_scoped(name: &str, handler: Handler) -> String {
+    let base = format!("{}#{}", build_context::current_path(), name);
+    let mut registry = get_registry().lock().unwrap();
+    let generation = registry.generation;
+
+    // Two widgets built at the same position in one pass (e.g. a custom widget
+    // building children by hand) get numbered ids, in build order.
+    let mut id = base.clone();
+    let mut n = 1;
+    while registry
+        .actions
+        .get(&id)
+        .is_some_and(|entry| entry.generation == generation)
+    {
+        id = format!("{}~{}", base, n);
+        n += 1;
+    }
+
     debug!("Register action: {}", id);
-    get_actions().lock().unwrap().insert(id, Box::new(f));
+    registry.actions.insert(
+        id.clone(),
+        ActionEntry {
+            handler,
+            scope: navigation::current_scope(),
+            generation,
+        },
+    );
+    id
+}
+
+/// Drop the actions registered at `path` and below, so a widget built there
+/// again outside a render pass (e.g. a lazy list item fetched twice) takes its
+/// ids back rather than numbered ones.
+pub(crate) fn forget_actions_under(path: &str) {
+    let here = format!("{}#", path);
+    let below = format!("{}/", path);
+    get_registry()
+        .lock()
+        .unwrap()
+        .actions
+        .retain(|id, _| !id.starts_with(&here) && !id.starts_with(&below));
+}
+
+pub fn begin_render_pass() {
+    let mut registry = get_registry().lock().unwrap();
+    registry.generation += 1;
+}
+
+/// Drop every action the pass that just finished didn't register.
+pub fn end_render_pass() {
+    let mut registry = get_registry().lock().unwrap();
+    let generation = registry.generation;
+    let before = registry.actions.len();
+    registry
+        .actions
+        .retain(|_, entry| entry.generation == generation);
+    let live = registry.actions.len();
+    registry.registered_last_pass = live as u64;
+    registry.dropped_last_pass = (before - live) as u64;
+    debug!(
+        "Render pass {} done: {} actions live, {} dropped.",
+        generation,
+        live,
+        before - live
+    );
 }
 
 pub fn clear_actions() {
     debug!("Clear all actions.");
-    get_actions().lock().unwrap().clear();
+    get_registry().lock().unwrap().actions.clear();
+}
+
+pub fn action_metrics() -> ActionMetrics {
+    let registry = get_registry().lock().unwrap();
+    ActionMetrics {
+        live: registry.actions.len() as u64,
+        registered_last_pass: registry.registered_last_pass,
+        dropped_last_pass: registry.dropped_last_pass,
+        generation: registry.generation,
+    }
+}
+
+#[uniffi::export]
+pub fn padauk_action_metrics() -> ActionMetrics {
+    action_metrics()
+}
+
+// Release the lock before running the handler so it can register or dispatch actions.
+fn lookup(id: &str) -> Option<(Handler, Option<String>)> {
+    let handler = get_registry()
+        .lock()
+        .unwrap()
+        .actions
+        .get(id)
+        .map(|entry| (entry.handler.clone(), entry.scope.clone()));
+    if handler.is_none() {
+        warn!("No action registered for id: {}", id);
+    }
+    handler
+}
+
+fn run(id: &str, scope: Option<String>, f: impl FnOnce()) {
+    // However many states the handler touches, the event redraws once.
+    let result = panics::catch(&format!("action {}", id), || {
+        navigation::with_scope(scope, || batch(f))
+    });
+    if let Err(report) = result {
+        panics::report(&report);
+    }
 }
 
 #[uniffi::export]
 pub fn padauk_dispatch_action(id: String) {
     debug!("Dispatch action: {}", id);
-    if let Some(f) = get_actions().lock().unwrap().get(&id) {
-        f();
-    } else {
-        warn!("No action registered for id: {}", id);
+    match lookup(&id) {
+        Some((Handler::Action(f), scope)) => run(&id, scope, || f()),
+        Some((Handler::Event(_), _)) => warn!("Action {} expects a payload.", id),
+        None => {}
+    }
+}
+
+/// Dispatch an event with its payload, e.g. the new text of a text field.
+#[uniffi::export]
+pub fn padauk_dispatch_event(id: String, payload: EventPayload) {
+    debug!("Dispatch event: {} {:?}", id, payload);
+    match lookup(&id) {
+        Some((Handler::Event(f), scope)) => run(&id, scope, || f(payload)),
+        Some((Handler::Action(_), _)) => warn!("Action {} doesn't take a payload.", id),
+        None => {}
     }
 }
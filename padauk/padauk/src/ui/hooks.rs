@@ -0,0 +1,153 @@
+use crate::ui::build_context;
+use crate::ui::state::State;
+use log::debug;
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, OnceLock};
+
+// Values remembered by widgets between builds, keyed by the widget's path and
+// the order of the hook calls inside its build. Each value belongs to the route
+// that was being built, so it outlives builds of other routes pushed on top of it.
+struct HookEntry {
+    route: Option<u64>,
+    generation: u64,
+    value: Box<dyn Any + Send + Sync>,
+}
+
+#[derive(Default)]
+struct HookStore {
+    entries: HashMap<String, HookEntry>,
+    generation: u64,
+    rendered_routes: HashSet<Option<u64>>,
+}
+
+static HOOKS: OnceLock<Mutex<HookStore>> = OnceLock::new();
+
+fn get_hooks() -> &'static Mutex<HookStore> {
+    HOOKS.get_or_init(|| Mutex::new(HookStore::default()))
+}
+
+thread_local! {
+    // Route whose page is being built
+    static CURRENT_ROUTE: Cell<Option<u64>> = const { Cell::new(None) };
+    // Hook calls made so far at each path in this pass
+    static CALL_COUNTS: RefCell<HashMap<String, usize>> = RefCell::new(HashMap::new());
+}
+
+/// Remember a value across rebuilds of the calling widget.
+///
+/// `init` runs on the first build; later builds get a clone of the stored value.
+/// The value is dropped when the widget is no longer built, or when its route
+/// is removed from the `Navigator`. Hooks are told apart by call order, so call
+/// them unconditionally, in the same order on every build.
+pub fn remember<T: Clone + Send + Sync + 'static>(init: impl FnOnce() -> T) -> T {
+    let path = build_context::current_path();
+    let index = CALL_COUNTS.with(|counts| {
+        let mut counts = counts.borrow_mut();
+        let count = counts.entry(path.clone()).or_insert(0);
+        *count += 1;
+        *count - 1
+    });
+    let id = format!("{}${}", path, index);
+    let route = CURRENT_ROUTE.with(Cell::get);
+
+    {
+        let mut store = get_hooks().lock().unwrap();
+        let generation = store.generation;
+        if let Some(entry) = store.entries.get_mut(&id)
+            && entry.route == route
+            && let Some(value) = entry.value.downcast_ref::<T>()
+        {
+            entry.generation = generation;
+            return value.clone();
+        }
+    }
+
+    // Not the lock holder while `init` runs, in case it builds widgets itself.
+    let value = init();
+    debug!("Remember new value: {}", id);
+    let mut store = get_hooks().lock().unwrap();
+    let generation = store.generation;
+    store.entries.insert(
+        id,
+        HookEntry {
+            route,
+            generation,
+            value: Box::new(value.clone()),
+        },
+    );
+    value
+}
+
+/// A `State` owned by the calling widget; see `remember`.
+pub fn use_state<T: Clone + Send + Sync + 'static>(init: impl FnOnce() -> T) -> State<T> {
+    remember(|| State::new(init()))
+}
+
+/// Build the page of route `route_id`; hooks called inside belong to that route.
+pub(crate) fn with_route<R>(route_id: u64, build: impl FnOnce() -> R) -> R {
+    let previous = CURRENT_ROUTE.with(|current| current.replace(Some(route_id)));
+    get_hooks()
+        .lock()
+        .unwrap()
+        .rendered_routes
+        .insert(Some(route_id));
+    let result = build();
+    CURRENT_ROUTE.with(|current| current.set(previous));
+    result
+}
+
+pub(crate) fn current_route() -> Option<u64> {
+    CURRENT_ROUTE.with(Cell::get)
+}
+
+pub(crate) fn set_current_route(route: Option<u64>) {
+    CURRENT_ROUTE.with(|current| current.set(route));
+}
+
+/// Forget the hook calls made at `path` and below, so a widget built there
+/// again outside a render pass gets its own values back rather than new ones.
+pub(crate) fn forget_calls_under(path: &str) {
+    let prefix = format!("{}/", path);
+    CALL_COUNTS.with(|counts| {
+        counts
+            .borrow_mut()
+            .retain(|call_path, _| call_path != path && !call_path.starts_with(&prefix))
+    });
+}
+
+pub(crate) fn begin_render_pass() {
+    CALL_COUNTS.with(|counts| counts.borrow_mut().clear());
+    let mut store = get_hooks().lock().unwrap();
+    store.generation += 1;
+    store.rendered_routes.clear();
+    // Hooks outside any route (e.g. a bare widget root) belong to every pass.
+    store.rendered_routes.insert(None);
+}
+
+/// Drop values of widgets that the routes built in this pass no longer contain.
+pub(crate) fn end_render_pass() {
+    let mut store = get_hooks().lock().unwrap();
+    let HookStore {
+        entries,
+        generation,
+        rendered_routes,
+    } = &mut *store;
+    entries.retain(|_, entry| {
+        entry.generation == *generation || !rendered_routes.contains(&entry.route)
+    });
+}
+
+/// Drop every value remembered by the pages of a removed route.
+pub(crate) fn drop_route(route_id: u64) {
+    let mut store = get_hooks().lock().unwrap();
+    store
+        .entries
+        .retain(|_, entry| entry.route != Some(route_id));
+}
+
+pub fn clear_hooks() {
+    debug!("Clear all remembered values.");
+    get_hooks().lock().unwrap().entries.clear();
+}
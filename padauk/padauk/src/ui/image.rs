@@ -1,7 +1,7 @@
 use crate::UiNode;
-use crate::{Widget, impl_modifiers, ui::modifier::Modifiers};
+use crate::{Widget, impl_key, impl_modifiers, ui::modifier::Modifiers};
 
-#[derive(uniffi::Enum, Clone)]
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
 pub enum BoxFit {
     Contain,
     Cover,
@@ -12,7 +12,7 @@ pub enum BoxFit {
     ScaleDown,
 }
 
-#[derive(uniffi::Enum, Clone)]
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
 pub enum ImageSource {
     Asset { name: String },
     Network { url: String },
@@ -24,6 +24,7 @@ pub struct Image {
     pub source: ImageSource,
     pub fit: BoxFit,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl Image {
@@ -34,6 +35,7 @@ impl Image {
             source: ImageSource::Asset { name: name.into() },
             fit: BoxFit::Contain, // Default
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -42,6 +44,7 @@ impl Image {
             source: ImageSource::Network { url: url.into() },
             fit: BoxFit::Contain,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -50,6 +53,7 @@ impl Image {
             source: ImageSource::File { path: path.into() },
             fit: BoxFit::Contain,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -58,6 +62,7 @@ impl Image {
             source: ImageSource::Memory { data },
             fit: BoxFit::Contain,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -70,6 +75,7 @@ impl Image {
 }
 
 impl_modifiers!(Image);
+impl_key!(Image);
 
 impl Widget for Image {
     fn build(&self) -> UiNode {
@@ -77,8 +83,13 @@ impl Widget for Image {
             source: self.source.clone(),
             fit: self.fit.clone(),
             modifiers: self.modifiers.clone(),
+            key: self.key.clone(),
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 // DSL Helpers (Optional, or rely on Image::*)
@@ -1,8 +1,37 @@
 // Alignment enum for Column/Row
-#[derive(uniffi::Enum, Clone)]
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
 pub enum MainAxisAlignment {
+    #[default]
     Start,
     Center,
     End,
     SpaceBetween,
+    // Equal space around each child (half of it at the ends)
+    SpaceAround,
+    // Equal space between the children and at the ends
+    SpaceEvenly,
+}
+
+// Where children sit across the axis: horizontally in a Column, vertically in a Row
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum CrossAxisAlignment {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+// Position of a child inside a Stack
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum Alignment {
+    #[default]
+    TopStart,
+    TopCenter,
+    TopEnd,
+    CenterStart,
+    Center,
+    CenterEnd,
+    BottomStart,
+    BottomCenter,
+    BottomEnd,
 }
@@ -0,0 +1,405 @@
+use crate::panics;
+use crate::ui::{build_context, event_registry, hooks, navigation, reactive};
+use crate::{UiNode, Widget, impl_key, impl_modifiers, ui::modifier::Modifiers};
+use log::{debug, warn};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+// Items built with the list on its first render, before the renderer has
+// asked for any
+const INITIAL_WINDOW: usize = 20;
+
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
+pub enum GridCells {
+    /// This many columns.
+    Fixed { count: u32 },
+    /// As many columns as fit, each at least `min_size` dp wide.
+    Adaptive { min_size: f32 },
+}
+
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
+pub enum LazyLayout {
+    Column,
+    Row,
+    // Scrolls vertically
+    Grid { cells: GridCells },
+}
+
+pub type ItemBuilder = Arc<dyn Fn(usize) -> Box<dyn Widget> + Send + Sync>;
+pub type ItemKey = Arc<dyn Fn(usize) -> String + Send + Sync>;
+
+// What the renderer needs to build items of a list after its render pass
+struct Source {
+    builder: ItemBuilder,
+    item_key: Option<ItemKey>,
+    item_count: usize,
+    // Where the list was built
+    segments: Vec<String>,
+    route: Option<u64>,
+    scope: Option<String>,
+    // Items the renderer asked for last; the next render builds them with the list
+    window: Mutex<Range<usize>>,
+    // Times the renderer asked for items since the list was first built
+    fetches: AtomicU32,
+}
+
+impl Source {
+    fn build_item(&self, index: usize) -> UiNode {
+        let key = self.item_key.as_ref().map(|item_key| item_key(index));
+        let mut node = build_context::child(index, key.as_deref(), || {
+            // The item may have been built already since the last render pass
+            let path = build_context::current_path();
+            hooks::forget_calls_under(&path);
+            event_registry::forget_actions_under(&path);
+            (self.builder)(index).build()
+        });
+        if key.is_some() {
+            node.set_key(key);
+        }
+        node
+    }
+
+    // Empty if the items aren't keyed
+    fn keys(&self, range: Range<usize>) -> Vec<String> {
+        match &self.item_key {
+            Some(item_key) => range.map(|i| item_key(i)).collect(),
+            None => vec![],
+        }
+    }
+}
+
+// Lists built by the last complete render pass, by id
+static SOURCES: OnceLock<Mutex<HashMap<String, Arc<Source>>>> = OnceLock::new();
+
+fn get_sources() -> &'static Mutex<HashMap<String, Arc<Source>>> {
+    SOURCES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+thread_local! {
+    static PASS: RefCell<HashMap<String, Arc<Source>>> = RefCell::new(HashMap::new());
+}
+
+#[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+pub(crate) fn clear_lazy_lists() {
+    get_sources().lock().unwrap().clear();
+}
+
+pub(crate) fn begin_render_pass() {
+    PASS.with(|pass| pass.borrow_mut().clear());
+}
+
+pub(crate) fn end_render_pass() {
+    let pass = PASS.with(|pass| std::mem::take(&mut *pass.borrow_mut()));
+    *get_sources().lock().unwrap() = pass;
+}
+
+/// A scrolling list or grid that builds only the items on screen. The renderer
+/// asks for items as they scroll into view, through `padauk_lazy_items`; items
+/// it hasn't asked for are never built.
+pub struct LazyList {
+    pub layout: LazyLayout,
+    pub item_count: usize,
+    pub builder: ItemBuilder,
+    pub item_key: Option<ItemKey>,
+    pub sticky_headers: Vec<usize>,
+    pub content_padding: f32,
+    pub spacing: f32,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(LazyList);
+impl_key!(LazyList);
+
+impl Widget for LazyList {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS lazy stacks; build the first items for now
+            UiNode::ScrollView {
+                views: (0..self.item_count.min(INITIAL_WINDOW))
+                    .map(|i| build_context::child(i, None, || (self.builder)(i).build()))
+                    .collect(),
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let route = hooks::current_route();
+            let list_id = match route {
+                Some(route) => format!("{}:{}", route, build_context::current_path()),
+                None => build_context::current_path(),
+            };
+            let (window, fetches) = get_sources()
+                .lock()
+                .unwrap()
+                .get(&list_id)
+                .map(|previous| {
+                    let window = previous.window.lock().unwrap().clone();
+                    (window, previous.fetches.load(Ordering::SeqCst))
+                })
+                .unwrap_or((0..INITIAL_WINDOW, 0));
+            let window = window.start.min(self.item_count)..window.end.min(self.item_count);
+
+            let source = Arc::new(Source {
+                builder: self.builder.clone(),
+                item_key: self.item_key.clone(),
+                item_count: self.item_count,
+                segments: build_context::segments(),
+                route,
+                scope: navigation::current_scope(),
+                window: Mutex::new(window.clone()),
+                fetches: AtomicU32::new(fetches),
+            });
+            let items = window.clone().map(|i| source.build_item(i)).collect();
+            let item_keys = source.keys(window.clone());
+            PASS.with(|pass| pass.borrow_mut().insert(list_id.clone(), source));
+
+            UiNode::LazyList {
+                list_id,
+                layout: self.layout,
+                item_count: self.item_count as u32,
+                first_index: window.start as u32,
+                fetches,
+                items,
+                keyed: self.item_key.is_some(),
+                item_keys,
+                sticky_headers: self.sticky_headers.iter().map(|&i| i as u32).collect(),
+                content_padding: self.content_padding,
+                spacing: self.spacing,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl LazyList {
+    pub fn new(
+        layout: LazyLayout,
+        item_count: usize,
+        builder: impl Fn(usize) -> Box<dyn Widget> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            layout,
+            item_count,
+            builder: Arc::new(builder),
+            item_key: None,
+            sticky_headers: vec![],
+            content_padding: 0.0,
+            spacing: 0.0,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// Identity of the item at each index, so items keep their state (and
+    /// the scroll position stays put) when items are inserted or removed.
+    /// It's called for the items near the screen, as the renderer asks for them.
+    pub fn item_key(mut self, item_key: impl Fn(usize) -> String + Send + Sync + 'static) -> Self {
+        self.item_key = Some(Arc::new(item_key));
+        self
+    }
+
+    /// Indices of the items that are section headers. In a column or row they
+    /// stick to the start while their section scrolls; in a grid they span
+    /// the full width.
+    pub fn sticky_headers(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.sticky_headers = indices.into_iter().collect();
+        self.sticky_headers.sort_unstable();
+        self.sticky_headers.dedup();
+        self
+    }
+
+    /// Padding around the items, inside the scrolling area, in dp.
+    pub fn content_padding(mut self, value: f32) -> Self {
+        self.content_padding = value;
+        self
+    }
+
+    /// Gap between items (and grid columns), in dp.
+    pub fn spacing(mut self, value: f32) -> Self {
+        self.spacing = value;
+        self
+    }
+}
+
+pub fn lazy_column(
+    item_count: usize,
+    builder: impl Fn(usize) -> Box<dyn Widget> + Send + Sync + 'static,
+) -> LazyList {
+    LazyList::new(LazyLayout::Column, item_count, builder)
+}
+
+pub fn lazy_row(
+    item_count: usize,
+    builder: impl Fn(usize) -> Box<dyn Widget> + Send + Sync + 'static,
+) -> LazyList {
+    LazyList::new(LazyLayout::Row, item_count, builder)
+}
+
+pub fn lazy_grid(
+    cells: GridCells,
+    item_count: usize,
+    builder: impl Fn(usize) -> Box<dyn Widget> + Send + Sync + 'static,
+) -> LazyList {
+    LazyList::new(LazyLayout::Grid { cells }, item_count, builder)
+}
+
+/// Build items `start..start + count` of list `list_id` (clamped to the list).
+/// They're built again with the list on the next render, until other items are
+/// asked for. Returns nothing if the list is no longer on screen.
+pub fn lazy_items(list_id: &str, start: usize, count: usize) -> Vec<UiNode> {
+    let Some(source) = get_sources().lock().unwrap().get(list_id).cloned() else {
+        warn!("Lazy list {} is no longer on screen.", list_id);
+        return vec![];
+    };
+    let end = start.saturating_add(count).min(source.item_count);
+    let start = start.min(end);
+    debug!("Build items {}..{} of lazy list {}", start, end, list_id);
+    *source.window.lock().unwrap() = start..end;
+    source.fetches.fetch_add(1, Ordering::SeqCst);
+
+    build_context::at(source.segments.clone(), || {
+        let built = panics::catch(&format!("lazy list {}", list_id), || {
+            let route = hooks::current_route();
+            hooks::set_current_route(source.route);
+            let items = navigation::with_scope(source.scope.clone(), || {
+                reactive::with_late_reads(|| (start..end).map(|i| source.build_item(i)).collect())
+            });
+            hooks::set_current_route(route);
+            items
+        });
+        built.unwrap_or_else(|report| {
+            panics::report(&report);
+            vec![]
+        })
+    })
+}
+
+/// Keys of items `start..start + count` of list `list_id` (clamped to the
+/// list), or nothing if its items aren't keyed or it's no longer on screen.
+pub fn lazy_keys(list_id: &str, start: usize, count: usize) -> Vec<String> {
+    let Some(source) = get_sources().lock().unwrap().get(list_id).cloned() else {
+        warn!("Lazy list {} is no longer on screen.", list_id);
+        return vec![];
+    };
+    let end = start.saturating_add(count).min(source.item_count);
+    let start = start.min(end);
+    let keys = panics::catch(&format!("lazy list {} keys", list_id), || {
+        reactive::with_late_reads(|| source.keys(start..end))
+    });
+    keys.unwrap_or_else(|report| {
+        panics::report(&report);
+        vec![]
+    })
+}
+
+#[uniffi::export]
+pub fn padauk_lazy_keys(list_id: String, start: u32, count: u32) -> Vec<String> {
+    lazy_keys(&list_id, start as usize, count as usize)
+}
+
+#[uniffi::export]
+pub fn padauk_lazy_items(list_id: String, start: u32, count: u32) -> Vec<UiNode> {
+    lazy_items(&list_id, start as usize, count as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_kind};
+    use crate::ui::event_registry::{action_metrics, padauk_dispatch_action};
+
+    fn list() -> LazyList {
+        lazy_column(100, |i| Box::new(button(format!("Item {}", i), || {})))
+            .item_key(|i| format!("item-{}", i))
+    }
+
+    fn list_id(tester: &WidgetTester) -> String {
+        let UiNode::LazyList { list_id, .. } = tester.find(&by_kind("LazyList")).node else {
+            panic!("No lazy list.\n{}", tester.dump());
+        };
+        list_id
+    }
+
+    #[test]
+    fn overlapping_fetches_take_the_same_action_ids() {
+        let tester = WidgetTester::mount(list());
+        let list_id = list_id(&tester);
+        let first = lazy_items(&list_id, 10, 30);
+        let live = action_metrics().live;
+        let again = lazy_items(&list_id, 20, 30);
+
+        assert_eq!(action_metrics().live, live + 10);
+        for (first, again) in first[10..].iter().zip(&again) {
+            assert_eq!(first.action_id(), again.action_id());
+        }
+        assert!(
+            again
+                .iter()
+                .all(|item| !item.action_id().unwrap().contains('~'))
+        );
+    }
+
+    #[test]
+    fn only_keys_of_built_items_come_with_the_list() {
+        let tester = WidgetTester::mount(list());
+        let UiNode::LazyList {
+            keyed, item_keys, ..
+        } = tester.find(&by_kind("LazyList")).node
+        else {
+            panic!("No lazy list.\n{}", tester.dump());
+        };
+
+        assert!(keyed);
+        assert_eq!(item_keys.len(), INITIAL_WINDOW);
+        assert_eq!(
+            lazy_keys(&list_id(&tester), 98, 10),
+            vec!["item-98", "item-99"]
+        );
+    }
+
+    fn node(tester: &WidgetTester) -> UiNode {
+        tester.find(&by_kind("LazyList")).node
+    }
+
+    #[test]
+    fn items_fetched_before_a_render_pass_are_dropped_or_still_tappable() {
+        let tapped = state(None);
+        let on_tap = tapped.clone();
+        let mut tester = WidgetTester::mount(lazy_column(100, move |i| {
+            let on_tap = on_tap.clone();
+            Box::new(button(format!("Item {}", i), move || on_tap.set(Some(i))))
+        }));
+        let list_id = list_id(&tester);
+        lazy_items(&list_id, 40, 30);
+        tester.pump();
+        let before = node(&tester);
+
+        // Scroll up, then back down to where the last render pass was
+        let fetched = lazy_items(&list_id, 0, 30);
+        lazy_items(&list_id, 40, 30);
+        tester.pump();
+        let after = node(&tester);
+
+        // Back at the top, the renderer shows what it cached for this build
+        // of the list, or fetches the items again for a new one
+        let shown = if after == before {
+            fetched
+        } else {
+            lazy_items(&list_id, 0, 30)
+        };
+        padauk_dispatch_action(shown[5].action_id().unwrap().to_string());
+        assert_eq!(tapped.get_untracked(), Some(5));
+    }
+}
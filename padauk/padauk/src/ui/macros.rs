@@ -29,11 +29,11 @@ macro_rules! impl_modifiers {
 
             // Adds a .bg() method to the struct (hex color)
             pub fn bg(mut self, color: &str) -> Self {
-                self.modifiers.background_color = Some(crate::ui::color::color_hex(color));
+                self.modifiers.background_color = Some($crate::ui::color::color_hex(color));
                 self
             }
 
-            pub fn bg_color(mut self, color: crate::ui::color::ColorValue) -> Self {
+            pub fn bg_color(mut self, color: $crate::ui::color::ColorValue) -> Self {
                 self.modifiers.background_color = Some(color);
                 self
             }
@@ -74,7 +74,13 @@ macro_rules! impl_modifiers {
                 self
             }
 
-            pub fn border(mut self, width: f32, color: crate::ui::color::ColorValue) -> Self {
+            // Position of the widget when it's a child of a stack
+            pub fn align(mut self, value: $crate::ui::layout::Alignment) -> Self {
+                self.modifiers.align = Some(value);
+                self
+            }
+
+            pub fn border(mut self, width: f32, color: $crate::ui::color::ColorValue) -> Self {
                 self.modifiers.border_width = Some(width);
                 self.modifiers.border_color = Some(color);
                 self
@@ -82,7 +88,7 @@ macro_rules! impl_modifiers {
 
             pub fn border_hex(mut self, width: f32, color: &str) -> Self {
                 self.modifiers.border_width = Some(width);
-                self.modifiers.border_color = Some(crate::ui::color::color_hex(color));
+                self.modifiers.border_color = Some($crate::ui::color::color_hex(color));
                 self
             }
 
@@ -116,6 +122,28 @@ macro_rules! impl_modifiers {
                 self.modifiers.enabled = Some(value);
                 self
             }
+
+            // Tags the node so tests can find it (maps to Compose's testTag)
+            pub fn test_tag(mut self, tag: impl Into<String>) -> Self {
+                self.modifiers.test_tag = Some(tag.into());
+                self
+            }
+        }
+    };
+}
+
+// Adds a .key() method to a widget with a `key: Option<String>` field, which
+// its `Widget::widget_key` should return. Kept out of `impl_modifiers!` so
+// widgets without keys can still use that.
+#[macro_export]
+macro_rules! impl_key {
+    ($widget_type:ident) => {
+        impl $widget_type {
+            // Identity among siblings; see `Widget::widget_key`
+            pub fn key(mut self, key: impl Into<String>) -> Self {
+                self.key = Some(key.into());
+                self
+            }
         }
     };
 }
@@ -1,14 +1,29 @@
 pub mod event_registry;
 pub mod image;
 pub mod layout;
+pub mod lazy_list;
 pub mod macros;
 pub mod modifier;
 pub mod app_bar;
+pub mod back_handler;
+pub mod build_context;
 pub mod button;
 pub mod card;
 pub mod chip;
 pub mod color;
+pub mod hooks;
 pub mod navigation;
+pub mod navigation_bar;
+pub mod navigator_observer;
+pub mod reactive;
+#[cfg(not(target_os = "ios"))]
+pub mod reconciler;
 pub mod render_callback;
+pub mod route_guard;
+pub mod route_registry;
+pub mod route_result;
+pub mod selection;
+pub mod snackbar;
 pub mod state;
+pub mod text_field;
 pub mod widget;
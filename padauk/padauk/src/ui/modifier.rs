@@ -1,4 +1,4 @@
-#[derive(uniffi::Record, Default, Clone)]
+#[derive(uniffi::Record, Default, Clone, Debug, PartialEq)]
 pub struct Modifiers {
     pub padding: Option<f32>,
     pub padding_horizontal: Option<f32>,
@@ -10,6 +10,8 @@ pub struct Modifiers {
     pub fill_max_height: bool,
     pub weight: Option<f32>,
     pub weight_fill: Option<bool>,
+    // Position inside a parent Stack
+    pub align: Option<crate::ui::layout::Alignment>,
     pub border_width: Option<f32>,
     pub border_color: Option<crate::ui::color::ColorValue>,
     pub alpha: Option<f32>,
@@ -19,4 +21,5 @@ pub struct Modifiers {
     pub offset_y: Option<f32>,
     pub z_index: Option<f32>,
     pub enabled: Option<bool>,
+    pub test_tag: Option<String>,
 }
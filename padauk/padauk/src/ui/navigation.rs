@@ -1,6 +1,29 @@
-use crate::ui::{render_callback::request_redraw, widget::Widget};
+use crate::ui::back_handler::{self, BackDecision, BackInterceptor};
+use crate::ui::button::IconType;
+use crate::ui::event_registry::register_scoped_action;
+use crate::ui::hooks::use_state;
+use crate::ui::layout::Alignment;
+use crate::ui::modifier::Modifiers;
+use crate::ui::navigation_bar::{Badge, NavItem, navigation_bar, tab_row};
+use crate::ui::navigator_observer::{
+    self, NavigatorObserver, Notification, ObserverId, RouteChange, RouteHook,
+};
+use crate::ui::render_callback::{batch, request_redraw};
+use crate::ui::route_guard::{self, Guard, GuardDecision, GuardId};
+use crate::ui::route_registry::{self, SavedRoute};
+use crate::ui::route_result::{self, ResultSender, RouteResult};
+use crate::ui::state::State;
+use crate::ui::widget::{IntoWidget, column};
+use crate::ui::{build_context, hooks, widget::Widget};
+use crate::{UiNode, impl_key, impl_modifiers};
 use log::{debug, info, warn};
-use std::sync::{Arc, Mutex, OnceLock}; // Assuming State is available in lib.rs
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
 
 // A Route is a named builder for a Widget (Page)
 #[derive(Clone)]
@@ -8,6 +31,32 @@ pub struct Route {
     pub name: String,
     // We use Arc<dyn Fn> to make the builder cloneable and thread-safe
     pub builder: Arc<dyn Fn() -> Box<dyn Widget> + Send + Sync>,
+    // Identity of this entry on the stack; state remembered by its page belongs to it.
+    pub(crate) id: u64,
+    // Arguments given to `with_args`
+    pub(crate) args: Option<Arc<dyn Any + Send + Sync>>,
+    // Set by `push_for_result`
+    pub(crate) result: Option<ResultSender>,
+    pub(crate) on_enter: Option<RouteHook>,
+    pub(crate) on_exit: Option<RouteHook>,
+    // Run before the route is pushed or put in place, after the global ones
+    pub(crate) guards: Vec<Guard>,
+    pub(crate) presentation: Presentation,
+    // Whether a dialog or bottom sheet closes on a scrim tap, back or swipe
+    pub(crate) dismissible: bool,
+    pub(crate) on_back: Option<BackInterceptor>,
+}
+
+/// How a route is shown.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Presentation {
+    /// Full screen, in place of the route below.
+    #[default]
+    Page,
+    /// A dialog over the routes below (see `Navigator::show_dialog`).
+    Dialog,
+    /// A modal bottom sheet over the routes below (see `Navigator::show_bottom_sheet`).
+    BottomSheet,
 }
 
 impl Route {
@@ -19,189 +68,1909 @@ impl Route {
         Self {
             name: name.into(),
             builder: Arc::new(move || Box::new(builder())),
+            id: 0,
+            args: None,
+            result: None,
+            on_enter: None,
+            on_exit: None,
+            guards: vec![],
+            presentation: Presentation::Page,
+            dismissible: true,
+            on_back: None,
         }
     }
+
+    /// A route whose page is built from typed arguments.
+    pub fn with_args<A, F, W>(name: impl Into<String>, args: A, builder: F) -> Self
+    where
+        A: Send + Sync + 'static,
+        F: Fn(&A) -> W + Send + Sync + 'static,
+        W: Widget + 'static,
+    {
+        let args = Arc::new(args);
+        let page_args = args.clone();
+        let mut route = Self::new(name, move || builder(&page_args));
+        route.args = Some(args);
+        route
+    }
+
+    /// The arguments given to `with_args`, if they are an `A`.
+    pub fn args<A: 'static>(&self) -> Option<&A> {
+        self.args.as_ref()?.downcast_ref::<A>()
+    }
+
+    /// Run `f` each time the route comes to the top of its stack: when it's
+    /// pushed, and when the route above it is popped.
+    pub fn on_enter(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_enter = Some(Arc::new(f));
+        self
+    }
+
+    /// Run `f` each time the route stops being the top of its stack: when
+    /// another is pushed over it, and when it's removed.
+    pub fn on_exit(mut self, f: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_exit = Some(Arc::new(f));
+        self
+    }
+
+    /// Intercept back presses while this route is on top, after the
+    /// interceptors its page registered with `use_back_handler`.
+    pub fn on_back(mut self, f: impl Fn() -> BackDecision + Send + Sync + 'static) -> Self {
+        self.on_back = Some(Arc::new(f));
+        self
+    }
+
+    /// For a dialog or bottom sheet: whether it closes on a scrim tap, back
+    /// press or swipe down (default `true`). Otherwise only `Navigator::pop`
+    /// or `pop_with` close it.
+    pub fn dismissible(mut self, dismissible: bool) -> Self {
+        self.dismissible = dismissible;
+        self
+    }
+
+    pub(crate) fn presented(mut self, presentation: Presentation) -> Self {
+        self.presentation = presentation;
+        self
+    }
+
+    /// Decide whether this route may be shown each time it's pushed or put in
+    /// place (see `Navigator::add_guard`).
+    pub fn guard(
+        mut self,
+        guard: impl Fn(&Route) -> GuardDecision + Send + Sync + 'static,
+    ) -> Self {
+        self.guards.push(Arc::new(guard));
+        self
+    }
+}
+
+static NEXT_ROUTE_ID: AtomicU64 = AtomicU64::new(1);
+
+// Give a route a fresh identity as it enters the stack, so pushing the same
+// `Route` twice gives each page its own remembered state.
+fn mounted(mut route: Route) -> Route {
+    route.id = NEXT_ROUTE_ID.fetch_add(1, Ordering::Relaxed);
+    route
+}
+
+// The page of a route. Its widgets are built under a per-route path segment,
+// so two pages of the same route never share hook state.
+struct RoutePage {
+    id: u64,
+    child: Box<dyn Widget>,
+}
+
+impl Widget for RoutePage {
+    fn build(&self) -> UiNode {
+        hooks::with_route(self.id, || {
+            build_context::slot(&format!("route-{}", self.id), || self.child.build())
+        })
+    }
+}
+
+// Build a route's page widget under its identity.
+fn build_route(route: &Route) -> Box<dyn Widget> {
+    let id = route.id;
+    hooks::with_route(id, || {
+        build_context::slot(&format!("route-{}", id), || (route.builder)())
+    })
 }
 
+// The content of a dialog or bottom sheet route
+struct ModalPage {
+    id: u64,
+    presentation: Presentation,
+    dismissible: bool,
+    navigator: NavigatorHandle,
+    child: Box<dyn Widget>,
+}
+
+impl Widget for ModalPage {
+    fn build(&self) -> UiNode {
+        let id = self.id;
+        let (content, dismiss_action_id) = hooks::with_route(id, || {
+            build_context::slot(&format!("route-{}", id), || {
+                let dismiss_action_id = self.dismissible.then(|| {
+                    let navigator = self.navigator.clone();
+                    register_scoped_action("dismiss", Arc::new(move || navigator.dismiss(id)))
+                });
+                (self.child.build(), dismiss_action_id)
+            })
+        });
+
+        #[cfg(target_os = "ios")]
+        {
+            let _ = dismiss_action_id;
+            UiNode::VStack {
+                views: vec![content],
+                attributes: Modifiers::default(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let key = Some(format!("route-{}", id));
+            match self.presentation {
+                Presentation::BottomSheet => UiNode::BottomSheet {
+                    content: vec![content],
+                    dismiss_action_id,
+                    modifiers: Modifiers::default(),
+                    key,
+                },
+                Presentation::Dialog | Presentation::Page => UiNode::Dialog {
+                    content: vec![content],
+                    dismiss_action_id,
+                    modifiers: Modifiers::default(),
+                    key,
+                },
+            }
+        }
+    }
+}
+
+// A page with dialogs and bottom sheets over it, bottom first. The overlays
+// are keyed, so opening or closing one over another only inserts or removes it.
+struct Overlays {
+    page: Box<dyn Widget>,
+    modals: Vec<ModalPage>,
+}
+
+impl Widget for Overlays {
+    fn build(&self) -> UiNode {
+        let mut children = vec![self.page.build()];
+        children.extend(self.modals.iter().map(|modal| modal.build()));
+
+        #[cfg(target_os = "ios")]
+        {
+            UiNode::VStack {
+                views: children,
+                attributes: Modifiers::default(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Stack {
+                children,
+                alignment: Alignment::TopStart,
+                modifiers: Modifiers::default(),
+                key: None,
+            }
+        }
+    }
+}
+
+// Name of the app's top-level navigator
+const ROOT: &str = "root";
+
+#[derive(Clone)]
+struct Stack {
+    routes: Vec<Route>,
+    // Route whose page hosts this navigator; the stack goes when that route does.
+    owner: Option<u64>,
+}
+
+#[derive(Default)]
 struct NavigatorState {
-    stack: Vec<Route>,
+    // Every navigator's stack by name, the root one included
+    stacks: HashMap<String, Stack>,
+    // Nested navigators built by the last render pass, with their depth
+    active: Vec<(usize, String)>,
 }
 
 // Global Singleton Navigator State
 static NAVIGATOR_STATE: OnceLock<Mutex<NavigatorState>> = OnceLock::new();
 
+thread_local! {
+    // Nested navigators around the widget being built (or the action or task
+    // running), innermost last. Empty means the root navigator.
+    static SCOPE: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    // Nested navigators built so far in this render pass
+    static PASS_ACTIVE: RefCell<Vec<(usize, String)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// The innermost navigator around the code running now (`None` for the root).
+pub(crate) fn current_scope() -> Option<String> {
+    SCOPE.with(|scope| scope.borrow().last().cloned())
+}
+
+/// Run `f` with `scope` as the innermost navigator, e.g. an action handler
+/// registered by a page of that navigator.
+pub(crate) fn with_scope<R>(scope: Option<String>, f: impl FnOnce() -> R) -> R {
+    let Some(name) = scope else {
+        return f();
+    };
+    SCOPE.with(|s| s.borrow_mut().push(name));
+    let result = f();
+    SCOPE.with(|s| s.borrow_mut().pop());
+    result
+}
+
+pub(crate) fn scope_depth() -> usize {
+    SCOPE.with(|scope| scope.borrow().len())
+}
+
+pub(crate) fn truncate_scope(depth: usize) {
+    SCOPE.with(|scope| scope.borrow_mut().truncate(depth));
+}
+
+pub(crate) fn begin_render_pass() {
+    PASS_ACTIVE.with(|active| active.borrow_mut().clear());
+}
+
+pub(crate) fn end_render_pass() {
+    let active = PASS_ACTIVE.with(|active| std::mem::take(&mut *active.borrow_mut()));
+    if let Some(mutex) = NAVIGATOR_STATE.get()
+        && let Ok(mut state) = mutex.lock()
+    {
+        state.active = active;
+    }
+}
+
+fn get_state() -> &'static Mutex<NavigatorState> {
+    NAVIGATOR_STATE.get_or_init(|| Mutex::new(NavigatorState::default()))
+}
+
+// `None` for the root navigator, as in `SCOPE`
+fn scope_of(navigator: &str) -> Option<String> {
+    (navigator != ROOT).then(|| navigator.to_string())
+}
+
+fn change(navigator: &str, route: &Route, previous: Option<&Route>, depth: usize) -> RouteChange {
+    RouteChange {
+        navigator: navigator.to_string(),
+        route: route.name.clone(),
+        previous_route: previous.map(|r| r.name.clone()),
+        depth,
+    }
+}
+
+// The app's initial route, as its guards have it. It can't be cancelled: there
+// would be nothing to show.
+fn guarded_initial(route: Route) -> Route {
+    route_guard::check(route.clone()).unwrap_or_else(|| {
+        warn!(
+            "Guards cancelled the initial route {}; showing it anyway.",
+            route.name
+        );
+        route
+    })
+}
+
+// Queue the hook of a route of `navigator` coming to or leaving the top.
+fn queue_hook(navigator: &str, hook: &Option<RouteHook>) {
+    if let Some(hook) = hook {
+        navigator_observer::queue(Notification::Hook(scope_of(navigator), hook.clone()));
+    }
+}
+
+// A route left its stack: drop the state its page remembered and stop its tasks.
+// A pending result resolves to `None`, unless `pop_with` delivered one first.
+// Navigators nested in its page go with it.
+fn unmounted(state: &mut NavigatorState, route: Route) {
+    hooks::drop_route(route.id);
+    crate::runtime::cancel_route(route.id);
+    if let Some(result) = route.result {
+        route_result::queue(result, None);
+    }
+    let nested: Vec<String> = state
+        .stacks
+        .iter()
+        .filter(|(_, stack)| stack.owner == Some(route.id))
+        .map(|(name, _)| name.clone())
+        .collect();
+    for name in nested {
+        if let Some(stack) = state.stacks.remove(&name) {
+            debug!("Navigator {} removed with its host route.", name);
+            if let Some(top) = stack.routes.last() {
+                queue_hook(&name, &top.on_exit);
+            }
+            for route in stack.routes.into_iter().rev() {
+                navigator_observer::queue(Notification::Remove(change(&name, &route, None, 0)));
+                unmounted(state, route);
+            }
+        }
+    }
+}
+
+// Replace every navigator's stack, e.g. on reset or restore.
+fn replace_all(stacks: Vec<(String, Stack)>) {
+    {
+        let mut state = get_state().lock().unwrap();
+        let old: Vec<(String, Stack)> = state.stacks.drain().collect();
+        for (name, stack) in old {
+            if let Some(top) = stack.routes.last() {
+                queue_hook(&name, &top.on_exit);
+            }
+            for route in stack.routes.into_iter().rev() {
+                let removed = change(&name, &route, None, 0);
+                navigator_observer::queue(Notification::Remove(removed));
+                unmounted(&mut state, route);
+            }
+        }
+        state.active.clear();
+        for (name, stack) in stacks {
+            for (i, route) in stack.routes.iter().enumerate() {
+                let previous = i.checked_sub(1).map(|i| &stack.routes[i]);
+                let pushed = change(&name, route, previous, i + 1);
+                navigator_observer::queue(Notification::Push(pushed));
+            }
+            if let Some(top) = stack.routes.last() {
+                queue_hook(&name, &top.on_enter);
+            }
+            state.stacks.insert(name, stack);
+        }
+    }
+    navigator_observer::deliver_pending();
+    route_result::deliver_pending();
+}
+
+// Version of the `save_state` encoding
+const SAVED_STATE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Default)]
+struct SavedState {
+    version: u32,
+    // Each nested navigator comes after the one hosting it
+    navigators: Vec<SavedNavigator>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SavedNavigator {
+    name: String,
+    // Navigator and index of the route hosting this one
+    owner: Option<(String, usize)>,
+    routes: Vec<SavedRoute>,
+}
+
+fn save_stacks(stacks: &HashMap<String, Stack>) -> SavedState {
+    let mut saved = SavedState {
+        version: SAVED_STATE_VERSION,
+        navigators: vec![],
+    };
+    // Root first, then navigators hosted by routes already saved
+    let mut pending: Vec<(String, Option<(String, usize)>)> = vec![(ROOT.to_string(), None)];
+    while let Some((name, owner)) = pending.pop() {
+        let Some(stack) = stacks.get(&name) else {
+            continue;
+        };
+        let routes: Vec<SavedRoute> = stack
+            .routes
+            .iter()
+            .map_while(route_registry::save)
+            .collect();
+        for (index, route) in stack.routes.iter().take(routes.len()).enumerate() {
+            let mut hosted: Vec<&String> = stacks
+                .iter()
+                .filter(|(_, nested)| nested.owner == Some(route.id))
+                .map(|(nested, _)| nested)
+                .collect();
+            hosted.sort();
+            pending.extend(
+                hosted
+                    .into_iter()
+                    .map(|nested| (nested.clone(), Some((name.clone(), index)))),
+            );
+        }
+        saved.navigators.push(SavedNavigator {
+            name,
+            owner,
+            routes,
+        });
+    }
+    saved
+}
+
+/// The app's navigators. The associated functions act on the innermost
+/// navigator around the caller: inside a page of a nested `navigator` (or an
+/// action or task started there), that one; otherwise the root navigator.
 pub struct Navigator;
 
 impl Navigator {
     // Initialize the navigator with a root route.
     /// This should be called once at app startup.
     pub fn init(initial_route: Route) {
-        let state = NavigatorState {
-            stack: vec![initial_route],
+        let initial_route = guarded_initial(initial_route);
+        let mut state = get_state().lock().unwrap();
+        // We ignore the call if it's already initialized
+        if state.stacks.contains_key(ROOT) {
+            warn!("Navigator init skipped: already initialized.");
+            return;
+        }
+        queue_hook(ROOT, &initial_route.on_enter);
+        state.stacks.insert(
+            ROOT.to_string(),
+            Stack {
+                routes: vec![mounted(initial_route)],
+                owner: None,
+            },
+        );
+        info!("Navigator initialized with root route.");
+        drop(state);
+        navigator_observer::deliver_pending();
+    }
+
+    /// Reset the stack to a single root route, initializing the navigator if needed.
+    #[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+    pub(crate) fn reset(initial_route: Route) {
+        debug!("Navigator reset with root route: {}", initial_route.name);
+        let initial_route = guarded_initial(initial_route);
+        let root = Stack {
+            routes: vec![mounted(initial_route)],
+            owner: None,
+        };
+        replace_all(vec![(ROOT.to_string(), root)]);
+    }
+
+    /// The stacks of all navigators, encoded for `restore_state`. A stack is
+    /// saved up to its first route that can't be restored (see `RouteRegistry`).
+    pub fn save_state() -> String {
+        // Arguments are serialized by app code, so not under the lock
+        let stacks = NAVIGATOR_STATE
+            .get()
+            .map(|mutex| mutex.lock().unwrap().stacks.clone());
+        let saved = match stacks {
+            Some(stacks) => save_stacks(&stacks),
+            None => SavedState::default(),
         };
-        // We ignore the error if it's already initialized
-        match NAVIGATOR_STATE.set(Mutex::new(state)) {
-            Ok(()) => info!("Navigator initialized with root route."),
-            Err(_) => warn!("Navigator init skipped: already initialized."),
+        serde_json::to_string(&saved).unwrap_or_default()
+    }
+
+    /// Replace every navigator's stack with ones saved by `save_state`. Guards
+    /// run on the restored stacks. Returns `false`, changing nothing, if
+    /// `saved` doesn't decode or no root route could be restored.
+    pub fn restore_state(saved: &str) -> bool {
+        let saved: SavedState = match serde_json::from_str(saved) {
+            Ok(saved) => saved,
+            Err(e) => {
+                warn!("Navigator restore_state: can't decode saved state: {}", e);
+                return false;
+            }
+        };
+        if saved.version != SAVED_STATE_VERSION {
+            warn!(
+                "Navigator restore_state: saved state has version {}.",
+                saved.version
+            );
+            return false;
+        }
+
+        let mut stacks: Vec<(String, Stack)> = vec![];
+        for navigator in saved.navigators {
+            // A nested navigator goes back under its restored host route
+            let owner = match &navigator.owner {
+                None => None,
+                Some((host, index)) => {
+                    let host = stacks.iter().find(|(name, _)| name == host);
+                    match host.and_then(|(_, stack)| stack.routes.get(*index)) {
+                        Some(route) => Some(route.id),
+                        None => continue,
+                    }
+                }
+            };
+            let routes: Vec<Route> = navigator
+                .routes
+                .into_iter()
+                .map_while(route_registry::restore)
+                .collect();
+            let routes: Vec<Route> = route_guard::check_stack(routes)
+                .into_iter()
+                .map(mounted)
+                .collect();
+            if !routes.is_empty() {
+                stacks.push((navigator.name, Stack { routes, owner }));
+            }
+        }
+        if !stacks.iter().any(|(name, _)| name == ROOT) {
+            warn!("Navigator restore_state: no root route could be restored.");
+            return false;
+        }
+        info!("Navigator restored {} stacks.", stacks.len());
+        replace_all(stacks);
+        request_redraw();
+        true
+    }
+
+    /// Register an observer of every navigator's changes.
+    pub fn add_observer(observer: impl NavigatorObserver + 'static) -> ObserverId {
+        navigator_observer::add_observer(Arc::new(observer))
+    }
+
+    pub fn remove_observer(id: ObserverId) {
+        navigator_observer::remove_observer(id);
+    }
+
+    /// Install a guard run before every route is pushed or put in place (by
+    /// `replace`, `set_stack` or a deep link), and on the initial route. It can
+    /// let the navigation through, redirect it, or cancel it:
+    ///
+    /// ```ignore
+    /// Navigator::add_guard(|route| {
+    ///     if route.name.starts_with("/account") && !session().get().signed_in {
+    ///         let back_to = route.clone();
+    ///         GuardDecision::Redirect(Route::new("login", move || Login::new(back_to.clone())))
+    ///     } else {
+    ///         GuardDecision::Allow
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// Global guards run in the order they were added, then the route's own.
+    pub fn add_guard(guard: impl Fn(&Route) -> GuardDecision + Send + Sync + 'static) -> GuardId {
+        route_guard::add_guard(Arc::new(guard))
+    }
+
+    pub fn remove_guard(id: GuardId) {
+        route_guard::remove_guard(id);
+    }
+
+    /// The root navigator.
+    pub fn root() -> NavigatorHandle {
+        NavigatorHandle {
+            name: ROOT.to_string(),
         }
     }
 
+    /// The nested navigator called `name` (see `navigator`).
+    pub fn named(name: impl Into<String>) -> NavigatorHandle {
+        NavigatorHandle { name: name.into() }
+    }
+
+    /// The innermost navigator around the caller.
+    pub fn current() -> NavigatorHandle {
+        current_scope().map_or_else(Self::root, Self::named)
+    }
+
     /// Push a new route onto the stack
     pub fn push(route: Route) {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(mut state) = mutex.lock() {
-                debug!("Navigator push: {}", route.name);
-                state.stack.push(route);
-                request_redraw();
-                debug!("Navigator stack size after push: {}", state.stack.len());
-            }
-        } else {
-            warn!("Navigator push called before init.");
-        }
+        Self::current().push(route);
+    }
+
+    /// Push a route whose page can hand a value back with `pop_with`.
+    pub fn push_for_result<T: Send + 'static>(route: Route) -> RouteResult<T> {
+        Self::current().push_for_result(route)
+    }
+
+    /// Show `route` as a dialog over the current screen. The result is
+    /// `Some(value)` if it's closed with `pop_with(value)`, `None` if it's
+    /// dismissed.
+    pub fn show_dialog<T: Send + 'static>(route: Route) -> RouteResult<T> {
+        Self::current().show_dialog(route)
+    }
+
+    /// Show `route` as a modal bottom sheet over the current screen, with
+    /// the same result as `show_dialog`.
+    pub fn show_bottom_sheet<T: Send + 'static>(route: Route) -> RouteResult<T> {
+        Self::current().show_bottom_sheet(route)
     }
 
     /// Replace the current route with a new one
     pub fn replace(route: Route) {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(mut state) = mutex.lock() {
-                debug!("Navigator replace with: {}", route.name);
-                if !state.stack.is_empty() {
-                    state.stack.pop();
-                }
-                state.stack.push(route);
-                request_redraw();
-                debug!("Navigator stack size after replace: {}", state.stack.len());
-            }
-        } else {
-            warn!("Navigator replace called before init.");
-        }
+        Self::current().replace(route);
     }
 
     /// Pop the top route from the stack
     pub fn pop() {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(mut state) = mutex.lock() {
-                if state.stack.len() > 1 {
-                    let popped = state.stack.pop();
-                    if let Some(route) = popped {
-                        debug!("Navigator pop: {}", route.name);
-                    }
-                    request_redraw();
-                    debug!("Navigator stack size after pop: {}", state.stack.len());
-                }
-            }
-        } else {
-            warn!("Navigator pop called before init.");
-        }
+        Self::current().pop();
+    }
+
+    /// Pop the top route, resolving its `push_for_result` with `Some(value)`.
+    pub fn pop_with<T: Send + 'static>(value: T) {
+        Self::current().pop_with(value);
     }
 
     /// Check if there is more than one route in the stack
     pub fn can_pop() -> bool {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(state) = mutex.lock() {
-                return state.stack.len() > 1;
+        Self::current().can_pop()
+    }
+
+    /// Pop routes until the top matches `name` (keeps the matching route).
+    pub fn pop_until(name: &str) {
+        Self::current().pop_until(name);
+    }
+
+    /// Pop routes until `name` is removed from the stack.
+    pub fn pop_til(name: &str) {
+        Self::current().pop_til(name);
+    }
+
+    /// Pop back to the first route.
+    pub fn pop_to_first() {
+        Self::current().pop_to_first();
+    }
+
+    /// Replace the whole stack, bottom first. Does nothing if `routes` is empty.
+    pub fn set_stack(routes: Vec<Route>) {
+        Self::current().set_stack(routes);
+    }
+
+    /// Push the route the installed `Router` builds for `uri`.
+    pub fn push_uri(uri: &str) {
+        Self::push(Router::route_for(uri));
+    }
+
+    /// Names of the routes on the stack, bottom first.
+    pub fn route_names() -> Vec<String> {
+        Self::current().route_names()
+    }
+
+    /// Whether the page being built has a route below it to go back to. Unlike
+    /// `can_pop`, a dialog shown over the page doesn't count.
+    pub(crate) fn page_can_pop() -> bool {
+        let Some(id) = hooks::current_route() else {
+            return Self::can_pop();
+        };
+        Self::current()
+            .read(|routes| routes.iter().position(|r| r.id == id))
+            .flatten()
+            .map_or_else(Self::can_pop, |index| index > 0)
+    }
+
+    /// Identity of the route on display.
+    pub(crate) fn current_route_id() -> Option<u64> {
+        Self::current().top_route_id()
+    }
+
+    /// Render the currently active route
+    pub fn render_current() -> Option<Box<dyn Widget>> {
+        Self::root().render_top()
+    }
+
+    /// Go back from the current route, as the `Scaffold` back button does:
+    /// its back interceptors run first, and may handle it instead of the pop.
+    /// Returns whether anything happened.
+    pub fn back() -> bool {
+        Self::current().back()
+    }
+
+    /// The navigator system back should pop: the innermost navigator on
+    /// screen that can pop, else the root one if it can.
+    pub fn back_target() -> Option<NavigatorHandle> {
+        Self::back_chain()
+            .into_iter()
+            .find(NavigatorHandle::can_pop)
+    }
+
+    // Navigators on screen, innermost first, ending with the root one.
+    fn back_chain() -> Vec<NavigatorHandle> {
+        let mut active = NAVIGATOR_STATE
+            .get()
+            .and_then(|mutex| mutex.lock().ok())
+            .map(|state| state.active.clone())
+            .unwrap_or_default();
+        // Deepest first; among equals, the last built
+        active.reverse();
+        active.sort_by_key(|(depth, _)| std::cmp::Reverse(*depth));
+        active
+            .into_iter()
+            .map(|(_, name)| name)
+            .chain(std::iter::once(ROOT.to_string()))
+            .map(Self::named)
+            .collect()
+    }
+
+    /// Handle system back: the top routes of the navigators on screen are
+    /// offered it innermost first, each to its interceptors, until one
+    /// handles it or a navigator can pop. Returns whether it was handled.
+    pub(crate) fn system_back() -> bool {
+        for navigator in Self::back_chain() {
+            if navigator.intercept_back() {
+                return true;
+            }
+            if navigator.can_pop() {
+                navigator.pop();
+                return true;
             }
         }
         false
     }
 
-    /// Pop routes until the top matches `name` (keeps the matching route).
-    pub fn pop_until(name: &str) {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(mut state) = mutex.lock() {
-                let mut changed = false;
-                while state.stack.len() > 1 {
-                    let top_matches = state.stack.last().map(|r| r.name == name).unwrap_or(false);
-                    if top_matches {
-                        break;
+    /// Whether `system_back` would do anything (otherwise the host handles
+    /// back itself, e.g. by closing the app).
+    pub(crate) fn handles_system_back() -> bool {
+        Self::back_chain()
+            .iter()
+            .any(|navigator| navigator.can_pop() || navigator.has_back_interceptor())
+    }
+}
+
+/// A navigator, by name. Get one with `Navigator::root`, `Navigator::named`
+/// or `Navigator::current`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavigatorHandle {
+    name: String,
+}
+
+impl NavigatorHandle {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the navigator exists: the root one once initialized, a nested
+    /// one once built and until its host route is popped.
+    pub fn exists(&self) -> bool {
+        NAVIGATOR_STATE
+            .get()
+            .and_then(|mutex| mutex.lock().ok())
+            .is_some_and(|state| state.stacks.contains_key(&self.name))
+    }
+
+    // Lock the state and run `f` on this navigator's stack. Observers, route
+    // hooks and results of routes removed by `f` are called after the lock is
+    // released.
+    fn edit<R>(
+        &self,
+        op: &str,
+        f: impl FnOnce(&mut NavigatorState, &mut Vec<Route>) -> R,
+    ) -> Option<R> {
+        let result = match NAVIGATOR_STATE.get() {
+            Some(mutex) => {
+                let mut state = mutex.lock().unwrap();
+                match state.stacks.get_mut(&self.name) {
+                    Some(stack) => {
+                        // Take the routes out so `f` can also unmount into the state
+                        let mut routes = std::mem::take(&mut stack.routes);
+                        let before = routes.last().map(|r| (r.id, r.on_exit.clone()));
+                        let result = f(&mut state, &mut routes);
+                        let after = routes.last().map(|r| (r.id, r.on_enter.clone()));
+                        // A new top route: the old one exits, the new one enters
+                        if before.as_ref().map(|(id, _)| id) != after.as_ref().map(|(id, _)| id) {
+                            if let Some((_, on_exit)) = &before {
+                                queue_hook(&self.name, on_exit);
+                            }
+                            if let Some((_, on_enter)) = &after {
+                                queue_hook(&self.name, on_enter);
+                            }
+                        }
+                        if let Some(stack) = state.stacks.get_mut(&self.name) {
+                            stack.routes = routes;
+                        }
+                        Some(result)
                     }
-                    let popped = state.stack.pop();
-                    if let Some(route) = popped {
-                        debug!("Navigator pop_until popped: {}", route.name);
+                    None => {
+                        warn!(
+                            "Navigator {} called on unknown navigator: {}",
+                            op, self.name
+                        );
+                        None
                     }
-                    changed = true;
-                }
-                if changed {
-                    request_redraw();
-                    debug!("Navigator stack size after pop_until: {}", state.stack.len());
                 }
             }
-        } else {
-            warn!("Navigator pop_until called before init.");
+            None => {
+                warn!("Navigator {} called before init.", op);
+                None
+            }
+        };
+        navigator_observer::deliver_pending();
+        route_result::deliver_pending();
+        result
+    }
+
+    // Read this navigator's stack.
+    fn read<R>(&self, f: impl FnOnce(&[Route]) -> R) -> Option<R> {
+        let state = NAVIGATOR_STATE.get()?.lock().ok()?;
+        state.stacks.get(&self.name).map(|stack| f(&stack.routes))
+    }
+
+    /// Push a new route onto the stack
+    pub fn push(&self, route: Route) {
+        let Some(route) = self.guarded(route) else {
+            return;
+        };
+        self.edit("push", |_, routes| {
+            debug!("Navigator {} push: {}", self.name, route.name);
+            let pushed = change(&self.name, &route, routes.last(), routes.len() + 1);
+            navigator_observer::queue(Notification::Push(pushed));
+            routes.push(mounted(route));
+            request_redraw();
+            debug!("Navigator stack size after push: {}", routes.len());
+        });
+    }
+
+    /// Push a route whose page can hand a value back with `pop_with`.
+    pub fn push_for_result<T: Send + 'static>(&self, mut route: Route) -> RouteResult<T> {
+        let (result, sender) = RouteResult::channel();
+        route.result = Some(sender);
+        self.push(route);
+        result
+    }
+
+    /// Show `route` as a dialog over this navigator's screen.
+    pub fn show_dialog<T: Send + 'static>(&self, route: Route) -> RouteResult<T> {
+        self.push_for_result(route.presented(Presentation::Dialog))
+    }
+
+    /// Show `route` as a modal bottom sheet over this navigator's screen.
+    pub fn show_bottom_sheet<T: Send + 'static>(&self, route: Route) -> RouteResult<T> {
+        self.push_for_result(route.presented(Presentation::BottomSheet))
+    }
+
+    /// Replace the current route with a new one
+    pub fn replace(&self, route: Route) {
+        let Some(route) = self.guarded(route) else {
+            return;
+        };
+        self.edit("replace", |state, routes| {
+            debug!("Navigator {} replace with: {}", self.name, route.name);
+            let old = routes.pop();
+            let replaced = change(&self.name, &route, old.as_ref(), routes.len() + 1);
+            navigator_observer::queue(Notification::Replace(replaced));
+            if let Some(old) = old {
+                unmounted(state, old);
+            }
+            routes.push(mounted(route));
+            request_redraw();
+            debug!("Navigator stack size after replace: {}", routes.len());
+        });
+    }
+
+    // The route to show in place of `route`, if guards don't cancel it. A
+    // `push_for_result` that is cancelled or redirected resolves to `None`:
+    // the route it waits on is never shown.
+    fn guarded(&self, route: Route) -> Option<Route> {
+        let result = route.result.clone();
+        let guarded = route_guard::follow(route);
+        if guarded.as_ref().is_none_or(|(_, redirected)| *redirected)
+            && let Some(result) = result
+        {
+            result.complete(None);
         }
+        guarded.map(|(route, _)| route)
     }
 
-    /// Pop routes until `name` is removed from the stack.
-    pub fn pop_til(name: &str) {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(mut state) = mutex.lock() {
-                let mut changed = false;
-                while state.stack.len() > 1 {
-                    let top_matches = state.stack.last().map(|r| r.name == name).unwrap_or(false);
-                    let popped = state.stack.pop();
-                    if let Some(route) = popped {
-                        debug!("Navigator pop_til popped: {}", route.name);
-                    }
-                    changed = true;
-                    if top_matches {
-                        break;
-                    }
-                }
-                if changed {
-                    request_redraw();
-                    debug!("Navigator stack size after pop_til: {}", state.stack.len());
+    // Pop routes while the stack has more than one and `should_pop` agrees.
+    fn pop_while(&self, op: &str, mut should_pop: impl FnMut(&Route) -> bool) {
+        self.edit(op, |state, routes| {
+            let mut changed = false;
+            while routes.len() > 1 && routes.last().is_some_and(&mut should_pop) {
+                if let Some(route) = routes.pop() {
+                    debug!("Navigator {} {} popped: {}", self.name, op, route.name);
+                    let popped = change(&self.name, &route, routes.last(), routes.len());
+                    navigator_observer::queue(Notification::Pop(popped));
+                    unmounted(state, route);
                 }
+                changed = true;
             }
-        } else {
-            warn!("Navigator pop_til called before init.");
-        }
+            if changed {
+                request_redraw();
+                debug!("Navigator stack size after {}: {}", op, routes.len());
+            }
+        });
+    }
+
+    /// Pop the top route from the stack
+    pub fn pop(&self) {
+        let mut first = true;
+        self.pop_while("pop", |_| std::mem::replace(&mut first, false));
+    }
+
+    /// Pop the top route, resolving its `push_for_result` with `Some(value)`.
+    pub fn pop_with<T: Send + 'static>(&self, value: T) {
+        let mut value = Some(value);
+        self.pop_while("pop_with", |route| {
+            let Some(value) = value.take() else {
+                return false;
+            };
+            if let Some(result) = &route.result {
+                route_result::queue(result.clone(), Some(Box::new(value)));
+            }
+            true
+        });
+    }
+
+    /// Check if there is more than one route in the stack
+    pub fn can_pop(&self) -> bool {
+        self.read(|routes| routes.len() > 1).unwrap_or(false)
+    }
+
+    /// Pop routes until the top matches `name` (keeps the matching route).
+    pub fn pop_until(&self, name: &str) {
+        self.pop_while("pop_until", |route| route.name != name);
+    }
+
+    /// Pop routes until `name` is removed from the stack.
+    pub fn pop_til(&self, name: &str) {
+        let mut done = false;
+        self.pop_while("pop_til", |route| {
+            let pop = !done;
+            done = route.name == name;
+            pop
+        });
     }
 
     /// Pop back to the first route.
-    pub fn pop_to_first() {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(mut state) = mutex.lock() {
-                let mut changed = false;
-                while state.stack.len() > 1 {
-                    let popped = state.stack.pop();
-                    if let Some(route) = popped {
-                        debug!("Navigator pop_to_first popped: {}", route.name);
-                    }
-                    changed = true;
-                }
-                if changed {
-                    request_redraw();
-                    debug!("Navigator stack size after pop_to_first: {}", state.stack.len());
-                }
+    pub fn pop_to_first(&self) {
+        self.pop_while("pop_to_first", |_| true);
+    }
+
+    /// Go back from this navigator's top route: its back interceptors run
+    /// first, then it's popped if it isn't the only one. Returns whether
+    /// anything happened.
+    pub fn back(&self) -> bool {
+        if self.intercept_back() {
+            return true;
+        }
+        if !self.can_pop() {
+            return false;
+        }
+        self.pop();
+        true
+    }
+
+    // Offer a back press to the top route's interceptors, innermost first.
+    // Returns whether one handled it.
+    fn intercept_back(&self) -> bool {
+        let Some(Some(route)) = self.read(|routes| routes.last().cloned()) else {
+            return false;
+        };
+        let mut interceptors = back_handler::interceptors_for(route.id);
+        if let Some(on_back) = route.on_back {
+            interceptors.push((scope_of(&self.name), on_back));
+        }
+        interceptors.into_iter().any(|(scope, interceptor)| {
+            with_scope(scope, || interceptor()) == BackDecision::Handled
+        })
+    }
+
+    fn has_back_interceptor(&self) -> bool {
+        self.read(|routes| {
+            routes.last().is_some_and(|route| {
+                route.on_back.is_some() || !back_handler::interceptors_for(route.id).is_empty()
+            })
+        })
+        .unwrap_or(false)
+    }
+
+    // Close the modal route `id` (and anything above it), if it's still there.
+    // Like back, this goes through the top route's interceptors first: a sheet
+    // holding a form can ask before it's thrown away.
+    fn dismiss(&self, id: u64) {
+        let on_stack = self.read(|routes| routes.iter().any(|r| r.id == id));
+        if on_stack != Some(true) || self.intercept_back() {
+            return;
+        }
+        let mut done = false;
+        self.pop_while("dismiss", |route| {
+            let pop = !done;
+            done = route.id == id;
+            pop
+        });
+    }
+
+    /// Replace the whole stack, bottom first. Does nothing if `routes` is empty.
+    /// Guards run bottom first; a redirect ends the stack with its target and
+    /// a cancel ends it before the cancelled route.
+    pub fn set_stack(&self, new_routes: Vec<Route>) {
+        if new_routes.is_empty() {
+            warn!("Navigator set_stack called with no routes.");
+            return;
+        }
+        let new_routes = route_guard::check_stack(new_routes);
+        if new_routes.is_empty() {
+            debug!("Navigator {} set_stack cancelled by a guard.", self.name);
+            return;
+        }
+        self.edit("set_stack", |state, routes| {
+            let old = std::mem::take(routes);
+            for route in old.into_iter().rev() {
+                let removed = change(&self.name, &route, None, 0);
+                navigator_observer::queue(Notification::Remove(removed));
+                unmounted(state, route);
             }
+            for route in new_routes {
+                let pushed = change(&self.name, &route, routes.last(), routes.len() + 1);
+                navigator_observer::queue(Notification::Push(pushed));
+                routes.push(mounted(route));
+            }
+            request_redraw();
+            debug!("Navigator stack size after set_stack: {}", routes.len());
+        });
+    }
+
+    /// Push the route the installed `Router` builds for `uri`.
+    pub fn push_uri(&self, uri: &str) {
+        self.push(Router::route_for(uri));
+    }
+
+    /// Names of the routes on the stack, bottom first.
+    pub fn route_names(&self) -> Vec<String> {
+        self.read(|routes| routes.iter().map(|r| r.name.clone()).collect())
+            .unwrap_or_default()
+    }
+
+    fn top_route_id(&self) -> Option<u64> {
+        self.read(|routes| routes.last().map(|r| r.id)).flatten()
+    }
+
+    // The page of the top route, built under it. Dialogs and bottom sheets on
+    // top are drawn over the last page route instead.
+    fn render_top(&self) -> Option<Box<dyn Widget>> {
+        // Clone the routes out so their builders can use the navigator.
+        let routes = self.read(|routes| {
+            let page = routes
+                .iter()
+                .rposition(|r| r.presentation == Presentation::Page)
+                .unwrap_or(0);
+            routes
+                .get(page..)
+                .map(<[Route]>::to_vec)
+                .unwrap_or_default()
+        });
+        let Some(routes) = routes else {
+            warn!("Navigator {} render_current called before init.", self.name);
+            return None;
+        };
+        let mut routes = routes.into_iter();
+        let route = routes.next()?;
+        debug!("Navigator {} render_current: {}", self.name, route.name);
+        let page = Box::new(RoutePage {
+            id: route.id,
+            child: build_route(&route),
+        });
+        let modals: Vec<ModalPage> = routes
+            .map(|route| ModalPage {
+                id: route.id,
+                presentation: route.presentation,
+                dismissible: route.dismissible,
+                navigator: self.clone(),
+                child: build_route(&route),
+            })
+            .collect();
+        if modals.is_empty() {
+            Some(page)
         } else {
-            warn!("Navigator pop_to_first called before init.");
+            Some(Box::new(Overlays { page, modals }))
         }
     }
+}
 
-    /// Render the currently active route
-    pub fn render_current() -> Option<Box<dyn Widget>> {
-        if let Some(mutex) = NAVIGATOR_STATE.get() {
-            if let Ok(state) = mutex.lock() {
-                if let Some(route) = state.stack.last() {
-                    debug!("Navigator render_current: {}", route.name);
-                    return Some((route.builder)());
+// ==========================
+//     NESTED NAVIGATORS
+// ==========================
+
+/// A navigator with its own stack, shown in place of this widget. Its stack
+/// is created with `initial_route` on the first build and kept until the
+/// route whose page contains it is popped, even while it isn't built
+/// (e.g. a tab that isn't selected).
+pub struct NestedNavigator {
+    pub name: String,
+    pub initial_route: Route,
+    pub key: Option<String>,
+}
+
+impl NestedNavigator {
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+}
+
+impl Widget for NestedNavigator {
+    fn build(&self) -> UiNode {
+        {
+            let mut state = get_state().lock().unwrap();
+            if !state.stacks.contains_key(&self.name) {
+                debug!("Navigator {} created.", self.name);
+                queue_hook(&self.name, &self.initial_route.on_enter);
+                let stack = Stack {
+                    routes: vec![mounted(self.initial_route.clone())],
+                    owner: hooks::current_route(),
+                };
+                state.stacks.insert(self.name.clone(), stack);
+            }
+        }
+        navigator_observer::deliver_pending();
+        let depth = scope_depth() + 1;
+        PASS_ACTIVE.with(|active| active.borrow_mut().push((depth, self.name.clone())));
+
+        let mut node = with_scope(Some(self.name.clone()), || {
+            build_context::slot(&format!("nav-{}", self.name), || {
+                match Navigator::named(self.name.clone()).render_top() {
+                    Some(page) => page.build(),
+                    None => crate::ui::widget::text("Navigator has no routes.").build(),
                 }
+            })
+        });
+        if self.key.is_some() {
+            node.set_key(self.key.clone());
+        }
+        node
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+/// A nested navigator called `name`, starting at `initial_route`.
+pub fn navigator(name: impl Into<String>, initial_route: Route) -> NestedNavigator {
+    NestedNavigator {
+        name: name.into(),
+        initial_route,
+        key: None,
+    }
+}
+
+// ==========================
+//         TAB HOST
+// ==========================
+
+pub struct Tab {
+    /// How the tab shows in the bar. Its label also names the tab's navigator.
+    pub item: NavItem,
+    pub route: Route,
+}
+
+pub fn tab(label: impl Into<String>, route: Route) -> Tab {
+    Tab {
+        item: NavItem::new(label),
+        route,
+    }
+}
+
+impl Tab {
+    pub fn icon(mut self, icon: IconType) -> Self {
+        self.item = self.item.icon(icon);
+        self
+    }
+
+    /// Badge on the tab; `None` clears it.
+    pub fn badge(mut self, badge: Option<Badge>) -> Self {
+        self.item = self.item.badge(badge);
+        self
+    }
+}
+
+/// Which bar a `TabHost` switches tabs with.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TabBarStyle {
+    /// A `NavigationBar` under the tabs.
+    #[default]
+    NavigationBar,
+    /// A `TabRow` over the tabs.
+    TabRow,
+}
+
+/// Tabs, each with its own nested navigator, and a bar to switch between them.
+/// A tab keeps its stack while another one is selected. Selecting the tab
+/// that's already selected pops it back to its first route.
+///
+/// Tab `label` of host `name` uses the navigator `"{name}/{label}"`.
+pub struct TabHost {
+    pub name: String,
+    pub tabs: Vec<Tab>,
+    pub selected: Option<State<usize>>,
+    pub bar: TabBarStyle,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(TabHost);
+impl_key!(TabHost);
+
+impl TabHost {
+    /// Keep the selected tab index in `selected` (e.g. to switch tabs from code).
+    pub fn selected(mut self, selected: State<usize>) -> Self {
+        self.selected = Some(selected);
+        self
+    }
+
+    pub fn bar(mut self, style: TabBarStyle) -> Self {
+        self.bar = style;
+        self
+    }
+
+    pub fn tab_navigator_name(&self, label: &str) -> String {
+        format!("{}/{}", self.name, label)
+    }
+}
+
+impl Widget for TabHost {
+    fn build(&self) -> UiNode {
+        let selected = match &self.selected {
+            Some(selected) => selected.clone(),
+            None => use_state(|| 0usize),
+        };
+        let index = selected.get().min(self.tabs.len().saturating_sub(1));
+
+        let content: Box<dyn Widget> = match self.tabs.get(index) {
+            Some(tab) => navigator(self.tab_navigator_name(&tab.item.label), tab.route.clone())
+                .key(tab.item.label.clone())
+                .into_widget(),
+            None => crate::ui::widget::text("No tabs.").into_widget(),
+        };
+        let items: Vec<NavItem> = self.tabs.iter().map(|tab| tab.item.clone()).collect();
+        let tab_navigators: Vec<NavigatorHandle> = self
+            .tabs
+            .iter()
+            .map(|tab| Navigator::named(self.tab_navigator_name(&tab.item.label)))
+            .collect();
+        // The bar has set `selected` already; a tap on the tab that was shown
+        // goes back to its first route
+        let on_select = move |i: usize| {
+            if i == index {
+                tab_navigators[i].pop_to_first();
+            }
+        };
+        let content = column(vec![content])
+            .weight(1.0)
+            .fill_max_width()
+            .into_widget();
+
+        let mut host = match self.bar {
+            TabBarStyle::NavigationBar => column(vec![
+                content,
+                navigation_bar(&selected, items)
+                    .on_select(on_select)
+                    .into_widget(),
+            ]),
+            TabBarStyle::TabRow => column(vec![
+                tab_row(&selected, items).on_select(on_select).into_widget(),
+                content,
+            ]),
+        };
+        host.modifiers = self.modifiers.clone();
+        host.key = self.key.clone();
+        host.build()
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+/// A tab host called `name`; see `TabHost`.
+pub fn tab_host(name: impl Into<String>, tabs: Vec<Tab>) -> TabHost {
+    TabHost {
+        name: name.into(),
+        tabs,
+        selected: None,
+        bar: TabBarStyle::default(),
+        modifiers: Modifiers::default(),
+        key: None,
+    }
+}
+
+// ==========================
+//          ROUTER
+// ==========================
+
+/// Parameters of a route built by the `Router`: the `:name` segments of its
+/// pattern, and the query string (over the pattern's defaults).
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RouteParams {
+    /// The path that was matched, without query, e.g. `"/users/42"`.
+    pub path: String,
+    pub params: HashMap<String, String>,
+    pub query: HashMap<String, String>,
+}
+
+impl RouteParams {
+    /// A path parameter, e.g. `id` for `/users/:id`.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str)
+    }
+
+    /// A path parameter parsed as `T`; `None` if it's missing or doesn't parse.
+    pub fn parse<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.get(name)?.parse().ok()
+    }
+
+    /// A query parameter, e.g. `tab` for `?tab=posts`.
+    pub fn query(&self, name: &str) -> Option<&str> {
+        self.query.get(name).map(String::as_str)
+    }
+}
+
+type PageBuilder = Arc<dyn Fn(&RouteParams) -> Box<dyn Widget> + Send + Sync>;
+
+struct RoutePattern {
+    segments: Vec<String>,
+    defaults: HashMap<String, String>,
+    builder: PageBuilder,
+}
+
+impl RoutePattern {
+    fn matches(&self, segments: &[String]) -> Option<HashMap<String, String>> {
+        if segments.len() != self.segments.len() {
+            return None;
+        }
+        let mut params = HashMap::new();
+        for (pattern, segment) in self.segments.iter().zip(segments) {
+            match pattern.strip_prefix(':') {
+                Some(name) => {
+                    params.insert(name.to_string(), segment.clone());
+                }
+                None if pattern == segment => {}
+                None => return None,
             }
+        }
+        Some(params)
+    }
+}
+
+/// The app's table of routes, matched against URL-style paths.
+///
+/// ```ignore
+/// Router::new()
+///     .route("/", |_| Home)
+///     .route("/users/:id?tab=posts", |p| UserScreen::new(p.parse("id"), p.query("tab")))
+///     .not_found(|p| NotFound::new(&p.path))
+///     .install();
+/// ```
+///
+/// Patterns are tried in the order they were added. A `?key=value` suffix
+/// gives query parameters a default.
+pub struct Router {
+    patterns: Vec<RoutePattern>,
+    not_found: PageBuilder,
+}
+
+static ROUTER: OnceLock<Mutex<Option<Arc<Router>>>> = OnceLock::new();
+
+fn get_router() -> &'static Mutex<Option<Arc<Router>>> {
+    ROUTER.get_or_init(|| Mutex::new(None))
+}
+
+/// Uninstall the router and empty every navigator, e.g. between tests.
+#[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+pub(crate) fn clear_navigation() {
+    *get_router().lock().unwrap() = None;
+    replace_all(vec![]);
+}
+
+impl Default for Router {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Router {
+    pub fn new() -> Self {
+        Self {
+            patterns: vec![],
+            not_found: Arc::new(|params: &RouteParams| {
+                Box::new(crate::ui::widget::text(&format!(
+                    "Page not found: {}",
+                    params.path
+                )))
+            }),
+        }
+    }
+
+    pub fn route<F, W>(mut self, pattern: &str, builder: F) -> Self
+    where
+        F: Fn(&RouteParams) -> W + Send + Sync + 'static,
+        W: Widget + 'static,
+    {
+        let (path, query) = split_query(pattern);
+        self.patterns.push(RoutePattern {
+            segments: path_segments(path),
+            defaults: parse_query(query),
+            builder: Arc::new(move |params: &RouteParams| Box::new(builder(params))),
+        });
+        self
+    }
+
+    /// The page for paths no pattern matches.
+    pub fn not_found<F, W>(mut self, builder: F) -> Self
+    where
+        F: Fn(&RouteParams) -> W + Send + Sync + 'static,
+        W: Widget + 'static,
+    {
+        self.not_found = Arc::new(move |params: &RouteParams| Box::new(builder(params)));
+        self
+    }
+
+    /// Make this the app's router, replacing any installed before.
+    pub fn install(self) {
+        info!("Router installed with {} routes.", self.patterns.len());
+        *get_router().lock().unwrap() = Some(Arc::new(self));
+    }
+
+    fn is_installed() -> bool {
+        get_router().lock().unwrap().is_some()
+    }
+
+    fn installed() -> Arc<Router> {
+        get_router().lock().unwrap().clone().unwrap_or_else(|| {
+            warn!("No router installed.");
+            Arc::new(Router::new())
+        })
+    }
+
+    // The route for `path` with `query`, if a pattern matches.
+    fn resolve(&self, segments: &[String], query: &HashMap<String, String>) -> Option<Route> {
+        self.patterns.iter().find_map(|pattern| {
+            let params = pattern.matches(segments)?;
+            let mut all_query = pattern.defaults.clone();
+            all_query.extend(query.clone());
+            Some(self.page(segments, params, all_query, pattern.builder.clone()))
+        })
+    }
+
+    fn page(
+        &self,
+        segments: &[String],
+        params: HashMap<String, String>,
+        query: HashMap<String, String>,
+        builder: PageBuilder,
+    ) -> Route {
+        let path = format!("/{}", segments.join("/"));
+        let params = RouteParams {
+            path: path.clone(),
+            params,
+            query,
+        };
+        Route::with_args(path, params, move |params: &RouteParams| {
+            RouterPage(builder(params))
+        })
+    }
+
+    /// The route for `uri` (a path like `/users/42?tab=posts`, or a full URL),
+    /// or the not-found route.
+    pub fn route_for(uri: &str) -> Route {
+        let router = Self::installed();
+        let (segments, query) = parse_uri(uri);
+        router.resolve(&segments, &query).unwrap_or_else(|| {
+            debug!("Router: no route for {}", uri);
+            router.page(&segments, HashMap::new(), query, router.not_found.clone())
+        })
+    }
+
+    /// The stack for a deep link: a route for each prefix of the path that
+    /// matches a pattern, ending with the route for the whole `uri`. E.g.
+    /// `/users/42` gives `/`, `/users`, `/users/42`, skipping prefixes with no route.
+    pub fn stack_for(uri: &str) -> Vec<Route> {
+        let router = Self::installed();
+        let (segments, _) = parse_uri(uri);
+        let mut stack: Vec<Route> = (0..segments.len())
+            .filter_map(|len| router.resolve(&segments[..len], &HashMap::new()))
+            .collect();
+        stack.push(Self::route_for(uri));
+        stack
+    }
+
+    // The route for params saved from a route it built
+    pub(crate) fn restore(params: &RouteParams) -> Route {
+        let router = Self::installed();
+        let segments = path_segments(&params.path);
+        router.resolve(&segments, &params.query).unwrap_or_else(|| {
+            router.page(
+                &segments,
+                HashMap::new(),
+                params.query.clone(),
+                router.not_found.clone(),
+            )
+        })
+    }
+
+    /// Whether a pattern matches `uri`.
+    pub fn matches(uri: &str) -> bool {
+        let (segments, query) = parse_uri(uri);
+        Self::installed().resolve(&segments, &query).is_some()
+    }
+}
+
+// Router pages are built as boxed widgets.
+struct RouterPage(Box<dyn Widget>);
+
+impl Widget for RouterPage {
+    fn build(&self) -> UiNode {
+        self.0.build()
+    }
+}
+
+fn split_query(uri: &str) -> (&str, &str) {
+    let uri = uri.split('#').next().unwrap_or("");
+    uri.split_once('?').unwrap_or((uri, ""))
+}
+
+fn path_segments(path: &str) -> Vec<String> {
+    path.split('/')
+        .filter(|segment| !segment.is_empty())
+        .map(percent_decode)
+        .collect()
+}
+
+fn parse_query(query: &str) -> HashMap<String, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (
+                percent_decode(&key.replace('+', " ")),
+                percent_decode(&value.replace('+', " ")),
+            )
+        })
+        .collect()
+}
+
+// Path segments and query of a path or a full URL (`scheme://host/path?query`).
+fn parse_uri(uri: &str) -> (Vec<String>, HashMap<String, String>) {
+    let uri = match uri.split_once("://") {
+        // Drop the scheme and the host
+        Some((_, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or(""),
+        None => uri,
+    };
+    let (path, query) = split_query(uri);
+    (path_segments(path), parse_query(query))
+}
+
+fn percent_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        // `from_str_radix` alone would take a sign, as in "%+1"
+        if bytes[i] == b'%'
+            && let Some(byte) = input
+                .get(i + 1..i + 3)
+                .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            out.push(byte);
+            i += 3;
         } else {
-            warn!("Navigator render_current called before init.");
+            out.push(bytes[i]);
+            i += 1;
         }
-        None
     }
+    String::from_utf8_lossy(&out).into_owned()
 }
 
 // --- Android back handling helpers (exported via UniFFI) ---
+// System back goes to the innermost navigator on screen that can pop, after
+// the back interceptors of the routes it would leave.
 #[uniffi::export]
 pub fn padauk_nav_can_pop() -> bool {
-    Navigator::can_pop()
+    Navigator::handles_system_back()
+}
+
+/// Handle system back. Returns `false` if nothing took it (e.g. the root
+/// route's interceptor let it through): the host should then handle it itself,
+/// e.g. by closing the app.
+#[uniffi::export]
+pub fn padauk_nav_pop() -> bool {
+    // Back interceptors are app code
+    let result = crate::panics::catch("back", || batch(Navigator::system_back));
+    // A panicking interceptor drops the press rather than closing the app
+    result.unwrap_or_else(|report| {
+        crate::panics::report(&report);
+        true
+    })
 }
 
+/// The navigator stacks, for the host to keep in its saved instance state.
+/// Empty if saving them panicked.
 #[uniffi::export]
-pub fn padauk_nav_pop() {
-    Navigator::pop();
+pub fn padauk_nav_save_state() -> String {
+    // Route arguments are serialized with the app's own types
+    let result = crate::panics::catch("save state", Navigator::save_state);
+    result.unwrap_or_else(|report| {
+        crate::panics::report(&report);
+        String::new()
+    })
+}
+
+/// Restore stacks saved by `padauk_nav_save_state` after the process was
+/// killed. Returns whether they were restored.
+#[uniffi::export]
+pub fn padauk_nav_restore_state(saved: String) -> bool {
+    // Route builders and guards are app code
+    let result = crate::panics::catch("restore state", || {
+        batch(|| Navigator::restore_state(&saved))
+    });
+    result.unwrap_or_else(|report| {
+        crate::panics::report(&report);
+        false
+    })
+}
+
+/// Open a deep link (e.g. an intent's data URI): the stack is replaced by
+/// `Router::stack_for(uri)`, as its guards allow. Returns whether a route
+/// matched the whole path; without a `Router` installed, the stack is left
+/// as it is.
+#[uniffi::export]
+pub fn padauk_open_deep_link(uri: String) -> bool {
+    debug!("Open deep link: {}", uri);
+    if !Router::is_installed() {
+        warn!("Deep link {} ignored: no router installed.", uri);
+        return false;
+    }
+    // Patterns, route builders and guards are app code
+    let result = crate::panics::catch("deep link", || {
+        batch(|| {
+            let matched = Router::matches(&uri);
+            Navigator::set_stack(Router::stack_for(&uri));
+            matched
+        })
+    });
+    result.unwrap_or_else(|report| {
+        crate::panics::report(&report);
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PadaukApp;
+    use crate::children;
+    use crate::prelude::{BackDecision, RouteRegistry, State, button, column, spawn, state, text};
+    use crate::testing::{WidgetTester, by_kind, by_text};
+
+    // Pushes a route for a result, which guards send to "login"
+    struct Home {
+        result: State<String>,
+    }
+
+    impl Widget for Home {
+        fn build(&self) -> UiNode {
+            let (on_result, awaited) = (self.result.clone(), self.result.clone());
+            column(children![
+                text(&format!("Result: {}", self.result.get())),
+                button("Callback", move || {
+                    let result = on_result.clone();
+                    Navigator::push_for_result::<i32>(secret())
+                        .on_result(move |value| result.set(format!("{:?}", value)));
+                }),
+                button("Await", move || {
+                    let result = awaited.clone();
+                    let value = Navigator::push_for_result::<i32>(secret());
+                    spawn(async move { result.set(format!("awaited {:?}", value.await)) });
+                }),
+            ])
+            .build()
+        }
+    }
+
+    fn secret() -> Route {
+        Route::new("secret", || text("Secret"))
+            .guard(|_| GuardDecision::Redirect(Route::new("login", || text("Login"))))
+    }
+
+    struct App {
+        result: State<String>,
+    }
+
+    impl PadaukApp for App {
+        fn initial_route(&self) -> Route {
+            let result = self.result.clone();
+            Route::new("home", move || Home {
+                result: result.clone(),
+            })
+        }
+    }
+
+    #[test]
+    fn redirected_push_for_result_resolves_to_none() {
+        let result = state(String::new());
+        let mut tester = WidgetTester::mount_app(App {
+            result: result.clone(),
+        });
+
+        tester.tap(&by_text("Callback"));
+        assert_eq!(tester.route_names(), vec!["home", "login"]);
+        assert_eq!(result.get_untracked(), "None");
+
+        assert!(tester.back());
+        tester.tap(&by_text("Await"));
+        tester.run_tasks();
+        assert_eq!(tester.route_names(), vec!["home", "login"]);
+        assert_eq!(result.get_untracked(), "awaited None");
+
+        // Popping the redirect target doesn't resolve anything a second time
+        assert!(tester.back());
+        assert!(tester.exists(&by_text("Result: awaited None")));
+    }
+
+    struct Tabs(TabBarStyle);
+
+    impl PadaukApp for Tabs {
+        fn initial_route(&self) -> Route {
+            let style = self.0;
+            Route::new("tabs", move || {
+                tab_host(
+                    "main",
+                    vec![
+                        tab(
+                            "Feed",
+                            Route::new("feed", || {
+                                button("Open", || {
+                                    Navigator::push(Route::new("post", || text("Post")))
+                                })
+                            }),
+                        ),
+                        tab("Profile", Route::new("profile", || text("Profile")))
+                            .badge(Some(Badge::Dot)),
+                    ],
+                )
+                .bar(style)
+            })
+        }
+    }
+
+    #[test]
+    fn tabs_keep_their_stack_and_pop_to_first_when_selected_again() {
+        let mut tester = WidgetTester::mount_app(Tabs(TabBarStyle::NavigationBar));
+        let bar = by_kind("NavigationBar");
+        tester.tap(&by_text("Open"));
+        assert!(tester.exists(&by_text("Post")));
+
+        tester.select(&bar, 1);
+        assert!(tester.exists(&by_text("Profile")));
+        tester.select(&bar, 0);
+        assert!(tester.exists(&by_text("Post")));
+
+        tester.select(&bar, 0);
+        assert!(tester.exists(&by_text("Open")));
+        assert_eq!(Navigator::named("main/Feed").route_names(), vec!["feed"]);
+    }
+
+    #[test]
+    fn tab_hosts_can_switch_tabs_with_a_tab_row() {
+        let mut tester = WidgetTester::mount_app(Tabs(TabBarStyle::TabRow));
+        assert!(!tester.exists(&by_kind("NavigationBar")));
+
+        tester.select(&by_kind("TabRow"), 1);
+        assert!(tester.exists(&by_text("Profile")));
+    }
+
+    #[test]
+    fn percent_decode_reads_hex_escapes() {
+        assert_eq!(percent_decode("a%20b%2Fc"), "a b/c");
+        assert_eq!(percent_decode("caf%C3%A9"), "café");
+    }
+
+    #[test]
+    fn percent_decode_keeps_invalid_escapes() {
+        assert_eq!(percent_decode("%+1"), "%+1");
+        assert_eq!(percent_decode("%-f"), "%-f");
+        assert_eq!(percent_decode("100%"), "100%");
+        assert_eq!(percent_decode("%zz%4"), "%zz%4");
+    }
+
+    #[test]
+    fn deep_link_panics_are_caught() {
+        let mut tester = WidgetTester::mount_app(App {
+            result: state(String::new()),
+        });
+        Router::new().route("/secret", |_| text("Secret")).install();
+        Navigator::add_guard(|_| panic!("guard failed"));
+
+        assert!(!padauk_open_deep_link("app://host/secret".to_string()));
+        tester.pump();
+        assert_eq!(tester.route_names(), vec!["home"]);
+    }
+
+    // Arguments whose serialization panics
+    struct Unsaveable;
+
+    impl serde::Serialize for Unsaveable {
+        fn serialize<S: serde::Serializer>(&self, _: S) -> Result<S::Ok, S::Error> {
+            panic!("can't save")
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for Unsaveable {
+        fn deserialize<D: serde::Deserializer<'de>>(_: D) -> Result<Self, D::Error> {
+            Ok(Unsaveable)
+        }
+    }
+
+    #[test]
+    fn save_and_restore_panics_are_caught() {
+        let mut tester = WidgetTester::mount_app(App {
+            result: state(String::new()),
+        });
+        RouteRegistry::register("home", || Route::new("home", || text("Home")));
+        RouteRegistry::register_with_args("unsaveable", |_: &Unsaveable| {
+            Route::new("unsaveable", || text("Unsaveable"))
+        });
+        Navigator::push(Route::with_args("unsaveable", Unsaveable, |_| {
+            text("Unsaveable")
+        }));
+        assert_eq!(padauk_nav_save_state(), "");
+
+        // The navigator is still usable after a panic while saving
+        assert!(tester.back());
+        RouteRegistry::register("unrestorable", || panic!("can't restore"));
+        Navigator::push(Route::new("unrestorable", || text("Unrestorable")));
+        let saved = padauk_nav_save_state();
+        assert!(saved.contains("unrestorable"));
+
+        assert!(tester.back());
+        assert!(!padauk_nav_restore_state(saved));
+        tester.pump();
+        assert_eq!(tester.route_names(), vec!["home"]);
+    }
+
+    #[test]
+    fn back_on_the_root_route_goes_to_the_host_once_interceptors_agree() {
+        let mut tester = WidgetTester::mount_app(App {
+            result: state(String::new()),
+        });
+        let leave = state(false);
+        let allow = leave.clone();
+        Navigator::root().set_stack(vec![Route::new("root", || text("Root")).on_back(
+            move || {
+                if allow.get_untracked() {
+                    BackDecision::Pop
+                } else {
+                    BackDecision::Handled
+                }
+            },
+        )]);
+        tester.pump();
+
+        assert!(padauk_nav_can_pop());
+        assert!(tester.back());
+        leave.set(true);
+        assert!(padauk_nav_can_pop());
+        assert!(!tester.back());
+        assert_eq!(tester.route_names(), vec!["root"]);
+    }
+
+    // A bottom sheet holding unsaved changes
+    struct Form {
+        dirty: State<bool>,
+    }
+
+    impl Widget for Form {
+        fn build(&self) -> UiNode {
+            let dirty = self.dirty.clone();
+            crate::ui::back_handler::use_back_handler(move || {
+                if dirty.get_untracked() {
+                    BackDecision::Handled
+                } else {
+                    BackDecision::Pop
+                }
+            });
+            text("Form").build()
+        }
+    }
+
+    #[test]
+    fn dismissing_a_sheet_asks_its_back_interceptors() {
+        let mut tester = WidgetTester::mount_app(App {
+            result: state(String::new()),
+        });
+        let dirty = state(true);
+        let form_dirty = dirty.clone();
+        Navigator::show_bottom_sheet::<()>(Route::new("form", move || Form {
+            dirty: form_dirty.clone(),
+        }))
+        .on_result(|_| {});
+        tester.pump();
+
+        assert!(tester.back());
+        assert_eq!(tester.route_names(), vec!["home", "form"]);
+        assert!(tester.exists(&by_text("Form")));
+
+        dirty.set(false);
+        assert!(tester.back());
+        assert_eq!(tester.route_names(), vec!["home"]);
+    }
 }
@@ -0,0 +1,396 @@
+use crate::UiNode;
+use crate::ui::build_context;
+use crate::ui::button::IconType;
+use crate::ui::event_registry::{EventHandler, register_scoped_event_action};
+use crate::ui::state::State;
+use crate::{Widget, impl_key, impl_modifiers, ui::modifier::Modifiers};
+use std::sync::Arc;
+
+// Small marker on an item's icon (or label, on a tab without one)
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
+pub enum Badge {
+    Dot,
+    // Drawn as "99+" above 99
+    Count { count: u32 },
+    Text { text: String },
+}
+
+/// A destination of a navigation bar, navigation rail or tab row.
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct NavItem {
+    pub label: String,
+    pub icon: Option<IconType>,
+    // Drawn instead of `icon` while the item is selected
+    pub selected_icon: Option<IconType>,
+    pub badge: Option<Badge>,
+    pub enabled: bool,
+}
+
+impl NavItem {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            selected_icon: None,
+            badge: None,
+            enabled: true,
+        }
+    }
+
+    pub fn icon(mut self, icon: IconType) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn selected_icon(mut self, icon: IconType) -> Self {
+        self.selected_icon = Some(icon);
+        self
+    }
+
+    /// Badge on the item; `None` clears it.
+    pub fn badge(mut self, badge: Option<Badge>) -> Self {
+        self.badge = badge;
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+/// An item with an icon, for a navigation bar or rail.
+pub fn nav_item(icon: IconType, label: impl Into<String>) -> NavItem {
+    NavItem::new(label).icon(icon)
+}
+
+/// An item with only a label, for a tab row.
+pub fn tab_item(label: impl Into<String>) -> NavItem {
+    NavItem::new(label)
+}
+
+// Registers the action the renderer dispatches with the index of the item
+// tapped (EventPayload::Selection), and returns the index to show selected.
+#[cfg(not(target_os = "ios"))]
+fn bind_selection(
+    items: &[NavItem],
+    selected: &State<usize>,
+    on_select: &Option<EventHandler<usize>>,
+) -> (u32, String) {
+    let count = items.len();
+    let index = selected.get().min(count.saturating_sub(1));
+    let (selected, on_select) = (selected.clone(), on_select.clone());
+    let action_id = register_scoped_event_action(
+        "select",
+        Arc::new(move |index: usize| {
+            if index >= count {
+                return;
+            }
+            selected.set(index);
+            if let Some(on_select) = &on_select {
+                on_select(index);
+            }
+        }),
+    );
+    (index as u32, action_id)
+}
+
+/// A bottom navigation bar bound to a `State<usize>`: the item at that index
+/// is selected, and tapping an item sets it. Put it in a scaffold's
+/// `bottom_bar`.
+pub struct NavigationBar {
+    pub items: Vec<NavItem>,
+    pub selected: State<usize>,
+    pub on_select: Option<EventHandler<usize>>,
+    // Labels of unselected items too
+    pub always_show_labels: bool,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(NavigationBar);
+impl_key!(NavigationBar);
+
+impl Widget for NavigationBar {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS tab bars
+            UiNode::Label {
+                title: self
+                    .items
+                    .get(self.selected.get())
+                    .map(|item| item.label.clone())
+                    .unwrap_or_default(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let (selected, action_id) =
+                bind_selection(&self.items, &self.selected, &self.on_select);
+            UiNode::NavigationBar {
+                items: self.items.clone(),
+                selected,
+                action_id,
+                always_show_labels: self.always_show_labels,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl NavigationBar {
+    pub fn new(selected: &State<usize>, items: Vec<NavItem>) -> Self {
+        Self {
+            items,
+            selected: selected.clone(),
+            on_select: None,
+            always_show_labels: true,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// Called with the index of the item tapped, once the state holds it.
+    /// Tapping the selected item calls it too (e.g. to scroll back to the top).
+    pub fn on_select(mut self, on_select: impl Fn(usize) + Send + Sync + 'static) -> Self {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    pub fn always_show_labels(mut self, value: bool) -> Self {
+        self.always_show_labels = value;
+        self
+    }
+}
+
+pub fn navigation_bar(selected: &State<usize>, items: Vec<NavItem>) -> NavigationBar {
+    NavigationBar::new(selected, items)
+}
+
+/// A vertical navigation bar for the side of large screens, bound to a
+/// `State<usize>` like `NavigationBar`. It can hold a header (e.g. a FAB)
+/// above its items.
+pub struct NavigationRail {
+    pub items: Vec<NavItem>,
+    pub selected: State<usize>,
+    pub on_select: Option<EventHandler<usize>>,
+    pub always_show_labels: bool,
+    pub header: Option<Box<dyn Widget>>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(NavigationRail);
+impl_key!(NavigationRail);
+
+impl Widget for NavigationRail {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS navigation rails
+            UiNode::Label {
+                title: self
+                    .items
+                    .get(self.selected.get())
+                    .map(|item| item.label.clone())
+                    .unwrap_or_default(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let (selected, action_id) =
+                bind_selection(&self.items, &self.selected, &self.on_select);
+            let header = match &self.header {
+                Some(header) => vec![build_context::slot("header", || header.build())],
+                None => vec![],
+            };
+            UiNode::NavigationRail {
+                items: self.items.clone(),
+                selected,
+                action_id,
+                always_show_labels: self.always_show_labels,
+                header,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl NavigationRail {
+    pub fn new(selected: &State<usize>, items: Vec<NavItem>) -> Self {
+        Self {
+            items,
+            selected: selected.clone(),
+            on_select: None,
+            always_show_labels: true,
+            header: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// See `NavigationBar::on_select`.
+    pub fn on_select(mut self, on_select: impl Fn(usize) + Send + Sync + 'static) -> Self {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    pub fn always_show_labels(mut self, value: bool) -> Self {
+        self.always_show_labels = value;
+        self
+    }
+
+    pub fn header(mut self, header: impl Widget + 'static) -> Self {
+        self.header = Some(Box::new(header));
+        self
+    }
+}
+
+pub fn navigation_rail(selected: &State<usize>, items: Vec<NavItem>) -> NavigationRail {
+    NavigationRail::new(selected, items)
+}
+
+/// A row of tabs bound to a `State<usize>`, like `NavigationBar`. Tabs show
+/// their label, and their icon if they have one.
+pub struct TabRow {
+    pub items: Vec<NavItem>,
+    pub selected: State<usize>,
+    pub on_select: Option<EventHandler<usize>>,
+    // Tabs take their own width and the row scrolls, instead of sharing it
+    pub scrollable: bool,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(TabRow);
+impl_key!(TabRow);
+
+impl Widget for TabRow {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS segmented controls
+            UiNode::Label {
+                title: self
+                    .items
+                    .get(self.selected.get())
+                    .map(|item| item.label.clone())
+                    .unwrap_or_default(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let (selected, action_id) =
+                bind_selection(&self.items, &self.selected, &self.on_select);
+            UiNode::TabRow {
+                items: self.items.clone(),
+                selected,
+                action_id,
+                scrollable: self.scrollable,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl TabRow {
+    pub fn new(selected: &State<usize>, items: Vec<NavItem>) -> Self {
+        Self {
+            items,
+            selected: selected.clone(),
+            on_select: None,
+            scrollable: false,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// See `NavigationBar::on_select`.
+    pub fn on_select(mut self, on_select: impl Fn(usize) + Send + Sync + 'static) -> Self {
+        self.on_select = Some(Arc::new(on_select));
+        self
+    }
+
+    pub fn scrollable(mut self, value: bool) -> Self {
+        self.scrollable = value;
+        self
+    }
+}
+
+pub fn tab_row(selected: &State<usize>, items: Vec<NavItem>) -> TabRow {
+    TabRow::new(selected, items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_kind};
+    use std::sync::Mutex;
+
+    fn items() -> Vec<NavItem> {
+        vec![
+            nav_item(IconType::Home, "Home"),
+            nav_item(IconType::Search, "Search"),
+        ]
+    }
+
+    #[test]
+    fn selecting_an_item_past_the_end_is_ignored() {
+        let page = state(1);
+        let mut tester = WidgetTester::mount(navigation_bar(&page, items()));
+
+        tester.select(&by_kind("NavigationBar"), 2);
+        assert_eq!(page.get_untracked(), 1);
+        assert_eq!(tester.redraw_count(), 0);
+    }
+
+    #[test]
+    fn a_selected_index_past_the_end_shows_the_last_item() {
+        let tester = WidgetTester::mount(tab_row(&state(5), items()));
+
+        let UiNode::TabRow { selected, .. } = tester.find(&by_kind("TabRow")).node else {
+            panic!("Not a tab row");
+        };
+        assert_eq!(selected, 1);
+    }
+
+    #[test]
+    fn on_select_is_called_again_when_the_selected_item_is_tapped() {
+        static SELECTED: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+        let page = state(0);
+        let mut tester = WidgetTester::mount(
+            navigation_rail(&page, items()).on_select(|index| SELECTED.lock().unwrap().push(index)),
+        );
+
+        tester.select(&by_kind("NavigationRail"), 1);
+        tester.select(&by_kind("NavigationRail"), 1);
+        assert_eq!(page.get_untracked(), 1);
+        assert_eq!(*SELECTED.lock().unwrap(), vec![1, 1]);
+    }
+}
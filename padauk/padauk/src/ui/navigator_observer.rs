@@ -0,0 +1,219 @@
+use crate::panics;
+use crate::ui::navigation::with_scope;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A change to a navigator's stack, as reported to a `NavigatorObserver`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteChange {
+    /// Name of the navigator, `"root"` for the top-level one.
+    pub navigator: String,
+    /// The route pushed, popped, removed, or put in place by a replace.
+    pub route: String,
+    /// For a push, the route now covered; for a pop, the route now on top;
+    /// for a replace, the route replaced; for a remove, `None`.
+    pub previous_route: Option<String>,
+    /// Number of routes on the stack after the change.
+    pub depth: usize,
+}
+
+/// Told about every change to any navigator's stack, e.g. for analytics.
+/// Callbacks run after the change, and may navigate.
+pub trait NavigatorObserver: Send + Sync {
+    fn did_push(&self, _change: &RouteChange) {}
+
+    fn did_pop(&self, _change: &RouteChange) {}
+
+    fn did_replace(&self, _change: &RouteChange) {}
+
+    /// A route left the stack other than by a pop from the top, e.g. when
+    /// the stack was replaced or the navigator's host route was popped.
+    fn did_remove(&self, _change: &RouteChange) {}
+}
+
+pub type RouteHook = Arc<dyn Fn() + Send + Sync>;
+
+pub(crate) enum Notification {
+    Push(RouteChange),
+    Pop(RouteChange),
+    Replace(RouteChange),
+    Remove(RouteChange),
+    /// A route's `on_enter` / `on_exit`, run in its navigator
+    Hook(Option<String>, RouteHook),
+}
+
+/// Identifies an observer for `Navigator::remove_observer`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObserverId(u64);
+
+type Observers = Vec<(ObserverId, Arc<dyn NavigatorObserver>)>;
+
+static OBSERVERS: OnceLock<Mutex<Observers>> = OnceLock::new();
+static NEXT_OBSERVER_ID: AtomicU64 = AtomicU64::new(1);
+
+fn get_observers() -> &'static Mutex<Observers> {
+    OBSERVERS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+pub(crate) fn add_observer(observer: Arc<dyn NavigatorObserver>) -> ObserverId {
+    let id = ObserverId(NEXT_OBSERVER_ID.fetch_add(1, Ordering::Relaxed));
+    get_observers().lock().unwrap().push((id, observer));
+    id
+}
+
+pub(crate) fn remove_observer(id: ObserverId) {
+    get_observers()
+        .lock()
+        .unwrap()
+        .retain(|(observer_id, _)| *observer_id != id);
+}
+
+#[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+pub(crate) fn clear_observers() {
+    get_observers().lock().unwrap().clear();
+}
+
+thread_local! {
+    // Notifications for changes made while the navigator was locked
+    static PENDING: RefCell<Vec<Notification>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) fn queue(notification: Notification) {
+    PENDING.with(|pending| pending.borrow_mut().push(notification));
+}
+
+/// Deliver queued notifications, once the navigator is unlocked. A panicking
+/// observer or hook is reported and doesn't stop the others.
+pub(crate) fn deliver_pending() {
+    let pending = PENDING.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    if pending.is_empty() {
+        return;
+    }
+    let observers: Vec<Arc<dyn NavigatorObserver>> = get_observers()
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, observer)| observer.clone())
+        .collect();
+    for notification in pending {
+        let result = panics::catch("navigation observer", || match &notification {
+            Notification::Push(change) => observers.iter().for_each(|o| o.did_push(change)),
+            Notification::Pop(change) => observers.iter().for_each(|o| o.did_pop(change)),
+            Notification::Replace(change) => observers.iter().for_each(|o| o.did_replace(change)),
+            Notification::Remove(change) => observers.iter().for_each(|o| o.did_remove(change)),
+            Notification::Hook(scope, hook) => with_scope(scope.clone(), || hook()),
+        });
+        if let Err(report) = result {
+            panics::report(&report);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::WidgetTester;
+
+    static LOG: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    fn log(entry: String) {
+        LOG.lock().unwrap().push(entry);
+    }
+
+    fn take_log() -> Vec<String> {
+        std::mem::take(&mut *LOG.lock().unwrap())
+    }
+
+    fn describe(change: &RouteChange) -> String {
+        format!(
+            "{} {} {:?} {}",
+            change.navigator, change.route, change.previous_route, change.depth
+        )
+    }
+
+    struct Logger;
+
+    impl NavigatorObserver for Logger {
+        fn did_push(&self, change: &RouteChange) {
+            log(format!("push {}", describe(change)));
+        }
+
+        fn did_pop(&self, change: &RouteChange) {
+            log(format!("pop {}", describe(change)));
+        }
+
+        fn did_replace(&self, change: &RouteChange) {
+            log(format!("replace {}", describe(change)));
+        }
+
+        fn did_remove(&self, change: &RouteChange) {
+            log(format!("remove {}", describe(change)));
+        }
+    }
+
+    fn page(name: &'static str) -> Route {
+        Route::new(name, move || text(name))
+            .on_enter(move || log(format!("enter {}", name)))
+            .on_exit(move || log(format!("exit {}", name)))
+    }
+
+    struct App;
+
+    impl PadaukApp for App {
+        fn initial_route(&self) -> Route {
+            page("home")
+        }
+    }
+
+    #[test]
+    fn observers_and_hooks_follow_push_pop_and_replace() {
+        let mut tester = WidgetTester::mount_app(App);
+        Navigator::add_observer(Logger);
+        take_log();
+
+        Navigator::push(page("details"));
+        Navigator::replace(page("edit"));
+        assert!(tester.back());
+
+        assert_eq!(
+            take_log(),
+            vec![
+                "push root details Some(\"home\") 2",
+                "exit home",
+                "enter details",
+                "replace root edit Some(\"details\") 2",
+                "exit details",
+                "enter edit",
+                "pop root edit Some(\"home\") 1",
+                "exit edit",
+                "enter home",
+            ]
+        );
+    }
+
+    #[test]
+    fn replacing_the_stack_removes_every_route_before_pushing() {
+        let _tester = WidgetTester::mount_app(App);
+        Navigator::push(page("details"));
+        let observer = Navigator::add_observer(Logger);
+        take_log();
+
+        Navigator::root().set_stack(vec![page("feed")]);
+        assert_eq!(
+            take_log(),
+            vec![
+                "remove root details None 0",
+                "remove root home None 0",
+                "push root feed None 1",
+                "exit details",
+                "enter feed",
+            ]
+        );
+
+        Navigator::remove_observer(observer);
+        Navigator::push(page("post"));
+        assert_eq!(take_log(), vec!["exit feed", "enter post"]);
+    }
+}
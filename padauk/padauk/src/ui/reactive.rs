@@ -0,0 +1,392 @@
+use crate::ui::build_context;
+use log::debug;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+// Dependency tracking for `State`. Every state has an id and a version that is
+// bumped on each change. Reads are reported to the innermost tracking scope
+// (a `Computed` or an `effect` being evaluated), and during a render pass also
+// recorded against the path of the widget being built.
+
+pub(crate) type Version = Arc<AtomicU64>;
+
+// Paths of the widgets that read each state, by state id
+type Reads = HashMap<u64, HashSet<String>>;
+
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+pub(crate) fn next_id() -> u64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+thread_local! {
+    // States read so far by each scope being evaluated, innermost last
+    static SCOPES: RefCell<Vec<HashMap<u64, Version>>> = const { RefCell::new(Vec::new()) };
+    // States read by the render pass in progress, with the paths that read them
+    static PASS_READS: RefCell<Option<Reads>> = const { RefCell::new(None) };
+    // Effects running on this thread, so an effect that changes its own
+    // dependencies doesn't run itself again
+    static RUNNING_EFFECTS: RefCell<HashSet<u64>> = RefCell::new(HashSet::new());
+}
+
+// States read by the last completed render pass. `None` until the first pass,
+// when every change has to redraw.
+static RENDERED: OnceLock<Mutex<Option<Reads>>> = OnceLock::new();
+
+fn get_rendered() -> &'static Mutex<Option<Reads>> {
+    RENDERED.get_or_init(|| Mutex::new(None))
+}
+
+/// Report a read of state `id`.
+pub(crate) fn track(id: u64, version: &Version) {
+    SCOPES.with(|scopes| {
+        if let Some(scope) = scopes.borrow_mut().last_mut() {
+            scope.entry(id).or_insert_with(|| version.clone());
+        }
+    });
+    PASS_READS.with(|reads| {
+        if let Some(reads) = reads.borrow_mut().as_mut() {
+            reads
+                .entry(id)
+                .or_default()
+                .insert(build_context::current_path());
+        }
+    });
+}
+
+// Evaluate `f`, returning what it read.
+fn tracked<R>(f: impl FnOnce() -> R) -> (R, HashMap<u64, Version>) {
+    SCOPES.with(|scopes| scopes.borrow_mut().push(HashMap::new()));
+    let result = f();
+    let deps = SCOPES.with(|scopes| scopes.borrow_mut().pop().unwrap_or_default());
+    (result, deps)
+}
+
+pub(crate) fn begin_render_pass() {
+    PASS_READS.with(|reads| *reads.borrow_mut() = Some(HashMap::new()));
+}
+
+pub(crate) fn end_render_pass() {
+    let reads = PASS_READS.with(|reads| reads.borrow_mut().take());
+    *get_rendered().lock().unwrap() = reads;
+}
+
+// A pass that panicked didn't replace the screen; keep what the last one read.
+pub(crate) fn abort_render_pass() {
+    PASS_READS.with(|reads| reads.borrow_mut().take());
+}
+
+/// Build part of the screen after its render pass (e.g. list items built on
+/// demand): what it reads is added to what the pass read.
+pub(crate) fn with_late_reads<R>(f: impl FnOnce() -> R) -> R {
+    if PASS_READS.with(|reads| reads.borrow().is_some()) {
+        return f();
+    }
+    PASS_READS.with(|reads| *reads.borrow_mut() = Some(HashMap::new()));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let late = PASS_READS.with(|reads| reads.borrow_mut().take());
+    if let (Some(rendered), Some(late)) = (get_rendered().lock().unwrap().as_mut(), late) {
+        for (id, paths) in late {
+            rendered.entry(id).or_default().extend(paths);
+        }
+    }
+    result.unwrap_or_else(|payload| panic::resume_unwind(payload))
+}
+
+pub(crate) fn scope_depth() -> usize {
+    SCOPES.with(|scopes| scopes.borrow().len())
+}
+
+pub(crate) fn truncate_scopes(depth: usize) {
+    SCOPES.with(|scopes| scopes.borrow_mut().truncate(depth));
+}
+
+/// Whether the screen on display depends on state `id`, i.e. whether a change
+/// to it needs a redraw.
+pub(crate) fn is_rendered(id: u64) -> bool {
+    match get_rendered().lock().unwrap().as_ref() {
+        Some(reads) => reads.contains_key(&id),
+        None => true,
+    }
+}
+
+/// Paths of the widgets that read state `id` in the last render pass.
+pub(crate) fn readers(id: u64) -> Vec<String> {
+    let rendered = get_rendered().lock().unwrap();
+    let mut paths: Vec<String> = rendered
+        .as_ref()
+        .and_then(|reads| reads.get(&id))
+        .map(|paths| paths.iter().cloned().collect())
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
+
+// ==========================
+//         COMPUTED
+// ==========================
+
+struct ComputedInner<T> {
+    value: Option<T>,
+    // Versions of the states read by the last evaluation
+    deps: HashMap<u64, (Version, u64)>,
+}
+
+/// A value derived from other states. It is evaluated on first `get` and
+/// again only after one of the states it read has changed.
+///
+/// Reading a `Computed` counts as reading its dependencies, so a widget
+/// that shows it redraws when they change.
+pub struct Computed<T: Clone + Send + Sync + 'static> {
+    compute: Arc<dyn Fn() -> T + Send + Sync>,
+    inner: Arc<Mutex<ComputedInner<T>>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Clone for Computed<T> {
+    fn clone(&self) -> Self {
+        Self {
+            compute: self.compute.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Computed<T> {
+    pub fn new(compute: impl Fn() -> T + Send + Sync + 'static) -> Self {
+        Self {
+            compute: Arc::new(compute),
+            inner: Arc::new(Mutex::new(ComputedInner {
+                value: None,
+                deps: HashMap::new(),
+            })),
+        }
+    }
+
+    pub fn get(&self) -> T {
+        {
+            let inner = self.inner.lock().unwrap();
+            if let Some(value) = &inner.value
+                && inner
+                    .deps
+                    .values()
+                    .all(|(version, seen)| version.load(Ordering::SeqCst) == *seen)
+            {
+                for (id, (version, _)) in &inner.deps {
+                    track(*id, version);
+                }
+                return value.clone();
+            }
+        }
+
+        // Evaluate without holding the lock; `compute` may read other computeds.
+        let (value, deps) = tracked(|| (self.compute)());
+        for (id, version) in &deps {
+            track(*id, version);
+        }
+        let mut inner = self.inner.lock().unwrap();
+        inner.deps = deps
+            .into_iter()
+            .map(|(id, version)| {
+                let seen = version.load(Ordering::SeqCst);
+                (id, (version, seen))
+            })
+            .collect();
+        inner.value = Some(value.clone());
+        value
+    }
+}
+
+pub fn computed<T: Clone + Send + Sync + 'static>(
+    compute: impl Fn() -> T + Send + Sync + 'static,
+) -> Computed<T> {
+    Computed::new(compute)
+}
+
+// ==========================
+//          EFFECTS
+// ==========================
+
+struct EffectInner {
+    id: u64,
+    run: Box<dyn Fn() + Send + Sync>,
+    disposed: AtomicBool,
+}
+
+// Effects subscribed to each state, by state id, then effect id
+type Subscribers = HashMap<u64, HashMap<u64, Arc<EffectInner>>>;
+
+static SUBSCRIBERS: OnceLock<Mutex<Subscribers>> = OnceLock::new();
+
+fn get_subscribers() -> &'static Mutex<Subscribers> {
+    SUBSCRIBERS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Handle to an effect registered with `effect`.
+#[derive(Clone)]
+pub struct Effect {
+    inner: Arc<EffectInner>,
+}
+
+impl Effect {
+    /// Stop running the effect. Effects are not stopped when the handle is dropped.
+    pub fn dispose(&self) {
+        self.inner.disposed.store(true, Ordering::SeqCst);
+        unsubscribe(self.inner.id);
+    }
+}
+
+/// Run `f` now, and again whenever a state it read changes. Each run tracks
+/// its reads afresh, so the dependencies can differ from run to run.
+pub fn effect(f: impl Fn() + Send + Sync + 'static) -> Effect {
+    let inner = Arc::new(EffectInner {
+        id: next_id(),
+        run: Box::new(f),
+        disposed: AtomicBool::new(false),
+    });
+    run_effect(&inner);
+    Effect { inner }
+}
+
+fn unsubscribe(effect_id: u64) {
+    let mut subscribers = get_subscribers().lock().unwrap();
+    subscribers.retain(|_, effects| {
+        effects.remove(&effect_id);
+        !effects.is_empty()
+    });
+}
+
+fn run_effect(effect: &Arc<EffectInner>) {
+    if effect.disposed.load(Ordering::SeqCst) {
+        return;
+    }
+    let entered = RUNNING_EFFECTS.with(|running| running.borrow_mut().insert(effect.id));
+    if !entered {
+        return;
+    }
+    let result = panic::catch_unwind(AssertUnwindSafe(|| tracked(|| (effect.run)())));
+    RUNNING_EFFECTS.with(|running| running.borrow_mut().remove(&effect.id));
+    let ((), deps) = result.unwrap_or_else(|payload| panic::resume_unwind(payload));
+
+    unsubscribe(effect.id);
+    if effect.disposed.load(Ordering::SeqCst) {
+        return;
+    }
+    let mut subscribers = get_subscribers().lock().unwrap();
+    for id in deps.keys() {
+        subscribers
+            .entry(*id)
+            .or_default()
+            .insert(effect.id, effect.clone());
+    }
+}
+
+/// State `id` changed: run the effects that read it.
+pub(crate) fn notify(id: u64) {
+    let effects: Vec<Arc<EffectInner>> = get_subscribers()
+        .lock()
+        .unwrap()
+        .get(&id)
+        .map(|effects| effects.values().cloned().collect())
+        .unwrap_or_default();
+    if !effects.is_empty() {
+        debug!("State {} changed: running {} effects.", id, effects.len());
+    }
+    for effect in effects {
+        run_effect(&effect);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_text};
+    use std::sync::atomic::AtomicUsize;
+
+    struct Shows(Computed<i32>);
+
+    impl Widget for Shows {
+        fn build(&self) -> UiNode {
+            text(&self.0.get().to_string()).build()
+        }
+    }
+
+    #[test]
+    fn computeds_recompute_only_after_a_dependency_changes() {
+        let _tester = WidgetTester::mount(text("Computed"));
+        let runs = Arc::new(AtomicUsize::new(0));
+        let a = state(1);
+        let doubled = computed({
+            let (runs, a) = (runs.clone(), a.clone());
+            move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+                a.get() * 2
+            }
+        });
+
+        assert_eq!((doubled.get(), doubled.get()), (2, 2));
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        a.set(2);
+        assert_eq!(doubled.get(), 4);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn a_widget_showing_a_computed_redraws_when_its_dependencies_change() {
+        let a = state(1);
+        let doubled = computed({
+            let a = a.clone();
+            move || a.get() * 2
+        });
+        let mut tester = WidgetTester::mount(Shows(doubled));
+        a.set(5);
+
+        assert_eq!(tester.redraw_count(), 1);
+        tester.pump();
+        assert!(tester.exists(&by_text("10")));
+    }
+
+    #[test]
+    fn effects_run_again_on_changes_until_disposed() {
+        let _tester = WidgetTester::mount(text("Effect"));
+        let seen = Arc::new(Mutex::new(vec![]));
+        let a = state(0);
+        let watcher = effect({
+            let (seen, a) = (seen.clone(), a.clone());
+            move || seen.lock().unwrap().push(a.get())
+        });
+
+        a.set(1);
+        watcher.dispose();
+        a.set(2);
+        assert_eq!(*seen.lock().unwrap(), vec![0, 1]);
+    }
+
+    #[test]
+    fn effects_follow_the_states_each_run_read() {
+        let _tester = WidgetTester::mount(text("Effect"));
+        let runs = Arc::new(AtomicUsize::new(0));
+        let (use_b, a, b) = (state(false), state(0), state(0));
+        let watcher = effect({
+            let (runs, use_b, a, b) = (runs.clone(), use_b.clone(), a.clone(), b.clone());
+            move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+                if use_b.get() {
+                    b.get()
+                } else {
+                    a.get()
+                };
+            }
+        });
+
+        use_b.set(true);
+        a.set(1);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        b.set(1);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        watcher.dispose();
+    }
+}
@@ -0,0 +1,506 @@
+use crate::UiNode;
+use log::debug;
+use std::collections::HashSet;
+use std::mem;
+use std::sync::{Mutex, OnceLock};
+
+// A single change to apply to the renderer's retained tree.
+// Paths are child indices from the root, as returned by `UiNode::children`.
+#[derive(uniffi::Enum, Clone, Debug, PartialEq)]
+pub enum UiPatch {
+    /// Insert `node` as child `index` of the list container at `path`.
+    Insert {
+        path: Vec<u32>,
+        index: u32,
+        node: UiNode,
+    },
+    /// Remove child `index` of the list container at `path`.
+    Remove { path: Vec<u32>, index: u32 },
+    /// Replace the node at `path` (and its subtree) with `node`.
+    Replace { path: Vec<u32>, node: UiNode },
+    /// The node at `path` changed its own properties. `node` is sent with empty
+    /// child slots; the renderer keeps the children it already has.
+    UpdateProps { path: Vec<u32>, node: UiNode },
+}
+
+// The tree the renderer currently holds
+static PREVIOUS_TREE: OnceLock<Mutex<Option<UiNode>>> = OnceLock::new();
+
+fn previous_tree() -> &'static Mutex<Option<UiNode>> {
+    PREVIOUS_TREE.get_or_init(|| Mutex::new(None))
+}
+
+/// Record `tree` as the renderer's current tree (e.g. after a full render).
+pub fn commit(tree: UiNode) {
+    *previous_tree().lock().unwrap() = Some(tree);
+}
+
+/// Forget the retained tree; the next patch will replace the root.
+pub fn reset() {
+    *previous_tree().lock().unwrap() = None;
+}
+
+/// Diff `tree` against the retained tree, then retain `tree`.
+pub fn reconcile(mut tree: UiNode) -> Vec<UiPatch> {
+    let mut previous = previous_tree().lock().unwrap();
+    let patches = match previous.as_mut() {
+        Some(old) => {
+            let mut patches = Vec::new();
+            diff_node(old, &mut tree, &mut vec![], &mut patches);
+            patches
+        }
+        None => vec![UiPatch::Replace {
+            path: vec![],
+            node: tree.clone(),
+        }],
+    };
+    debug!("Reconciler produced {} patches.", patches.len());
+    *previous = Some(tree);
+    patches
+}
+
+/// The patches that turn `old` into `new`.
+pub fn diff(old: &UiNode, new: &UiNode) -> Vec<UiPatch> {
+    let mut patches = Vec::new();
+    diff_node(
+        &mut old.clone(),
+        &mut new.clone(),
+        &mut vec![],
+        &mut patches,
+    );
+    patches
+}
+
+/// Apply `patches` to `tree` in order. This is what the renderer does to its retained copy.
+pub fn apply(tree: &mut UiNode, patches: &[UiPatch]) {
+    for patch in patches {
+        match patch {
+            UiPatch::Insert { path, index, node } => {
+                if let Some(list) = node_at(tree, path).and_then(child_list) {
+                    list.insert(*index as usize, node.clone());
+                }
+            }
+            UiPatch::Remove { path, index } => {
+                if let Some(list) = node_at(tree, path).and_then(child_list) {
+                    list.remove(*index as usize);
+                }
+            }
+            UiPatch::Replace { path, node } => {
+                if let Some(target) = node_at(tree, path) {
+                    *target = node.clone();
+                }
+            }
+            UiPatch::UpdateProps { path, node } => {
+                if let Some(target) = node_at(tree, path) {
+                    let slots = take_slots(target);
+                    *target = node.clone();
+                    restore_slots(target, slots);
+                }
+            }
+        }
+    }
+}
+
+fn node_at<'a>(node: &'a mut UiNode, path: &[u32]) -> Option<&'a mut UiNode> {
+    let Some((&first, rest)) = path.split_first() else {
+        return Some(node);
+    };
+    let child = node
+        .child_slots_mut()
+        .into_iter()
+        .flat_map(|slot| slot.iter_mut())
+        .nth(first as usize)?;
+    node_at(child, rest)
+}
+
+fn child_list(node: &mut UiNode) -> Option<&mut Vec<UiNode>> {
+    if !node.has_child_list() {
+        return None;
+    }
+    node.child_slots_mut().into_iter().next()
+}
+
+fn take_slots(node: &mut UiNode) -> Vec<Vec<UiNode>> {
+    node.child_slots_mut().into_iter().map(mem::take).collect()
+}
+
+fn restore_slots(node: &mut UiNode, slots: Vec<Vec<UiNode>>) {
+    for (slot, children) in node.child_slots_mut().into_iter().zip(slots) {
+        *slot = children;
+    }
+}
+
+fn diff_node(old: &mut UiNode, new: &mut UiNode, path: &mut Vec<u32>, patches: &mut Vec<UiPatch>) {
+    if old.kind() != new.kind() {
+        patches.push(UiPatch::Replace {
+            path: path.clone(),
+            node: new.clone(),
+        });
+        return;
+    }
+
+    // Children are detached so the nodes can be compared (and sent) without them.
+    let mut old_slots = take_slots(old);
+    let mut new_slots = take_slots(new);
+
+    // Only list containers can grow or shrink; other nodes have fixed slots.
+    let same_shape = new.has_child_list()
+        || old_slots
+            .iter()
+            .zip(&new_slots)
+            .all(|(a, b)| a.len() == b.len());
+    if !same_shape {
+        restore_slots(old, old_slots);
+        restore_slots(new, new_slots);
+        patches.push(UiPatch::Replace {
+            path: path.clone(),
+            node: new.clone(),
+        });
+        return;
+    }
+
+    if old != new {
+        patches.push(UiPatch::UpdateProps {
+            path: path.clone(),
+            node: new.clone(),
+        });
+    }
+
+    if new.has_child_list() {
+        if let (Some(old_children), Some(new_children)) =
+            (old_slots.first_mut(), new_slots.first_mut())
+        {
+            diff_list(old_children, new_children, path, patches);
+        }
+    } else {
+        // Fixed slots have the same sizes on both sides (checked above).
+        let mut offset = 0;
+        for (old_children, new_children) in old_slots.iter_mut().zip(new_slots.iter_mut()) {
+            for (i, (old_child, new_child)) in old_children
+                .iter_mut()
+                .zip(new_children.iter_mut())
+                .enumerate()
+            {
+                path.push((offset + i) as u32);
+                diff_node(old_child, new_child, path, patches);
+                path.pop();
+            }
+            offset += new_children.len();
+        }
+    }
+
+    restore_slots(old, old_slots);
+    restore_slots(new, new_slots);
+}
+
+// Diff the children of a list container. Children are matched by key, and
+// unkeyed children by position, so removing or moving a keyed child produces
+// a Remove/Insert for that child only. `old` is edited along with the emitted
+// patches, so indices always refer to the renderer's list at that point.
+fn diff_list(
+    old: &mut Vec<UiNode>,
+    new: &mut [UiNode],
+    path: &mut Vec<u32>,
+    patches: &mut Vec<UiPatch>,
+) {
+    let new_keys: HashSet<String> = new
+        .iter()
+        .filter_map(|child| child.key().map(str::to_string))
+        .collect();
+
+    // 1. Drop keyed children that are gone. Remove from the end so earlier indices stay valid.
+    for i in (0..old.len()).rev() {
+        if old[i].key().is_some_and(|key| !new_keys.contains(key)) {
+            old.remove(i);
+            patches.push(UiPatch::Remove {
+                path: path.clone(),
+                index: i as u32,
+            });
+        }
+    }
+
+    // 2. Walk the new list, diffing matches and inserting (or moving) the rest.
+    for i in 0..new.len() {
+        let key = new[i].key().map(str::to_string);
+        if i < old.len() && old[i].key() == key.as_deref() {
+            path.push(i as u32);
+            diff_node(&mut old[i], &mut new[i], path, patches);
+            path.pop();
+            continue;
+        }
+
+        let moved_from = key.as_deref().and_then(|key| {
+            old.iter()
+                .skip(i + 1)
+                .position(|child| child.key() == Some(key))
+                .map(|offset| i + 1 + offset)
+        });
+        if let Some(j) = moved_from {
+            old.remove(j);
+            patches.push(UiPatch::Remove {
+                path: path.clone(),
+                index: j as u32,
+            });
+        }
+        old.insert(i, new[i].clone());
+        patches.push(UiPatch::Insert {
+            path: path.clone(),
+            index: i as u32,
+            node: new[i].clone(),
+        });
+    }
+
+    // 3. Drop whatever is left past the end of the new list.
+    for i in (new.len()..old.len()).rev() {
+        old.remove(i);
+        patches.push(UiPatch::Remove {
+            path: path.clone(),
+            index: i as u32,
+        });
+    }
+}
+
+/// Rebuild the current screen and return only what changed since the last render.
+/// The first call (or the first after `padauk_reset_render_patch`) replaces the root.
+#[uniffi::export]
+pub fn padauk_render_patch() -> Vec<UiPatch> {
+    reconcile(crate::build_root_or_error())
+}
+
+/// Drop the retained tree, e.g. when the renderer's own copy has been discarded.
+#[uniffi::export]
+pub fn padauk_reset_render_patch() {
+    reset();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::button::{ButtonStyle, ButtonStyleOptions};
+    use crate::ui::layout::{CrossAxisAlignment, MainAxisAlignment};
+    use crate::ui::modifier::Modifiers;
+
+    fn text(text: &str) -> UiNode {
+        UiNode::Text {
+            text: text.to_string(),
+            sp_size: 14.0,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    fn keyed(key: &str) -> UiNode {
+        UiNode::Text {
+            text: key.to_string(),
+            sp_size: 14.0,
+            modifiers: Modifiers::default(),
+            key: Some(key.to_string()),
+        }
+    }
+
+    fn column(children: Vec<UiNode>) -> UiNode {
+        UiNode::Column {
+            children,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Start,
+            spacing: 0.0,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    fn button(label: &str) -> UiNode {
+        UiNode::Button {
+            action_id: "button".to_string(),
+            content: vec![text(label)],
+            style: ButtonStyle::Filled,
+            options: ButtonStyleOptions::default(),
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    // Diffs `old` against `new` and checks the patches rebuild `new` from `old`
+    fn round_trip(old: &UiNode, new: &UiNode) -> Vec<UiPatch> {
+        let patches = diff(old, new);
+        let mut patched = old.clone();
+        apply(&mut patched, &patches);
+        assert_eq!(&patched, new, "patches: {:#?}", patches);
+        patches
+    }
+
+    #[test]
+    fn unchanged_tree_has_no_patches() {
+        let tree = column(vec![text("a"), button("b"), keyed("c")]);
+        assert!(round_trip(&tree, &tree).is_empty());
+    }
+
+    #[test]
+    fn inserts_children() {
+        let old = column(vec![keyed("a"), keyed("c")]);
+        let new = column(vec![keyed("a"), keyed("b"), keyed("c"), keyed("d")]);
+        let patches = round_trip(&old, &new);
+        assert!(
+            patches
+                .iter()
+                .all(|patch| matches!(patch, UiPatch::Insert { .. }))
+        );
+        assert_eq!(patches.len(), 2);
+
+        round_trip(
+            &column(vec![text("a")]),
+            &column(vec![text("a"), text("b")]),
+        );
+        round_trip(&column(vec![]), &column(vec![text("a"), keyed("b")]));
+    }
+
+    #[test]
+    fn removes_children() {
+        let old = column(vec![keyed("a"), keyed("b"), keyed("c")]);
+        let new = column(vec![keyed("a"), keyed("c")]);
+        let patches = round_trip(&old, &new);
+        assert_eq!(
+            patches,
+            vec![UiPatch::Remove {
+                path: vec![],
+                index: 1
+            }]
+        );
+
+        round_trip(
+            &column(vec![text("a"), text("b")]),
+            &column(vec![text("a")]),
+        );
+        round_trip(&old, &column(vec![]));
+    }
+
+    #[test]
+    fn moves_keyed_children() {
+        let old = column(vec![keyed("a"), keyed("b"), keyed("c"), keyed("d")]);
+        round_trip(
+            &old,
+            &column(vec![keyed("d"), keyed("c"), keyed("b"), keyed("a")]),
+        );
+        round_trip(
+            &old,
+            &column(vec![keyed("b"), keyed("c"), keyed("d"), keyed("a")]),
+        );
+
+        // Moving one child only touches that child
+        let patches = round_trip(
+            &old,
+            &column(vec![keyed("a"), keyed("c"), keyed("b"), keyed("d")]),
+        );
+        assert_eq!(patches.len(), 2);
+    }
+
+    #[test]
+    fn mixes_keyed_and_unkeyed_children() {
+        round_trip(
+            &column(vec![text("x"), keyed("a"), text("y"), keyed("b")]),
+            &column(vec![keyed("b"), text("x"), keyed("new"), text("z")]),
+        );
+    }
+
+    #[test]
+    fn updates_props_in_place() {
+        let old = column(vec![text("a"), button("Save")]);
+        let new = column(vec![text("b"), button("Saved")]);
+        let patches = round_trip(&old, &new);
+        assert_eq!(
+            patches,
+            vec![
+                UiPatch::UpdateProps {
+                    path: vec![0],
+                    node: text("b"),
+                },
+                UiPatch::UpdateProps {
+                    path: vec![1, 0],
+                    node: text("Saved"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn container_props_keep_their_children() {
+        let old = column(vec![text("a"), text("b")]);
+        let UiNode::Column { children, .. } = &old else {
+            unreachable!()
+        };
+        let new = UiNode::Column {
+            children: children.clone(),
+            main_axis_alignment: MainAxisAlignment::Center,
+            cross_axis_alignment: CrossAxisAlignment::Start,
+            spacing: 8.0,
+            modifiers: Modifiers::default(),
+            key: None,
+        };
+        let patches = round_trip(&old, &new);
+        assert!(matches!(
+            patches.as_slice(),
+            [UiPatch::UpdateProps { path, node }] if path.is_empty() && node.children().is_empty()
+        ));
+    }
+
+    #[test]
+    fn replaces_nodes_of_another_kind() {
+        let old = column(vec![text("a"), text("b")]);
+        let new = column(vec![text("a"), button("b")]);
+        let patches = round_trip(&old, &new);
+        assert!(matches!(
+            patches.as_slice(),
+            [UiPatch::Replace { path, .. }] if path == &vec![1]
+        ));
+    }
+
+    #[test]
+    fn edits_nested_lists() {
+        let old = column(vec![
+            column(vec![keyed("a"), keyed("b")]),
+            column(vec![text("x")]),
+        ]);
+        let new = column(vec![
+            column(vec![keyed("b"), keyed("a"), keyed("c")]),
+            column(vec![text("y"), text("z")]),
+        ]);
+        round_trip(&old, &new);
+    }
+
+    #[test]
+    fn round_trips_generated_lists() {
+        // Small deterministic generator, so failures reproduce
+        let mut seed = 0x2545_f491_u32;
+        let mut next = move |bound: u32| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            seed % bound
+        };
+        let list = |next: &mut dyn FnMut(u32) -> u32| {
+            let mut keys: Vec<u32> = (0..8).filter(|_| next(3) != 0).collect();
+            for i in (1..keys.len()).rev() {
+                keys.swap(i, next(i as u32 + 1) as usize);
+            }
+            let children = keys
+                .into_iter()
+                .map(|key| match next(4) {
+                    0 => text(&key.to_string()),
+                    1 => keyed(&key.to_string()),
+                    _ => UiNode::Text {
+                        text: format!("{}-{}", key, next(2)),
+                        sp_size: 14.0,
+                        modifiers: Modifiers::default(),
+                        key: Some(key.to_string()),
+                    },
+                })
+                .collect();
+            column(children)
+        };
+        for _ in 0..500 {
+            let old = list(&mut next);
+            let new = list(&mut next);
+            round_trip(&old, &new);
+        }
+    }
+}
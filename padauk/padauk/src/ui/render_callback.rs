@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::sync::OnceLock;
 use log::{debug, info, warn};
 
@@ -18,8 +19,43 @@ pub fn register_render_callback(callback: Box<dyn RenderCallback>) {
     }
 }
 
+thread_local! {
+    // Nesting depth of `batch` calls on this thread
+    static BATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+    // A redraw was requested inside the current batch
+    static REDRAW_PENDING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Ends a batch even if its closure panics.
+struct BatchGuard;
+
+impl Drop for BatchGuard {
+    fn drop(&mut self) {
+        let depth = BATCH_DEPTH.with(|d| {
+            d.set(d.get() - 1);
+            d.get()
+        });
+        if depth == 0 && REDRAW_PENDING.with(|p| p.replace(false)) {
+            request_redraw();
+        }
+    }
+}
+
+/// Run `f`, holding back redraws until it returns; then request at most one.
+/// Batches nest: only the outermost one redraws. Action handlers already run
+/// in a batch.
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    BATCH_DEPTH.with(|d| d.set(d.get() + 1));
+    let _guard = BatchGuard;
+    f()
+}
+
 /// Call this internally whenever state changes to force a native UI update
 pub fn request_redraw() {
+    if BATCH_DEPTH.with(Cell::get) > 0 {
+        REDRAW_PENDING.with(|p| p.set(true));
+        return;
+    }
     if let Some(callback) = RENDER_CALLBACK.get() {
         debug!("Request redraw.");
         callback.on_update();
@@ -27,3 +63,93 @@ pub fn request_redraw() {
         warn!("Request redraw ignored: no render callback registered.");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::children;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_text};
+    use std::panic::{self, AssertUnwindSafe};
+
+    struct Pair {
+        a: State<i32>,
+        b: State<i32>,
+    }
+
+    impl Widget for Pair {
+        fn build(&self) -> UiNode {
+            let (a, b) = (self.a.clone(), self.b.clone());
+            column(children![
+                text(&format!("{} {}", self.a.get(), self.b.get())),
+                button("Both", move || {
+                    a.update(|a| *a += 1);
+                    b.update(|b| *b += 1);
+                }),
+            ])
+            .build()
+        }
+    }
+
+    fn mount() -> (WidgetTester, State<i32>, State<i32>) {
+        let (a, b) = (state(0), state(0));
+        let tester = WidgetTester::mount(Pair {
+            a: a.clone(),
+            b: b.clone(),
+        });
+        (tester, a, b)
+    }
+
+    #[test]
+    fn sets_in_a_batch_redraw_once() {
+        let (mut tester, a, b) = mount();
+        batch(|| {
+            for i in 1..=5 {
+                a.set(i);
+                b.set(i);
+            }
+        });
+
+        assert_eq!(tester.redraw_count(), 1);
+        tester.pump();
+        assert!(tester.exists(&by_text("5 5")));
+    }
+
+    #[test]
+    fn nested_batches_redraw_when_the_outermost_ends() {
+        let (tester, a, b) = mount();
+        batch(|| {
+            batch(|| a.set(1));
+            assert_eq!(tester.redraw_count(), 0);
+            b.set(1);
+        });
+
+        assert_eq!(tester.redraw_count(), 1);
+    }
+
+    #[test]
+    fn a_batch_that_panics_still_redraws() {
+        let (tester, a, _) = mount();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            batch(|| {
+                a.set(1);
+                panic!("batch failed on purpose");
+            })
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(tester.redraw_count(), 1);
+        // Out of the batch again: sets redraw right away
+        a.set(2);
+        assert_eq!(tester.redraw_count(), 2);
+    }
+
+    #[test]
+    fn an_action_redraws_once() {
+        let (mut tester, _, _) = mount();
+        tester.tap(&by_text("Both"));
+
+        assert_eq!(tester.redraw_count(), 1);
+        assert!(tester.exists(&by_text("1 1")));
+    }
+}
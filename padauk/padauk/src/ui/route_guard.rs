@@ -0,0 +1,110 @@
+use crate::ui::navigation::Route;
+use log::{debug, warn};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// What a guard decided about a navigation.
+pub enum GuardDecision {
+    Allow,
+    /// Go to this route instead, e.g. a login page holding the original route
+    /// to return to. Its own guards run too.
+    Redirect(Route),
+    /// Stay where we are.
+    Cancel,
+}
+
+pub type Guard = Arc<dyn Fn(&Route) -> GuardDecision + Send + Sync>;
+
+/// Identifies a guard for `Navigator::remove_guard`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GuardId(u64);
+
+// Redirects followed before giving up on a navigation, in case guards loop
+const MAX_REDIRECTS: usize = 8;
+
+static GUARDS: OnceLock<Mutex<Vec<(GuardId, Guard)>>> = OnceLock::new();
+static NEXT_GUARD_ID: AtomicU64 = AtomicU64::new(1);
+
+fn get_guards() -> &'static Mutex<Vec<(GuardId, Guard)>> {
+    GUARDS.get_or_init(|| Mutex::new(Vec::new()))
+}
+
+pub(crate) fn add_guard(guard: Guard) -> GuardId {
+    let id = GuardId(NEXT_GUARD_ID.fetch_add(1, Ordering::Relaxed));
+    get_guards().lock().unwrap().push((id, guard));
+    id
+}
+
+pub(crate) fn remove_guard(id: GuardId) {
+    get_guards()
+        .lock()
+        .unwrap()
+        .retain(|(guard_id, _)| *guard_id != id);
+}
+
+#[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+pub(crate) fn clear_guards() {
+    get_guards().lock().unwrap().clear();
+}
+
+// The first decision other than `Allow`: global guards first, then the route's own.
+fn decide(route: &Route) -> GuardDecision {
+    let global: Vec<Guard> = get_guards()
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(_, guard)| guard.clone())
+        .collect();
+    global
+        .iter()
+        .chain(route.guards.iter())
+        .map(|guard| guard(route))
+        .find(|decision| !matches!(decision, GuardDecision::Allow))
+        .unwrap_or(GuardDecision::Allow)
+}
+
+/// Run the guards on a route about to be shown: the route to show instead
+/// (itself, or where it was redirected), or `None` if it was cancelled.
+pub(crate) fn check(route: Route) -> Option<Route> {
+    follow(route).map(|(route, _)| route)
+}
+
+/// Like `check`, also telling whether guards redirected to the route.
+pub(crate) fn follow(route: Route) -> Option<(Route, bool)> {
+    let mut route = route;
+    for redirects in 0..=MAX_REDIRECTS {
+        match decide(&route) {
+            GuardDecision::Allow => return Some((route, redirects > 0)),
+            GuardDecision::Redirect(to) => {
+                debug!("Guard redirected {} to {}", route.name, to.name);
+                route = to;
+            }
+            GuardDecision::Cancel => {
+                debug!("Guard cancelled navigation to {}", route.name);
+                return None;
+            }
+        }
+    }
+    warn!(
+        "Navigation cancelled: more than {} guard redirects, last to {}",
+        MAX_REDIRECTS, route.name
+    );
+    None
+}
+
+/// Run the guards on a stack about to be shown, bottom first. The first
+/// redirect ends the stack with its target; the first cancel ends it there.
+pub(crate) fn check_stack(routes: Vec<Route>) -> Vec<Route> {
+    let mut checked = Vec::with_capacity(routes.len());
+    for route in routes {
+        match follow(route) {
+            Some((route, false)) => checked.push(route),
+            Some((route, true)) => {
+                checked.push(route);
+                break;
+            }
+            None => break,
+        }
+    }
+    checked
+}
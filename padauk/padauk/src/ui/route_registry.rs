@@ -0,0 +1,163 @@
+use crate::ui::navigation::{Presentation, Route, RouteParams, Router};
+use log::warn;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A route as saved by `padauk_nav_save_state`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub(crate) struct SavedRoute {
+    pub name: String,
+    /// The route's arguments, encoded by its registration
+    #[serde(default)]
+    pub args: Option<Value>,
+    /// Built by the `Router`; `args` holds its `RouteParams`.
+    #[serde(default)]
+    pub router: bool,
+    #[serde(default)]
+    pub presentation: Presentation,
+    #[serde(default = "dismissible_default")]
+    pub dismissible: bool,
+}
+
+fn dismissible_default() -> bool {
+    true
+}
+
+// Encoded arguments of a route (`None` if it has none), or `None` if they
+// aren't the registered type
+type SaveArgs = Box<dyn Fn(&Route) -> Option<Option<Value>> + Send + Sync>;
+
+struct Registration {
+    save: SaveArgs,
+    restore: Box<dyn Fn(Option<Value>) -> Option<Route> + Send + Sync>,
+}
+
+static REGISTRY: OnceLock<Mutex<HashMap<String, Arc<Registration>>>> = OnceLock::new();
+
+fn get_registry() -> &'static Mutex<HashMap<String, Arc<Registration>>> {
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn registration(name: &str) -> Option<Arc<Registration>> {
+    get_registry().lock().unwrap().get(name).cloned()
+}
+
+/// Rebuilds routes from their names, so the navigator stack can be restored
+/// after the process is killed. Routes built by the installed `Router` are
+/// restored without registering them.
+///
+/// ```ignore
+/// RouteRegistry::register("settings", || Route::new("settings", || Settings));
+/// RouteRegistry::register_with_args("user", |id: &u64| user_route(*id));
+/// ```
+pub struct RouteRegistry;
+
+impl RouteRegistry {
+    /// Register how to build the route called `name`.
+    pub fn register(name: impl Into<String>, build: impl Fn() -> Route + Send + Sync + 'static) {
+        Self::insert(
+            name.into(),
+            Registration {
+                save: Box::new(|_| Some(None)),
+                restore: Box::new(move |_| Some(build())),
+            },
+        );
+    }
+
+    /// Register how to build the route called `name` from its arguments, as
+    /// given to `Route::with_args`. They're saved with serde.
+    pub fn register_with_args<A, F>(name: impl Into<String>, build: F)
+    where
+        A: Serialize + DeserializeOwned + Send + Sync + 'static,
+        F: Fn(&A) -> Route + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let restore_name = name.clone();
+        Self::insert(
+            name,
+            Registration {
+                save: Box::new(|route| {
+                    let args = route.args::<A>()?;
+                    serde_json::to_value(args).ok().map(Some)
+                }),
+                restore: Box::new(move |args| {
+                    let args = args.and_then(|args| serde_json::from_value::<A>(args).ok());
+                    if args.is_none() {
+                        warn!("Saved arguments of route {} don't decode.", restore_name);
+                    }
+                    Some(build(&args?))
+                }),
+            },
+        );
+    }
+
+    fn insert(name: String, registration: Registration) {
+        get_registry()
+            .lock()
+            .unwrap()
+            .insert(name, Arc::new(registration));
+    }
+
+    /// Whether a route called `name` can be restored.
+    pub fn is_registered(name: &str) -> bool {
+        get_registry().lock().unwrap().contains_key(name)
+    }
+}
+
+/// Encode a route, or `None` if it can't be restored.
+pub(crate) fn save(route: &Route) -> Option<SavedRoute> {
+    if let Some(registration) = registration(&route.name) {
+        let args = (registration.save)(route);
+        if args.is_none() {
+            warn!(
+                "Arguments of route {} aren't the registered type.",
+                route.name
+            );
+        }
+        return Some(SavedRoute {
+            name: route.name.clone(),
+            args: args?,
+            router: false,
+            presentation: route.presentation,
+            dismissible: route.dismissible,
+        });
+    }
+    let params = route.args::<RouteParams>()?;
+    Some(SavedRoute {
+        name: route.name.clone(),
+        args: serde_json::to_value(params).ok(),
+        router: true,
+        presentation: route.presentation,
+        dismissible: route.dismissible,
+    })
+}
+
+/// Rebuild a saved route, or `None` if it's no longer known.
+pub(crate) fn restore(saved: SavedRoute) -> Option<Route> {
+    let route = if saved.router {
+        let params = serde_json::from_value::<RouteParams>(saved.args?).ok()?;
+        Router::restore(&params)
+    } else {
+        let Some(registration) = registration(&saved.name) else {
+            warn!("Can't restore route {}: it isn't registered.", saved.name);
+            return None;
+        };
+        let route = (registration.restore)(saved.args)?;
+        if route.name != saved.name {
+            warn!(
+                "Route registered as {} is called {}.",
+                saved.name, route.name
+            );
+        }
+        route
+    };
+    // Shown the way it was, e.g. as a dialog
+    Some(
+        route
+            .presented(saved.presentation)
+            .dismissible(saved.dismissible),
+    )
+}
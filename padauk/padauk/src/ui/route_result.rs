@@ -0,0 +1,129 @@
+use log::warn;
+use std::any::Any;
+use std::cell::RefCell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+// The value a route was popped with, before it's checked against the type
+// its pusher expects. `None` when the route was removed any other way.
+pub(crate) type AnyResult = Option<Box<dyn Any + Send>>;
+
+type Completer = Box<dyn FnOnce(AnyResult) + Send>;
+
+/// The receiving end of a route pushed with `Navigator::push_for_result`,
+/// held by the route. Clones share it; only the first result is delivered.
+#[derive(Clone)]
+pub(crate) struct ResultSender {
+    complete: Arc<Mutex<Option<Completer>>>,
+}
+
+impl ResultSender {
+    pub(crate) fn complete(&self, result: AnyResult) {
+        let complete = self.complete.lock().unwrap().take();
+        if let Some(complete) = complete {
+            complete(result);
+        }
+    }
+}
+
+struct ResultSlot<T> {
+    result: Option<Option<T>>,
+    waker: Option<Waker>,
+    callback: Option<Box<dyn FnOnce(Option<T>) + Send>>,
+}
+
+/// The result of a route pushed with `Navigator::push_for_result`: `Some(value)`
+/// if it was popped with `Navigator::pop_with(value)`, `None` if it was removed
+/// any other way (e.g. the system back button).
+///
+/// Await it (e.g. in `spawn`), or pass a callback to `on_result`.
+pub struct RouteResult<T> {
+    slot: Arc<Mutex<ResultSlot<T>>>,
+}
+
+impl<T: Send + 'static> RouteResult<T> {
+    pub(crate) fn channel() -> (Self, ResultSender) {
+        let slot = Arc::new(Mutex::new(ResultSlot {
+            result: None,
+            waker: None,
+            callback: None,
+        }));
+        let writer = slot.clone();
+        let complete: Completer = Box::new(move |result: AnyResult| {
+            let result = result.and_then(|value| match value.downcast::<T>() {
+                Ok(value) => Some(*value),
+                Err(_) => {
+                    warn!(
+                        "Route popped with a value that isn't a {}.",
+                        std::any::type_name::<T>()
+                    );
+                    None
+                }
+            });
+            let mut slot = writer.lock().unwrap();
+            match slot.callback.take() {
+                Some(callback) => {
+                    drop(slot);
+                    callback(result);
+                }
+                None => {
+                    slot.result = Some(result);
+                    if let Some(waker) = slot.waker.take() {
+                        waker.wake();
+                    }
+                }
+            }
+        });
+        let sender = ResultSender {
+            complete: Arc::new(Mutex::new(Some(complete))),
+        };
+        (Self { slot }, sender)
+    }
+
+    /// Call `f` with the result once the route is gone (right away if it already is).
+    pub fn on_result(self, f: impl FnOnce(Option<T>) + Send + 'static) {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => {
+                drop(slot);
+                f(result);
+            }
+            None => slot.callback = Some(Box::new(f)),
+        }
+    }
+}
+
+impl<T> Future for RouteResult<T> {
+    type Output = Option<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut slot = self.slot.lock().unwrap();
+        match slot.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+thread_local! {
+    // Results of routes removed while the navigator was locked
+    static PENDING: RefCell<Vec<(ResultSender, AnyResult)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Queue a result; it's delivered by `deliver_pending`, once the navigator is unlocked,
+/// so callbacks can navigate.
+pub(crate) fn queue(sender: ResultSender, result: AnyResult) {
+    PENDING.with(|pending| pending.borrow_mut().push((sender, result)));
+}
+
+pub(crate) fn deliver_pending() {
+    let pending = PENDING.with(|pending| std::mem::take(&mut *pending.borrow_mut()));
+    for (sender, result) in pending {
+        sender.complete(result);
+    }
+}
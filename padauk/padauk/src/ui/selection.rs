@@ -0,0 +1,580 @@
+use crate::UiNode;
+use crate::ui::build_context;
+use crate::ui::color::ColorValue;
+use crate::ui::event_registry::{
+    ActionHandler, register_scoped_action, register_scoped_event_action,
+};
+use crate::ui::layout::{CrossAxisAlignment, MainAxisAlignment};
+use crate::ui::state::State;
+use crate::{Widget, impl_key, impl_modifiers, ui::modifier::Modifiers};
+use log::warn;
+use std::sync::Arc;
+
+/// An on/off switch bound to a `State<bool>`, which it sets to the value the
+/// user toggled it to.
+pub struct Switch {
+    pub checked: State<bool>,
+    pub enabled: bool,
+    pub color_checked_thumb: Option<ColorValue>,
+    pub color_checked_track: Option<ColorValue>,
+    pub color_unchecked_thumb: Option<ColorValue>,
+    pub color_unchecked_track: Option<ColorValue>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(Switch);
+impl_key!(Switch);
+
+impl Widget for Switch {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS switches
+            UiNode::Label {
+                title: "Switch".to_string(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let checked = self.checked.clone();
+            UiNode::Switch {
+                checked: self.checked.get(),
+                action_id: register_scoped_event_action(
+                    "toggle",
+                    Arc::new(move |value: bool| checked.set(value)),
+                ),
+                enabled: self.enabled,
+                color_checked_thumb: self.color_checked_thumb.clone(),
+                color_checked_track: self.color_checked_track.clone(),
+                color_unchecked_thumb: self.color_unchecked_thumb.clone(),
+                color_unchecked_track: self.color_unchecked_track.clone(),
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl Switch {
+    pub fn new(checked: &State<bool>) -> Self {
+        Self {
+            checked: checked.clone(),
+            enabled: true,
+            color_checked_thumb: None,
+            color_checked_track: None,
+            color_unchecked_thumb: None,
+            color_unchecked_track: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn colors(
+        mut self,
+        checked_thumb: Option<ColorValue>,
+        checked_track: Option<ColorValue>,
+        unchecked_thumb: Option<ColorValue>,
+        unchecked_track: Option<ColorValue>,
+    ) -> Self {
+        self.color_checked_thumb = checked_thumb;
+        self.color_checked_track = checked_track;
+        self.color_unchecked_thumb = unchecked_thumb;
+        self.color_unchecked_track = unchecked_track;
+        self
+    }
+}
+
+pub fn switch(checked: &State<bool>) -> Switch {
+    Switch::new(checked)
+}
+
+/// A single radio button, with an optional label that is part of its tap
+/// target. Most of the time `radio_group` is what you want.
+pub struct RadioButton {
+    pub selected: bool,
+    pub label: Option<String>,
+    pub on_click: ActionHandler,
+    pub enabled: bool,
+    pub color_selected: Option<ColorValue>,
+    pub color_unselected: Option<ColorValue>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(RadioButton);
+impl_key!(RadioButton);
+
+impl Widget for RadioButton {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS radio buttons
+            UiNode::Label {
+                title: self.label.clone().unwrap_or_default(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::RadioButton {
+                selected: self.selected,
+                label: self.label.clone(),
+                action_id: register_scoped_action("select", self.on_click.clone()),
+                enabled: self.enabled,
+                color_selected: self.color_selected.clone(),
+                color_unselected: self.color_unselected.clone(),
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl RadioButton {
+    pub fn new(selected: bool, on_click: impl Fn() + Send + Sync + 'static) -> Self {
+        Self {
+            selected,
+            label: None,
+            on_click: Arc::new(on_click),
+            enabled: true,
+            color_selected: None,
+            color_unselected: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn colors(mut self, selected: Option<ColorValue>, unselected: Option<ColorValue>) -> Self {
+        self.color_selected = selected;
+        self.color_unselected = unselected;
+        self
+    }
+}
+
+pub fn radio_button(selected: bool, on_click: impl Fn() + Send + Sync + 'static) -> RadioButton {
+    RadioButton::new(selected, on_click)
+}
+
+// One choice of a RadioGroup, with the value type erased
+struct RadioOption {
+    label: String,
+    is_selected: Arc<dyn Fn() -> bool + Send + Sync>,
+    select: ActionHandler,
+}
+
+/// A column of labelled radio buttons bound to a `State<T>`: the button whose
+/// value equals the state is selected, and tapping a button sets the state to
+/// its value.
+pub struct RadioGroup {
+    options: Vec<RadioOption>,
+    pub enabled: bool,
+    pub spacing: f32,
+    pub color_selected: Option<ColorValue>,
+    pub color_unselected: Option<ColorValue>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(RadioGroup);
+impl_key!(RadioGroup);
+
+impl Widget for RadioGroup {
+    fn build(&self) -> UiNode {
+        let buttons = self
+            .options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| {
+                build_context::child(i, None, || {
+                    let select = option.select.clone();
+                    RadioButton::new((option.is_selected)(), move || select())
+                        .label(option.label.clone())
+                        .enabled(self.enabled)
+                        .colors(self.color_selected.clone(), self.color_unselected.clone())
+                        .build()
+                })
+            })
+            .collect();
+
+        #[cfg(target_os = "ios")]
+        {
+            UiNode::VStack {
+                views: buttons,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Column {
+                children: buttons,
+                main_axis_alignment: MainAxisAlignment::Start,
+                cross_axis_alignment: CrossAxisAlignment::Start,
+                spacing: self.spacing,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl RadioGroup {
+    pub fn new<T: Clone + PartialEq + Send + Sync + 'static>(
+        selected: &State<T>,
+        options: Vec<(T, impl Into<String>)>,
+    ) -> Self {
+        let options = options
+            .into_iter()
+            .map(|(value, label)| {
+                let (state, this) = (selected.clone(), value.clone());
+                let select: ActionHandler = Arc::new(move || state.set(this.clone()));
+                let state = selected.clone();
+                RadioOption {
+                    label: label.into(),
+                    is_selected: Arc::new(move || state.get() == value),
+                    select,
+                }
+            })
+            .collect();
+        Self {
+            options,
+            enabled: true,
+            spacing: 0.0,
+            color_selected: None,
+            color_unselected: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Gap between the buttons, in dp.
+    pub fn spacing(mut self, value: f32) -> Self {
+        self.spacing = value;
+        self
+    }
+
+    pub fn colors(mut self, selected: Option<ColorValue>, unselected: Option<ColorValue>) -> Self {
+        self.color_selected = selected;
+        self.color_unselected = unselected;
+        self
+    }
+}
+
+pub fn radio_group<T: Clone + PartialEq + Send + Sync + 'static>(
+    selected: &State<T>,
+    options: Vec<(T, impl Into<String>)>,
+) -> RadioGroup {
+    RadioGroup::new(selected, options)
+}
+
+// The state a slider writes to: one value, or both ends of a range
+#[derive(Clone)]
+pub enum SliderValue {
+    Single(State<f32>),
+    Range(State<(f32, f32)>),
+}
+
+/// A slider bound to a `State<f32>` (or, for a range slider, a
+/// `State<(f32, f32)>`), which it sets as the thumb is dragged.
+pub struct Slider {
+    pub value: SliderValue,
+    pub min: f32,
+    pub max: f32,
+    // Distance between the values the thumb stops at; None = continuous
+    pub step: Option<f32>,
+    pub enabled: bool,
+    pub color_thumb: Option<ColorValue>,
+    pub color_active_track: Option<ColorValue>,
+    pub color_inactive_track: Option<ColorValue>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(Slider);
+impl_key!(Slider);
+
+impl Widget for Slider {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS sliders
+            UiNode::Label {
+                title: "Slider".to_string(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let snap = self.snapper();
+            let (value, end_value, action_id) = match &self.value {
+                SliderValue::Single(state) => {
+                    let (value, state) = (state.get(), state.clone());
+                    let action_id = register_scoped_event_action(
+                        "change",
+                        Arc::new(move |value: f32| state.set(snap(value))),
+                    );
+                    (value, None, action_id)
+                }
+                SliderValue::Range(state) => {
+                    let ((start, end), state) = (state.get(), state.clone());
+                    let action_id = register_scoped_event_action(
+                        "change",
+                        Arc::new(move |(start, end): (f32, f32)| {
+                            let (start, end) = (snap(start), snap(end));
+                            state.set((start.min(end), start.max(end)))
+                        }),
+                    );
+                    (start, Some(end), action_id)
+                }
+            };
+
+            UiNode::Slider {
+                value,
+                end_value,
+                min: self.min,
+                max: self.max,
+                steps: self.steps(),
+                action_id,
+                enabled: self.enabled,
+                color_thumb: self.color_thumb.clone(),
+                color_active_track: self.color_active_track.clone(),
+                color_inactive_track: self.color_inactive_track.clone(),
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl Slider {
+    /// Ends given the wrong way round are swapped. If either isn't a finite
+    /// number, the slider goes from 0 to 1.
+    pub fn new(value: SliderValue, min: f32, max: f32) -> Self {
+        let (min, max) = if !min.is_finite() || !max.is_finite() {
+            warn!("Slider range {}..{} isn't finite; using 0..1.", min, max);
+            (0.0, 1.0)
+        } else if min > max {
+            warn!(
+                "Slider range {}..{} is inverted; swapping its ends.",
+                min, max
+            );
+            (max, min)
+        } else {
+            (min, max)
+        };
+        Self {
+            value,
+            min,
+            max,
+            step: None,
+            enabled: true,
+            color_thumb: None,
+            color_active_track: None,
+            color_inactive_track: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// Make the thumb stop only at `min`, `min + size`, `min + 2 * size`, ...
+    /// If the range isn't a multiple of `size`, `max` comes down to the last
+    /// stop below it. A size that leaves fewer than three stops is ignored:
+    /// the renderer can't stop only at the ends.
+    pub fn step(mut self, size: f32) -> Self {
+        self.step = None;
+        if !(size > 0.0 && size.is_finite()) {
+            return self;
+        }
+        // Tolerates float error, e.g. 0..1 in steps of 0.1
+        let count = ((self.max - self.min) / size + 1e-3).floor();
+        if count < 2.0 {
+            warn!(
+                "Slider step {} leaves fewer than three stops in {}..{}; ignoring it.",
+                size, self.min, self.max
+            );
+            return self;
+        }
+        self.max = self.min + count * size;
+        self.step = Some(size);
+        self
+    }
+
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    pub fn colors(
+        mut self,
+        thumb: Option<ColorValue>,
+        active_track: Option<ColorValue>,
+        inactive_track: Option<ColorValue>,
+    ) -> Self {
+        self.color_thumb = thumb;
+        self.color_active_track = active_track;
+        self.color_inactive_track = inactive_track;
+        self
+    }
+
+    // Stops between the ends, as Compose counts them
+    fn steps(&self) -> u32 {
+        match self.step {
+            Some(step) => (((self.max - self.min) / step).round() as u32).saturating_sub(1),
+            None => 0,
+        }
+    }
+
+    // Clamps a value from the renderer to the slider, and to its steps if any
+    fn snapper(&self) -> impl Fn(f32) -> f32 + Send + Sync + 'static {
+        let (min, max, step) = (self.min, self.max, self.step);
+        move |value: f32| {
+            let value = match step {
+                Some(step) => min + ((value - min) / step).round() * step,
+                None => value,
+            };
+            value.clamp(min, max)
+        }
+    }
+}
+
+pub fn slider(value: &State<f32>, min: f32, max: f32) -> Slider {
+    Slider::new(SliderValue::Single(value.clone()), min, max)
+}
+
+/// A slider with two thumbs, bound to the `(start, end)` of a range.
+pub fn range_slider(value: &State<(f32, f32)>, min: f32, max: f32) -> Slider {
+    Slider::new(SliderValue::Range(value.clone()), min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_test_tag};
+    use crate::ui::event_registry::{EventPayload, padauk_dispatch_event};
+
+    #[test]
+    fn tapping_a_switch_toggles_its_state() {
+        let on = state(false);
+        let mut tester = WidgetTester::mount(switch(&on).test_tag("switch"));
+
+        tester.tap(&by_test_tag("switch"));
+        assert!(on.get_untracked());
+        tester.tap(&by_test_tag("switch"));
+        assert!(!on.get_untracked());
+    }
+
+    #[test]
+    fn a_switch_takes_the_value_toggled_to() {
+        let on = state(false);
+        let tester = WidgetTester::mount(switch(&on).test_tag("switch"));
+        let found = tester.find(&by_test_tag("switch"));
+        let action_id = found.node.toggle_action_id().unwrap();
+
+        // Delivered twice, or after the state changed in Rust: still on
+        for _ in 0..2 {
+            padauk_dispatch_event(
+                action_id.to_string(),
+                EventPayload::Toggle { checked: true },
+            );
+        }
+        assert!(on.get_untracked());
+    }
+
+    #[test]
+    fn inverted_ranges_are_swapped() {
+        let slider = slider(&state(0.0), 10.0, 0.0).step(2.5);
+
+        assert_eq!((slider.min, slider.max), (0.0, 10.0));
+        assert_eq!(slider.steps(), 3);
+        assert_eq!(slider.snapper()(11.0), 10.0);
+        assert_eq!(slider.snapper()(3.0), 2.5);
+    }
+
+    #[test]
+    fn ranges_that_arent_finite_fall_back_to_0_to_1() {
+        for (min, max) in [(f32::NAN, 1.0), (0.0, f32::NAN), (0.0, f32::INFINITY)] {
+            let slider = range_slider(&state((0.0, 1.0)), min, max);
+            assert_eq!((slider.min, slider.max), (0.0, 1.0));
+            assert_eq!(slider.snapper()(2.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn steps_that_arent_positive_and_finite_are_ignored() {
+        for size in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            let slider = slider(&state(0.0), 0.0, 1.0).step(size);
+            assert_eq!(slider.step, None);
+            assert_eq!(slider.steps(), 0);
+        }
+    }
+
+    #[test]
+    fn ranges_that_arent_a_multiple_of_the_step_end_at_the_last_stop() {
+        let slider = slider(&state(0.0), 0.0, 10.0).step(3.0);
+
+        // Stops at 0, 3, 6 and 9, as the renderer spaces them
+        assert_eq!((slider.min, slider.max), (0.0, 9.0));
+        assert_eq!(slider.steps(), 2);
+        assert_eq!(slider.snapper()(10.0), 9.0);
+        assert_eq!(slider.snapper()(4.0), 3.0);
+
+        let slider = range_slider(&state((0.0, 1.0)), 0.0, 1.0).step(0.1);
+        assert_eq!(slider.max, 1.0);
+        assert_eq!(slider.steps(), 9);
+    }
+
+    #[test]
+    fn steps_leaving_only_the_ends_are_ignored() {
+        for size in [6.0, 10.0, 20.0] {
+            let slider = slider(&state(0.0), 0.0, 10.0).step(size);
+            assert_eq!((slider.max, slider.step), (10.0, None));
+            assert_eq!(slider.steps(), 0);
+            assert_eq!(slider.snapper()(7.0), 7.0);
+        }
+    }
+}
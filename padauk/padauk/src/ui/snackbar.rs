@@ -0,0 +1,319 @@
+use crate::UiNode;
+use crate::ui::build_context;
+use crate::ui::event_registry::{ActionHandler, register_scoped_action};
+use crate::ui::modifier::Modifiers;
+use crate::ui::state::State;
+use log::debug;
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+// How long a message stays up, unless it's dismissed or its action pressed
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum SnackbarDuration {
+    // About 4 seconds
+    #[default]
+    Short,
+    // About 10 seconds
+    Long,
+    // Until it's dismissed, its action pressed, or it's hidden from Rust; with
+    // no Scaffold on screen, that's only from Rust
+    Indefinite,
+}
+
+/// A short message shown at the bottom of the screen, with an optional action
+/// button. Show it with `Messenger::show`.
+#[derive(Clone)]
+pub struct Snackbar {
+    pub message: String,
+    pub action_label: Option<String>,
+    pub on_action: Option<ActionHandler>,
+    pub on_dismiss: Option<ActionHandler>,
+    pub duration: SnackbarDuration,
+    // Shows a close button
+    pub dismiss_button: bool,
+}
+
+impl Snackbar {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            action_label: None,
+            on_action: None,
+            on_dismiss: None,
+            duration: SnackbarDuration::Short,
+            dismiss_button: false,
+        }
+    }
+
+    /// Add an action button; pressing it calls `on_action` and hides the message.
+    pub fn action(
+        mut self,
+        label: impl Into<String>,
+        on_action: impl Fn() + Send + Sync + 'static,
+    ) -> Self {
+        self.action_label = Some(label.into());
+        self.on_action = Some(Arc::new(on_action));
+        self
+    }
+
+    /// Called when the message goes away without its action being pressed:
+    /// it timed out, was swiped or closed, or was hidden with `Messenger`.
+    pub fn on_dismiss(mut self, on_dismiss: impl Fn() + Send + Sync + 'static) -> Self {
+        self.on_dismiss = Some(Arc::new(on_dismiss));
+        self
+    }
+
+    pub fn duration(mut self, duration: SnackbarDuration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn dismiss_button(mut self, value: bool) -> Self {
+        self.dismiss_button = value;
+        self
+    }
+}
+
+pub fn snackbar(message: impl Into<String>) -> Snackbar {
+    Snackbar::new(message)
+}
+
+/// Identifies a message passed to `Messenger::show`.
+pub type SnackbarId = u64;
+
+#[derive(Clone)]
+struct Queued {
+    id: SnackbarId,
+    snackbar: Snackbar,
+}
+
+// Messages waiting to be shown; the first one is on screen
+static QUEUE: OnceLock<State<Vec<Queued>>> = OnceLock::new();
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn queue() -> State<Vec<Queued>> {
+    QUEUE.get_or_init(|| State::new(Vec::new())).clone()
+}
+
+/// Shows snackbars, one at a time, in the outermost `Scaffold` on screen.
+/// Its functions can be called from anywhere: a build, an action, a task.
+///
+/// While no scaffold is on screen, messages wait for one. An `Indefinite`
+/// message then holds up the queue until it's hidden with `hide`.
+pub struct Messenger;
+
+impl Messenger {
+    /// Queue `snackbar`; it's shown once the messages before it are gone.
+    pub fn show(snackbar: Snackbar) -> SnackbarId {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        debug!("Messenger: queue message {}: {}", id, snackbar.message);
+        queue().update(|queue| queue.push(Queued { id, snackbar }));
+        id
+    }
+
+    /// Hide message `id`, calling its `on_dismiss` if it's on screen, or
+    /// drop it from the queue if it isn't yet.
+    pub fn hide(id: SnackbarId) {
+        let shown = queue().get_untracked().first().map(|queued| queued.id);
+        if shown == Some(id) {
+            close(id, false);
+        } else {
+            queue().update(|queue| queue.retain(|queued| queued.id != id));
+        }
+    }
+
+    /// Hide the message on screen, if any, calling its `on_dismiss`.
+    pub fn hide_current() {
+        if let Some(queued) = queue().get_untracked().first() {
+            close(queued.id, false);
+        }
+    }
+
+    /// Hide the message on screen and drop the queued ones.
+    pub fn clear() {
+        let queued = queue().get_untracked();
+        queue().update(|queue| queue.truncate(1));
+        if let Some(first) = queued.first() {
+            close(first.id, false);
+        }
+    }
+
+    /// Messages not gone yet, the one on screen first.
+    pub fn pending() -> Vec<SnackbarId> {
+        queue()
+            .get_untracked()
+            .iter()
+            .map(|queued| queued.id)
+            .collect()
+    }
+}
+
+// Take message `id` off the screen, then call back
+fn close(id: SnackbarId, action: bool) {
+    let queue = queue();
+    let Some(first) = queue.get_untracked().first().cloned() else {
+        return;
+    };
+    // The renderer may report a message twice, or after it was hidden
+    if first.id != id {
+        return;
+    }
+    debug!("Messenger: close message {} (action: {})", id, action);
+    queue.update(|queue| {
+        queue.remove(0);
+    });
+    let callback = if action {
+        first.snackbar.on_action
+    } else {
+        first.snackbar.on_dismiss
+    };
+    if let Some(callback) = callback {
+        callback();
+    }
+}
+
+#[cfg(all(not(target_os = "ios"), any(test, feature = "testing")))]
+pub(crate) fn clear_messages() {
+    queue().set_silently(Vec::new());
+}
+
+thread_local! {
+    // A scaffold of this render pass shows the message
+    static HOST_CLAIMED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub(crate) fn begin_render_pass() {
+    HOST_CLAIMED.with(|claimed| claimed.set(false));
+}
+
+/// Called by a scaffold as it starts building: `true` for the first one of
+/// the pass, the outermost, which shows the messages.
+pub(crate) fn claim_host() -> bool {
+    !HOST_CLAIMED.with(|claimed| claimed.replace(true))
+}
+
+/// The node of the message to show, if any, for the scaffold that claimed
+/// the host. Built in the current widget's `"snackbar"` slot.
+pub(crate) fn build_host() -> Vec<UiNode> {
+    let Some(queued) = queue().get().first().cloned() else {
+        return vec![];
+    };
+
+    #[cfg(target_os = "ios")]
+    {
+        // TODO: iOS snackbars
+        let _ = queued;
+        vec![]
+    }
+
+    #[cfg(not(target_os = "ios"))]
+    {
+        let id = queued.id;
+        let snackbar = queued.snackbar;
+        build_context::slot("snackbar", || {
+            // Named after the message, so a late report about one that's gone
+            // can't close the next
+            let action_id = snackbar.action_label.as_ref().map(|_| {
+                register_scoped_action(&format!("action{}", id), Arc::new(move || close(id, true)))
+            });
+            let dismiss_action_id = register_scoped_action(
+                &format!("dismiss{}", id),
+                Arc::new(move || close(id, false)),
+            );
+            vec![UiNode::Snackbar {
+                message_id: id,
+                message: snackbar.message,
+                action_label: snackbar.action_label,
+                action_id,
+                dismiss_action_id,
+                duration: snackbar.duration,
+                dismiss_button: snackbar.dismiss_button,
+                modifiers: Modifiers::default(),
+                key: None,
+            }]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_kind, by_test_tag, by_text};
+    use crate::ui::event_registry::padauk_dispatch_action;
+    use std::sync::atomic::AtomicU32;
+
+    #[test]
+    fn messages_are_shown_one_at_a_time_in_order() {
+        let mut tester = WidgetTester::mount(scaffold(text("Body")));
+        Messenger::show(snackbar("First"));
+        Messenger::show(snackbar("Second"));
+        tester.pump();
+
+        assert_eq!(tester.count(&by_kind("Snackbar")), 1);
+        assert!(tester.exists(&by_text("First")));
+        assert!(tester.dismiss_snackbar());
+        assert!(tester.exists(&by_text("Second")));
+        assert!(tester.dismiss_snackbar());
+        assert!(!tester.dismiss_snackbar());
+        assert!(Messenger::pending().is_empty());
+    }
+
+    #[test]
+    fn the_outermost_scaffold_shows_messages() {
+        let mut tester = WidgetTester::mount(
+            scaffold(scaffold(text("Body")).test_tag("inner")).test_tag("outer"),
+        );
+        Messenger::show(snackbar("Saved"));
+        tester.pump();
+
+        let snackbars = |tag: &str| match tester.find(&by_test_tag(tag)).node {
+            UiNode::Scaffold { snackbar, .. } => snackbar.len(),
+            other => panic!("Not a scaffold: {:?}", other),
+        };
+        assert_eq!((snackbars("outer"), snackbars("inner")), (1, 0));
+    }
+
+    #[test]
+    fn action_and_dismiss_callbacks_fire_once() {
+        static ACTIONS: AtomicU32 = AtomicU32::new(0);
+        static DISMISSALS: AtomicU32 = AtomicU32::new(0);
+        let mut tester = WidgetTester::mount(scaffold(text("Body")));
+        let message = |text: &str| {
+            snackbar(text)
+                .action("Undo", || {
+                    ACTIONS.fetch_add(1, Ordering::SeqCst);
+                })
+                .on_dismiss(|| {
+                    DISMISSALS.fetch_add(1, Ordering::SeqCst);
+                })
+        };
+        let first = Messenger::show(message("Deleted"));
+        Messenger::show(message("Archived"));
+        tester.pump();
+
+        // The renderer reports the action, then the message going away
+        let node = tester.find(&by_kind("Snackbar")).node;
+        let action_id = node.action_id().unwrap().to_string();
+        let dismiss_action_id = node.dismiss_action_id().unwrap().to_string();
+        tester.tap(&by_kind("Snackbar"));
+        padauk_dispatch_action(action_id);
+        padauk_dispatch_action(dismiss_action_id);
+        Messenger::hide(first);
+        assert_eq!(ACTIONS.load(Ordering::SeqCst), 1);
+        assert_eq!(DISMISSALS.load(Ordering::SeqCst), 0);
+
+        let dismiss_action_id = tester
+            .find(&by_text("Archived"))
+            .node
+            .dismiss_action_id()
+            .unwrap()
+            .to_string();
+        assert!(tester.dismiss_snackbar());
+        padauk_dispatch_action(dismiss_action_id);
+        Messenger::hide_current();
+        assert_eq!(ACTIONS.load(Ordering::SeqCst), 1);
+        assert_eq!(DISMISSALS.load(Ordering::SeqCst), 1);
+    }
+}
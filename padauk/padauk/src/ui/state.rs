@@ -1,28 +1,60 @@
+use crate::ui::reactive::{self, Version};
 use crate::ui::render_callback::request_redraw;
+use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 
 #[derive(Clone)]
 pub struct State<T: Clone + Send + Sync + 'static> {
     inner: Arc<Mutex<T>>,
+    // Shared by clones; see `ui::reactive`
+    id: u64,
+    version: Version,
 }
 
 impl<T: Clone + Send + Sync + 'static> State<T> {
     pub fn new(value: T) -> Self {
         Self {
             inner: Arc::new(Mutex::new(value)),
+            id: reactive::next_id(),
+            version: Version::default(),
         }
     }
 
+    /// Read the value. Reads are tracked: a widget that reads a state during
+    /// build redraws when it changes, as do computeds and effects that read it.
     pub fn get(&self) -> T {
+        reactive::track(self.id, &self.version);
         self.inner.lock().unwrap().clone()
     }
 
+    /// Read the value without tracking it.
+    pub fn get_untracked(&self) -> T {
+        self.inner.lock().unwrap().clone()
+    }
+
+    /// Set the value. A redraw is requested only if the screen on display read it.
     pub fn set(&self, value: T) {
-        *self.inner.lock().unwrap() = value;
-        request_redraw();
+        self.set_silently(value);
+        self.redraw_if_rendered();
     }
 
     pub fn update<F>(&self, f: F)
+    where
+        F: FnOnce(&mut T),
+    {
+        self.update_silently(f);
+        self.redraw_if_rendered();
+    }
+
+    /// Set the value without requesting a redraw; it shows on the next one.
+    /// Computeds and effects that read it still see the change.
+    pub fn set_silently(&self, value: T) {
+        *self.inner.lock().unwrap() = value;
+        self.changed();
+    }
+
+    /// Update the value without requesting a redraw; it shows on the next one.
+    pub fn update_silently<F>(&self, f: F)
     where
         F: FnOnce(&mut T),
     {
@@ -30,10 +62,109 @@ impl<T: Clone + Send + Sync + 'static> State<T> {
             let mut guard = self.inner.lock().unwrap();
             f(&mut *guard);
         }
-        request_redraw();
+        self.changed();
+    }
+
+    /// Paths of the widgets that read this state in the last render pass.
+    pub fn readers(&self) -> Vec<String> {
+        reactive::readers(self.id)
+    }
+
+    fn changed(&self) {
+        self.version.fetch_add(1, Ordering::SeqCst);
+        reactive::notify(self.id);
+    }
+
+    fn redraw_if_rendered(&self) {
+        if reactive::is_rendered(self.id) {
+            request_redraw();
+        }
     }
 }
 
 pub fn state<T: Clone + Send + Sync + 'static>(value: T) -> State<T> {
     State::new(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_text};
+
+    struct Shows {
+        show_b: State<bool>,
+        a: State<i32>,
+        b: State<i32>,
+    }
+
+    impl Widget for Shows {
+        fn build(&self) -> UiNode {
+            let shown = if self.show_b.get() {
+                format!("{} {}", self.a.get(), self.b.get())
+            } else {
+                self.a.get().to_string()
+            };
+            text(&shown).build()
+        }
+    }
+
+    fn mount() -> (WidgetTester, Shows) {
+        let (show_b, a, b) = (state(true), state(0), state(0));
+        let tester = WidgetTester::mount(Shows {
+            show_b: show_b.clone(),
+            a: a.clone(),
+            b: b.clone(),
+        });
+        (tester, Shows { show_b, a, b })
+    }
+
+    #[test]
+    fn setting_a_state_the_screen_read_redraws() {
+        let (mut tester, states) = mount();
+        states.a.set(1);
+
+        assert_eq!(tester.redraw_count(), 1);
+        tester.pump();
+        assert!(tester.exists(&by_text("1 0")));
+    }
+
+    #[test]
+    fn setting_a_state_the_screen_didnt_read_doesnt_redraw() {
+        let (tester, _) = mount();
+        let unread = state(0);
+        unread.set(1);
+        unread.update(|n| *n += 1);
+
+        assert_eq!(tester.redraw_count(), 0);
+        assert_eq!(unread.get_untracked(), 2);
+    }
+
+    #[test]
+    fn a_state_the_screen_stopped_reading_doesnt_redraw() {
+        let (mut tester, states) = mount();
+        states.show_b.set(false);
+        tester.pump();
+        states.b.set(1);
+
+        assert_eq!(tester.redraw_count(), 1);
+        assert!(states.b.readers().is_empty());
+    }
+
+    #[test]
+    fn set_silently_shows_on_the_next_redraw() {
+        let (mut tester, states) = mount();
+        states.a.set_silently(1);
+
+        assert_eq!(tester.redraw_count(), 0);
+        assert!(tester.exists(&by_text("0 0")));
+        tester.pump();
+        assert!(tester.exists(&by_text("1 0")));
+    }
+
+    #[test]
+    fn readers_are_the_widgets_that_read_the_state() {
+        let (_tester, states) = mount();
+        assert_eq!(states.a.readers(), vec!["/".to_string()]);
+    }
+}
@@ -0,0 +1,287 @@
+use crate::UiNode;
+use crate::ui::button::IconType;
+use crate::ui::event_registry::{
+    EventHandler, register_scoped_action, register_scoped_event_action,
+};
+use crate::ui::state::State;
+use crate::{Widget, impl_key, impl_modifiers, ui::modifier::Modifiers};
+use std::sync::Arc;
+
+#[derive(uniffi::Enum, Clone, Copy, Debug, PartialEq)]
+pub enum TextFieldStyle {
+    Filled,
+    Outlined,
+}
+
+// Which soft keyboard to show
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum KeyboardType {
+    #[default]
+    Text,
+    Number,
+    Decimal,
+    Phone,
+    Email,
+    Uri,
+    Password,
+}
+
+// The action key of the soft keyboard; pressing it submits the field
+#[derive(uniffi::Enum, Clone, Copy, Debug, Default, PartialEq)]
+pub enum ImeAction {
+    #[default]
+    Default,
+    Done,
+    // Also moves the focus to the next field
+    Next,
+    Search,
+    Send,
+    Go,
+}
+
+#[derive(uniffi::Record, Clone, Debug, PartialEq)]
+pub struct TextFieldOptions {
+    pub label: Option<String>,
+    // Shown while the field is empty
+    pub placeholder: Option<String>,
+    pub leading_icon: Option<IconType>,
+    pub trailing_icon: Option<IconType>,
+    // Shown under the field, which is drawn in the error colour
+    pub error_text: Option<String>,
+    // Masks the text, e.g. for passwords
+    pub secure: bool,
+    pub keyboard_type: KeyboardType,
+    pub ime_action: ImeAction,
+    pub single_line: bool,
+    pub enabled: bool,
+}
+
+impl Default for TextFieldOptions {
+    fn default() -> Self {
+        Self {
+            label: None,
+            placeholder: None,
+            leading_icon: None,
+            trailing_icon: None,
+            error_text: None,
+            secure: false,
+            keyboard_type: KeyboardType::Text,
+            ime_action: ImeAction::Default,
+            single_line: true,
+            enabled: true,
+        }
+    }
+}
+
+/// An editable text field bound to a `State<String>`: the state holds what
+/// the field shows, and every edit is written back to it.
+pub struct TextField {
+    pub value: State<String>,
+    pub style: TextFieldStyle,
+    pub options: TextFieldOptions,
+    pub on_change: Option<EventHandler<String>>,
+    pub on_submit: Option<EventHandler<String>>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(TextField);
+impl_key!(TextField);
+
+impl Widget for TextField {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS text fields
+            UiNode::Label {
+                title: self.value.get(),
+                pt_size: 16.0,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            let value = self.value.clone();
+            let on_change = self.on_change.clone();
+            let change_action_id = register_scoped_event_action(
+                "change",
+                Arc::new(move |text: String| {
+                    value.set(text.clone());
+                    if let Some(on_change) = &on_change {
+                        on_change(text);
+                    }
+                }),
+            );
+            let submit_action_id = self.on_submit.clone().map(|on_submit| {
+                let value = self.value.clone();
+                register_scoped_action("submit", Arc::new(move || on_submit(value.get_untracked())))
+            });
+
+            UiNode::TextField {
+                value: self.value.get(),
+                style: self.style,
+                options: self.options.clone(),
+                change_action_id,
+                submit_action_id,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl TextField {
+    pub fn new(value: &State<String>) -> Self {
+        Self {
+            value: value.clone(),
+            style: TextFieldStyle::Filled,
+            options: TextFieldOptions::default(),
+            on_change: None,
+            on_submit: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    pub fn style(mut self, style: TextFieldStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn options(mut self, options: TextFieldOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.options.label = Some(label.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.options.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn leading_icon(mut self, icon: IconType) -> Self {
+        self.options.leading_icon = Some(icon);
+        self
+    }
+
+    pub fn trailing_icon(mut self, icon: IconType) -> Self {
+        self.options.trailing_icon = Some(icon);
+        self
+    }
+
+    /// Error shown under the field; `None` clears it.
+    pub fn error_text(mut self, text: Option<String>) -> Self {
+        self.options.error_text = text;
+        self
+    }
+
+    pub fn secure(mut self, value: bool) -> Self {
+        self.options.secure = value;
+        self
+    }
+
+    pub fn keyboard_type(mut self, value: KeyboardType) -> Self {
+        self.options.keyboard_type = value;
+        self
+    }
+
+    pub fn ime_action(mut self, value: ImeAction) -> Self {
+        self.options.ime_action = value;
+        self
+    }
+
+    pub fn single_line(mut self, value: bool) -> Self {
+        self.options.single_line = value;
+        self
+    }
+
+    pub fn enabled(mut self, value: bool) -> Self {
+        self.options.enabled = value;
+        self
+    }
+
+    /// Called with the new text after each edit, once the state holds it.
+    pub fn on_change(mut self, on_change: impl Fn(String) + Send + Sync + 'static) -> Self {
+        self.on_change = Some(Arc::new(on_change));
+        self
+    }
+
+    /// Called with the text when the keyboard's action key is pressed.
+    pub fn on_submit(mut self, on_submit: impl Fn(String) + Send + Sync + 'static) -> Self {
+        self.on_submit = Some(Arc::new(on_submit));
+        self
+    }
+}
+
+pub fn text_field(value: &State<String>) -> TextField {
+    TextField::new(value)
+}
+
+pub fn outlined_text_field(value: &State<String>) -> TextField {
+    TextField::new(value).style(TextFieldStyle::Outlined)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_test_tag};
+
+    fn shown(tester: &WidgetTester) -> String {
+        let UiNode::TextField { value, .. } = tester.find(&by_test_tag("field")).node else {
+            panic!("No text field.\n{}", tester.dump());
+        };
+        value
+    }
+
+    #[test]
+    fn edits_are_written_to_the_state() {
+        let value = state(String::new());
+        let mut tester = WidgetTester::mount(text_field(&value).test_tag("field"));
+        tester.enter_text(&by_test_tag("field"), "Hello");
+
+        assert_eq!(value.get_untracked(), "Hello");
+        assert_eq!(shown(&tester), "Hello");
+    }
+
+    #[test]
+    fn an_edit_on_change_rejects_shows_the_previous_text() {
+        let value = state("12".to_string());
+        let digits = value.clone();
+        let field = text_field(&value)
+            .on_change(move |text| digits.set(text.chars().filter(char::is_ascii_digit).collect()))
+            .test_tag("field");
+        let mut tester = WidgetTester::mount(field);
+        tester.enter_text(&by_test_tag("field"), "12a");
+
+        assert_eq!(value.get_untracked(), "12");
+        assert_eq!(shown(&tester), "12");
+        // The renderer resyncs the field on the build that follows the edit
+        assert_eq!(tester.redraw_count(), 1);
+    }
+
+    #[test]
+    fn an_edit_on_change_rewrites_shows_the_rewrite() {
+        let value = state(String::new());
+        let upper = value.clone();
+        let field = text_field(&value)
+            .on_change(move |text| upper.set(text.to_uppercase()))
+            .test_tag("field");
+        let mut tester = WidgetTester::mount(field);
+        tester.enter_text(&by_test_tag("field"), "abc");
+
+        assert_eq!(value.get_untracked(), "ABC");
+        assert_eq!(shown(&tester), "ABC");
+        tester.enter_text(&by_test_tag("field"), "ABCd");
+        assert_eq!(shown(&tester), "ABCD");
+    }
+}
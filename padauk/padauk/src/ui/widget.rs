@@ -5,12 +5,14 @@ pub use crate::native::android_ui_node::AndroidUiNode;
 pub use crate::native::ios_ui_node::IosUiNode;
 
 use crate::{
+    impl_key,
     impl_modifiers,
+    panics,
     prelude::Navigator,
     ui::{
         app_bar::{AppBarStyle, AppBarStyleOptions},
+        build_context,
         button::{
-            ButtonShape,
             ButtonStyle,
             ButtonStyleOptions,
             FabOptions,
@@ -19,12 +21,23 @@ use crate::{
             IconButtonStyle,
             IconType,
         },
-        card::{CardShape, CardStyle, CardStyleOptions},
+        card::{CardStyle, CardStyleOptions},
         chip::{ChipStyle, ChipStyleOptions},
+        event_registry::{
+            ActionHandler,
+            DragDelta,
+            EventHandler,
+            ScrollPosition,
+            register_scoped_action,
+            register_scoped_event_action,
+        },
+        layout::{Alignment, CrossAxisAlignment, MainAxisAlignment},
         modifier::Modifiers,
+        snackbar,
     },
 };
 use log::debug;
+use std::sync::Arc;
 
 // --------------------------------------------------------
 // THE SWITCH: Choose the definition based on the OS
@@ -35,12 +48,18 @@ pub use IosUiNode as UiNode;
 
 #[cfg(not(target_os = "ios"))] // Fallback for iOS/Tests
 pub use AndroidUiNode as UiNode;
-use uuid::Uuid;
 
 // This is equivalent to Flutter's "abstract class Widget"
 pub trait Widget {
     // Equivalent to: Widget build(BuildContext context)
     fn build(&self) -> UiNode;
+
+    /// Identity of the widget among its siblings, set with `.key(...)`.
+    /// Keyed widgets keep their action ids (and, in Compose, their slot)
+    /// when siblings are inserted, removed or reordered.
+    fn widget_key(&self) -> Option<&str> {
+        None
+    }
 }
 
 pub trait IntoWidget {
@@ -53,6 +72,89 @@ impl<T: Widget + Sized + 'static> IntoWidget for T {
     }
 }
 
+// ==========================
+//      KEYED WRAPPER
+// ==========================
+
+/// Gives any widget (e.g. a custom one without `.key(...)`) a key.
+pub struct Keyed {
+    pub key: String,
+    pub child: Box<dyn Widget>,
+}
+
+impl Widget for Keyed {
+    fn build(&self) -> UiNode {
+        let mut node = self.child.build();
+        node.set_key(Some(self.key.clone()));
+        node
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+}
+
+pub fn keyed(key: impl Into<String>, child: impl Widget + 'static) -> Keyed {
+    Keyed {
+        key: key.into(),
+        child: Box::new(child),
+    }
+}
+
+// ==========================
+//      ERROR BOUNDARY
+// ==========================
+
+/// Builds `child`, or `fallback` if building `child` panics. The panic is
+/// reported to `PadaukApp::on_panic`.
+pub struct ErrorBoundary {
+    pub child: Box<dyn Widget>,
+    pub fallback: Box<dyn Widget>,
+    pub key: Option<String>,
+}
+
+impl ErrorBoundary {
+    pub fn new(child: impl Widget + 'static, fallback: impl Widget + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            fallback: Box::new(fallback),
+            key: None,
+        }
+    }
+
+    pub fn key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+}
+
+impl Widget for ErrorBoundary {
+    fn build(&self) -> UiNode {
+        let built = panics::catch("build", || {
+            build_context::slot("child", || self.child.build())
+        });
+        let mut node = built.unwrap_or_else(|report| {
+            panics::report(&report);
+            build_context::slot("fallback", || self.fallback.build())
+        });
+        if self.key.is_some() {
+            node.set_key(self.key.clone());
+        }
+        node
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref().or_else(|| self.child.widget_key())
+    }
+}
+
+pub fn error_boundary(
+    child: impl Widget + 'static,
+    fallback: impl Widget + 'static,
+) -> ErrorBoundary {
+    ErrorBoundary::new(child, fallback)
+}
+
 // ==========================
 //      SCAFFOLD WIDGET
 // ==========================
@@ -61,7 +163,9 @@ pub struct Scaffold {
     pub app_bar: Option<Box<dyn Widget>>,
     pub body: Box<dyn Widget>,
     pub fab: Option<Box<dyn Widget>>,
+    pub bottom_bar: Option<Box<dyn Widget>>,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl Scaffold {
@@ -70,7 +174,9 @@ impl Scaffold {
             body: Box::new(body),
             app_bar: None,
             fab: None,
+            bottom_bar: None,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -83,49 +189,70 @@ impl Scaffold {
         self.fab = Some(Box::new(button));
         self
     }
+
+    /// Under the body, e.g. a `navigation_bar`.
+    pub fn bottom_bar(mut self, bar: impl Widget + 'static) -> Self {
+        self.bottom_bar = Some(Box::new(bar));
+        self
+    }
 }
 
 impl_modifiers!(Scaffold);
+impl_key!(Scaffold);
 
 impl Widget for Scaffold {
     fn build(&self) -> UiNode {
+        // Claimed before the body builds, so nested scaffolds don't show messages too
+        let hosts_snackbar = snackbar::claim_host();
+
         // 1. Build the AppBar Node first
         let mut app_bar_nodes: Vec<UiNode> = Vec::new();
 
         if let Some(bar) = &self.app_bar {
-            let mut node = bar.build();
+            let mut node = build_context::slot("app_bar", || bar.build());
 
             // 2. Logic: Inject Back Button if Global Navigator says we can pop
-            if Navigator::can_pop() {
+            if Navigator::page_can_pop() {
                 debug!("Scaffold: injecting back button into AppBar.");
                 if let UiNode::AppBar { leading, .. } = &mut node {
                     // Create a Back Button
                     let back_btn = Button::new("<", || {
-                        Navigator::pop();
+                        Navigator::back();
                     });
 
                     // Inject into the 'leading' slot of the AppBar node
-                    leading.push(back_btn.build());
+                    leading.push(build_context::slot("back", || back_btn.build()));
                 }
             }
             app_bar_nodes.push(node);
         }
 
         // Helper to convert Option<Box<Widget>> -> Vec<UiNode>
-        let to_vec = |opt: &Option<Box<dyn Widget>>| -> Vec<UiNode> {
+        let to_vec = |name: &str, opt: &Option<Box<dyn Widget>>| -> Vec<UiNode> {
             match opt {
-                Some(w) => vec![w.build()],
+                Some(w) => vec![build_context::slot(name, || w.build())],
                 None => vec![],
             }
         };
 
         UiNode::Scaffold {
             app_bar: app_bar_nodes,
-            body: vec![self.body.build()],
-            floating_action_button: to_vec(&self.fab),
+            body: vec![build_context::slot("body", || self.body.build())],
+            floating_action_button: to_vec("fab", &self.fab),
+            bottom_bar: to_vec("bottom_bar", &self.bottom_bar),
+            snackbar: if hosts_snackbar {
+                snackbar::build_host()
+            } else {
+                vec![]
+            },
             modifiers: self.modifiers.clone(),
+            key: self.key.clone(),
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 // DSL Helper
@@ -139,26 +266,40 @@ pub fn scaffold(body: impl Widget + 'static) -> Scaffold {
 
 pub struct Scroll {
     pub child: Box<dyn Widget>,
+    pub on_scroll: Option<EventHandler<ScrollPosition>>,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl Scroll {
     pub fn new(child: impl Widget + 'static) -> Self {
         Self {
             child: Box::new(child),
+            on_scroll: None,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
+
+    /// Called with the position of the content, in dp from its top, as it
+    /// scrolls.
+    pub fn on_scroll(mut self, on_scroll: impl Fn(ScrollPosition) + Send + Sync + 'static) -> Self {
+        self.on_scroll = Some(Arc::new(on_scroll));
+        self
+    }
 }
 
 impl_modifiers!(Scroll);
+impl_key!(Scroll);
 
 impl Widget for Scroll {
     fn build(&self) -> UiNode {
         #[cfg(target_os = "ios")]
         {
             UiNode::ScrollView {
-                views: vec![self.child.build()],
+                views: vec![build_context::child(0, self.child.widget_key(), || {
+                    self.child.build()
+                })],
                 attributes: self.modifiers.clone(),
             }
         }
@@ -166,17 +307,93 @@ impl Widget for Scroll {
         #[cfg(not(target_os = "ios"))]
         {
             UiNode::Scroll {
-                child: vec![self.child.build()],
+                child: vec![build_context::child(0, self.child.widget_key(), || {
+                    self.child.build()
+                })],
+                scroll_action_id: self
+                    .on_scroll
+                    .as_ref()
+                    .map(|handler| register_scoped_event_action("scroll", handler.clone())),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 pub fn scroll(child: impl Widget + 'static) -> Scroll {
     Scroll::new(child)
 }
 
+// ==========================
+//      DRAGGABLE
+// ==========================
+
+/// Reports drags that start on its child, e.g. to move a widget around with
+/// the finger.
+pub struct Draggable {
+    pub child: Box<dyn Widget>,
+    pub on_drag: EventHandler<DragDelta>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl Draggable {
+    /// `on_drag` is called with how far the finger moved, in dp, each time
+    /// it moves during a drag.
+    pub fn new(
+        child: impl Widget + 'static,
+        on_drag: impl Fn(DragDelta) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            child: Box::new(child),
+            on_drag: Arc::new(on_drag),
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+}
+
+impl_modifiers!(Draggable);
+impl_key!(Draggable);
+
+impl Widget for Draggable {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS drag gestures
+            self.child.build()
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Draggable {
+                child: vec![build_context::child(0, self.child.widget_key(), || {
+                    self.child.build()
+                })],
+                action_id: register_scoped_event_action("drag", self.on_drag.clone()),
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+pub fn draggable(
+    child: impl Widget + 'static,
+    on_drag: impl Fn(DragDelta) + Send + Sync + 'static,
+) -> Draggable {
+    Draggable::new(child, on_drag)
+}
+
 // ==========================
 //      APP BAR WIDGET
 // ==========================
@@ -186,6 +403,7 @@ pub struct AppBar {
     pub style: AppBarStyle,
     pub options: AppBarStyleOptions,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl AppBar {
@@ -195,6 +413,7 @@ impl AppBar {
             style: AppBarStyle::Small,
             options: AppBarStyleOptions::default(),
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -210,6 +429,7 @@ impl AppBar {
 }
 
 impl_modifiers!(AppBar);
+impl_key!(AppBar);
 
 impl Widget for AppBar {
     fn build(&self) -> UiNode {
@@ -219,8 +439,13 @@ impl Widget for AppBar {
             style: self.style,
             options: self.options.clone(),
             modifiers: self.modifiers.clone(),
+            key: self.key.clone(),
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 pub fn app_bar(title: impl Into<String>) -> AppBar {
@@ -245,9 +470,11 @@ pub struct Text {
     pub content: String,
     pub font_size: f32,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Text);
+impl_key!(Text);
 
 impl Widget for Text {
     fn build(&self) -> UiNode {
@@ -270,9 +497,14 @@ impl Widget for Text {
                 // Logic: Convert abstract "size" to Android SP
                 sp_size: self.font_size,
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Text {
@@ -281,6 +513,7 @@ impl Text {
             content: content.into(),
             font_size: 16.0, // ✅ Your Custom Default Value
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 }
@@ -292,16 +525,20 @@ pub fn text(content: &str) -> Text {
 
 pub struct Button {
     pub label: String,
-    pub action_id: String,
+    pub on_click: ActionHandler,
     pub style: ButtonStyle,
     pub options: ButtonStyleOptions,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Button);
+impl_key!(Button);
 
 impl Widget for Button {
     fn build(&self) -> UiNode {
+        let action_id = register_scoped_action("click", self.on_click.clone());
+
         // --- iOS ---
         #[cfg(target_os = "ios")]
         {
@@ -312,7 +549,7 @@ impl Widget for Button {
             };
 
             UiNode::Button {
-                action_id: self.action_id.clone(),
+                action_id,
                 // FIX: Wrap in Arc::new
                 label: vec![child_node],
                 attributes: self.modifiers.clone(),
@@ -327,35 +564,37 @@ impl Widget for Button {
                 text: self.label.clone(),
                 sp_size: 16.0,
                 modifiers: Modifiers::default(),
+                key: None,
             };
 
             UiNode::Button {
-                action_id: self.action_id.clone(),
+                action_id,
                 // FIX: Wrap in Arc::new
                 content: vec![child_node],
                 style: self.style,
                 options: self.options.clone(),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Button {
     // Standard constructor
     pub fn new(label: impl Into<String>, on_click: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        debug!("Button created with action id: {}", action_id);
-
-        // Register the closure in our static map
-        crate::ui::event_registry::register_action(action_id.clone(), on_click);
-
+        // The closure is registered when the button is built
         Self {
             label: label.into(),
-            action_id: action_id,
+            on_click: Arc::new(on_click),
             style: ButtonStyle::Filled,
             options: ButtonStyleOptions::default(),
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -403,12 +642,14 @@ pub fn text_button(label: impl Into<String>, on_click: impl Fn() + Send + Sync +
 pub struct IconButton {
     pub icon: IconType,
     pub style: IconButtonStyle,
-    pub action_id: String,
+    pub on_click: ActionHandler,
     pub options: IconButtonOptions,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(IconButton);
+impl_key!(IconButton);
 
 impl Widget for IconButton {
     fn build(&self) -> UiNode {
@@ -425,26 +666,30 @@ impl Widget for IconButton {
         #[cfg(not(target_os = "ios"))]
         {
             UiNode::IconButton {
-                action_id: self.action_id.clone(),
+                action_id: register_scoped_action("click", self.on_click.clone()),
                 icon: self.icon,
                 style: self.style,
                 options: self.options.clone(),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl IconButton {
     pub fn new(icon: IconType, on_click: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        crate::ui::event_registry::register_action(action_id.clone(), on_click);
         Self {
             icon,
             style: IconButtonStyle::Standard,
-            action_id,
+            on_click: Arc::new(on_click),
             options: IconButtonOptions::default(),
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -492,12 +737,14 @@ pub fn outlined_icon_button(
 pub struct Card {
     pub children: Vec<Box<dyn Widget>>,
     pub style: CardStyle,
-    pub action_id: Option<String>,
+    pub on_click: Option<ActionHandler>,
     pub options: CardStyleOptions,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Card);
+impl_key!(Card);
 
 impl Widget for Card {
     fn build(&self) -> UiNode {
@@ -513,14 +760,27 @@ impl Widget for Card {
         #[cfg(not(target_os = "ios"))]
         {
             UiNode::Card {
-                children: self.children.iter().map(|c| c.build()).collect(),
+                children: self
+                    .children
+                    .iter()
+                    .enumerate()
+                    .map(|(i, c)| build_context::child(i, c.widget_key(), || c.build()))
+                    .collect(),
                 style: self.style,
-                action_id: self.action_id.clone(),
+                action_id: self
+                    .on_click
+                    .as_ref()
+                    .map(|handler| register_scoped_action("click", handler.clone())),
                 options: self.options.clone(),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Card {
@@ -528,9 +788,10 @@ impl Card {
         Self {
             children,
             style: CardStyle::Filled,
-            action_id: None,
+            on_click: None,
             options: CardStyleOptions::default(),
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -540,9 +801,7 @@ impl Card {
     }
 
     pub fn on_click(mut self, on_click: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        crate::ui::event_registry::register_action(action_id.clone(), on_click);
-        self.action_id = Some(action_id);
+        self.on_click = Some(Arc::new(on_click));
         self
     }
 
@@ -566,15 +825,17 @@ pub fn outlined_card(children: Vec<Box<dyn Widget>>) -> Card {
 
 pub struct Checkbox {
     pub checked: bool,
-    pub action_id: String,
+    pub on_toggle: ActionHandler,
     pub enabled: bool,
     pub color_checked: Option<crate::ui::color::ColorValue>,
     pub color_unchecked: Option<crate::ui::color::ColorValue>,
     pub color_checkmark: Option<crate::ui::color::ColorValue>,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Checkbox);
+impl_key!(Checkbox);
 
 impl Widget for Checkbox {
     fn build(&self) -> UiNode {
@@ -591,29 +852,33 @@ impl Widget for Checkbox {
         {
             UiNode::Checkbox {
                 checked: self.checked,
-                action_id: self.action_id.clone(),
+                action_id: register_scoped_action("toggle", self.on_toggle.clone()),
                 enabled: self.enabled,
                 color_checked: self.color_checked.clone(),
                 color_unchecked: self.color_unchecked.clone(),
                 color_checkmark: self.color_checkmark.clone(),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Checkbox {
     pub fn new(checked: bool, on_toggle: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        crate::ui::event_registry::register_action(action_id.clone(), on_toggle);
         Self {
             checked,
-            action_id,
+            on_toggle: Arc::new(on_toggle),
             enabled: true,
             color_checked: None,
             color_unchecked: None,
             color_checkmark: None,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -648,15 +913,17 @@ pub struct Chip {
     pub label: String,
     pub style: ChipStyle,
     pub selected: bool,
-    pub action_id: String,
+    pub on_click: ActionHandler,
     pub leading_icon: Option<IconType>,
     pub trailing_icon: Option<IconType>,
-    pub close_action_id: Option<String>,
+    pub on_close: Option<ActionHandler>,
     pub options: ChipStyleOptions,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Chip);
+impl_key!(Chip);
 
 impl Widget for Chip {
     fn build(&self) -> UiNode {
@@ -675,31 +942,38 @@ impl Widget for Chip {
                 label: self.label.clone(),
                 style: self.style,
                 selected: self.selected,
-                action_id: self.action_id.clone(),
+                action_id: register_scoped_action("click", self.on_click.clone()),
                 leading_icon: self.leading_icon,
                 trailing_icon: self.trailing_icon,
-                close_action_id: self.close_action_id.clone(),
+                close_action_id: self
+                    .on_close
+                    .as_ref()
+                    .map(|handler| register_scoped_action("close", handler.clone())),
                 options: self.options.clone(),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Chip {
     pub fn new(label: impl Into<String>, style: ChipStyle, on_click: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        crate::ui::event_registry::register_action(action_id.clone(), on_click);
         Self {
             label: label.into(),
             style,
             selected: false,
-            action_id,
+            on_click: Arc::new(on_click),
             leading_icon: None,
             trailing_icon: None,
-            close_action_id: None,
+            on_close: None,
             options: ChipStyleOptions::default(),
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -719,9 +993,7 @@ impl Chip {
     }
 
     pub fn close_action(mut self, on_click: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        crate::ui::event_registry::register_action(action_id.clone(), on_click);
-        self.close_action_id = Some(action_id);
+        self.on_close = Some(Arc::new(on_click));
         self
     }
 
@@ -760,12 +1032,14 @@ pub struct Fab {
     pub icon: IconType,
     pub style: FabStyle,
     pub label: Option<String>,
-    pub action_id: String,
+    pub on_click: ActionHandler,
     pub options: FabOptions,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Fab);
+impl_key!(Fab);
 
 impl Widget for Fab {
     fn build(&self) -> UiNode {
@@ -781,28 +1055,32 @@ impl Widget for Fab {
         #[cfg(not(target_os = "ios"))]
         {
             UiNode::Fab {
-                action_id: self.action_id.clone(),
+                action_id: register_scoped_action("click", self.on_click.clone()),
                 icon: self.icon,
                 style: self.style,
                 label: self.label.clone(),
                 options: self.options.clone(),
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Fab {
     pub fn new(icon: IconType, on_click: impl Fn() + Send + Sync + 'static) -> Self {
-        let action_id = Uuid::new_v4().to_string();
-        crate::ui::event_registry::register_action(action_id.clone(), on_click);
         Self {
             icon,
             style: FabStyle::Normal,
             label: None,
-            action_id,
+            on_click: Arc::new(on_click),
             options: FabOptions::default(),
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
 
@@ -851,15 +1129,25 @@ pub fn button(label: impl Into<String>, on_click: impl Fn() + Send + Sync + 'sta
 
 pub struct Column {
     pub children: Vec<Box<dyn Widget>>,
+    pub main_axis_alignment: MainAxisAlignment,
+    pub cross_axis_alignment: CrossAxisAlignment,
+    pub spacing: f32,
     pub modifiers: Modifiers,
+    pub key: Option<String>,
 }
 
 impl_modifiers!(Column);
+impl_key!(Column);
 
 impl Widget for Column {
     fn build(&self) -> UiNode {
         // Recursively build children
-        let node_children = self.children.iter().map(|child| child.build()).collect();
+        let node_children = self
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| build_context::child(i, child.widget_key(), || child.build()))
+            .collect();
 
         // 2. Return the Platform-Specific Node
         #[cfg(target_os = "ios")]
@@ -874,10 +1162,18 @@ impl Widget for Column {
         {
             UiNode::Column {
                 children: node_children, // Matches Vec<AndroidUiNode>
+                main_axis_alignment: self.main_axis_alignment,
+                cross_axis_alignment: self.cross_axis_alignment,
+                spacing: self.spacing,
                 modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
             }
         }
     }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
 }
 
 impl Column {
@@ -885,11 +1181,368 @@ impl Column {
     pub fn new(children: Vec<Box<dyn Widget>>) -> Self {
         Self {
             children,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Center,
+            spacing: 0.0,
             modifiers: Modifiers::default(),
+            key: None,
         }
     }
+
+    /// How the children are placed vertically, e.g. `SpaceBetween`.
+    pub fn main_axis_alignment(mut self, value: MainAxisAlignment) -> Self {
+        self.main_axis_alignment = value;
+        self
+    }
+
+    /// How the children are placed horizontally. Centered by default.
+    pub fn cross_axis_alignment(mut self, value: CrossAxisAlignment) -> Self {
+        self.cross_axis_alignment = value;
+        self
+    }
+
+    /// Gap between consecutive children, in dp. With the `Space*` alignments,
+    /// it's the least gap: the room left is spread out on top of it.
+    pub fn spacing(mut self, value: f32) -> Self {
+        self.spacing = value;
+        self
+    }
 }
 
 pub fn column(children: Vec<Box<dyn Widget>>) -> Column {
     Column::new(children)
 }
+
+pub struct Row {
+    pub children: Vec<Box<dyn Widget>>,
+    pub main_axis_alignment: MainAxisAlignment,
+    pub cross_axis_alignment: CrossAxisAlignment,
+    pub spacing: f32,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(Row);
+impl_key!(Row);
+
+impl Widget for Row {
+    fn build(&self) -> UiNode {
+        let node_children = self
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| build_context::child(i, child.widget_key(), || child.build()))
+            .collect();
+
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS HStack
+            UiNode::VStack {
+                views: node_children,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Row {
+                children: node_children,
+                main_axis_alignment: self.main_axis_alignment,
+                cross_axis_alignment: self.cross_axis_alignment,
+                spacing: self.spacing,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl Row {
+    pub fn new(children: Vec<Box<dyn Widget>>) -> Self {
+        Self {
+            children,
+            main_axis_alignment: MainAxisAlignment::Start,
+            cross_axis_alignment: CrossAxisAlignment::Center,
+            spacing: 0.0,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// How the children are placed horizontally, e.g. `SpaceBetween`.
+    pub fn main_axis_alignment(mut self, value: MainAxisAlignment) -> Self {
+        self.main_axis_alignment = value;
+        self
+    }
+
+    /// How the children are placed vertically. Centered by default.
+    pub fn cross_axis_alignment(mut self, value: CrossAxisAlignment) -> Self {
+        self.cross_axis_alignment = value;
+        self
+    }
+
+    /// Gap between consecutive children, in dp. With the `Space*` alignments,
+    /// it's the least gap: the room left is spread out on top of it.
+    pub fn spacing(mut self, value: f32) -> Self {
+        self.spacing = value;
+        self
+    }
+}
+
+pub fn row(children: Vec<Box<dyn Widget>>) -> Row {
+    Row::new(children)
+}
+
+/// Children drawn on top of each other, the last one on top. Each is placed
+/// by its own `.align(...)`, or else by the stack's `alignment`.
+pub struct Stack {
+    pub children: Vec<Box<dyn Widget>>,
+    pub alignment: Alignment,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(Stack);
+impl_key!(Stack);
+
+impl Widget for Stack {
+    fn build(&self) -> UiNode {
+        let node_children = self
+            .children
+            .iter()
+            .enumerate()
+            .map(|(i, child)| build_context::child(i, child.widget_key(), || child.build()))
+            .collect();
+
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS ZStack
+            UiNode::VStack {
+                views: node_children,
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Stack {
+                children: node_children,
+                alignment: self.alignment,
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl Stack {
+    pub fn new(children: Vec<Box<dyn Widget>>) -> Self {
+        Self {
+            children,
+            alignment: Alignment::TopStart,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// Where children without an `.align(...)` of their own are placed.
+    pub fn alignment(mut self, value: Alignment) -> Self {
+        self.alignment = value;
+        self
+    }
+}
+
+pub fn stack(children: Vec<Box<dyn Widget>>) -> Stack {
+    Stack::new(children)
+}
+
+// Compose's name for a stack
+pub fn zbox(children: Vec<Box<dyn Widget>>) -> Stack {
+    Stack::new(children)
+}
+
+/// Empty space. `spacer()` takes up the room left in a row or column
+/// (a weight of 1), `hgap(size)` / `vgap(size)` are fixed gaps.
+pub struct Spacer {
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(Spacer);
+impl_key!(Spacer);
+
+impl Widget for Spacer {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            UiNode::VStack {
+                views: vec![],
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Spacer {
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl Spacer {
+    pub fn new() -> Self {
+        Self {
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+}
+
+impl Default for Spacer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn spacer() -> Spacer {
+    Spacer::new().weight(1.0)
+}
+
+/// A fixed horizontal gap of `size` dp, e.g. between children of a row. It
+/// doesn't make the row any taller.
+pub fn hgap(size: f32) -> Spacer {
+    Spacer::new().width(size)
+}
+
+/// A fixed vertical gap of `size` dp, e.g. between children of a column.
+pub fn vgap(size: f32) -> Spacer {
+    Spacer::new().height(size)
+}
+
+/// A thin line separating content: horizontal in a column, vertical in a row.
+pub struct Divider {
+    pub vertical: bool,
+    pub thickness: Option<f32>,
+    pub color: Option<crate::ui::color::ColorValue>,
+    pub modifiers: Modifiers,
+    pub key: Option<String>,
+}
+
+impl_modifiers!(Divider);
+impl_key!(Divider);
+
+impl Widget for Divider {
+    fn build(&self) -> UiNode {
+        #[cfg(target_os = "ios")]
+        {
+            // TODO: iOS Divider
+            UiNode::VStack {
+                views: vec![],
+                attributes: self.modifiers.clone(),
+            }
+        }
+
+        #[cfg(not(target_os = "ios"))]
+        {
+            UiNode::Divider {
+                vertical: self.vertical,
+                thickness: self.thickness,
+                color: self.color.clone(),
+                modifiers: self.modifiers.clone(),
+                key: self.key.clone(),
+            }
+        }
+    }
+
+    fn widget_key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl Divider {
+    pub fn new(vertical: bool) -> Self {
+        Self {
+            vertical,
+            thickness: None,
+            color: None,
+            modifiers: Modifiers::default(),
+            key: None,
+        }
+    }
+
+    /// Line thickness in dp (Material's default is 1).
+    pub fn thickness(mut self, value: f32) -> Self {
+        self.thickness = Some(value);
+        self
+    }
+
+    pub fn color(mut self, color: crate::ui::color::ColorValue) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+pub fn divider() -> Divider {
+    Divider::new(false)
+}
+
+pub fn vertical_divider() -> Divider {
+    Divider::new(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::testing::{WidgetTester, by_kind, by_text};
+
+    #[test]
+    fn scroll_views_report_where_their_content_is() {
+        let offset = state(0.0);
+        let on_scroll = offset.clone();
+        let mut tester = WidgetTester::mount(
+            scroll(text("Posts")).on_scroll(move |position| on_scroll.set(position.y)),
+        );
+
+        tester.scroll_to_offset(&by_kind("Scroll"), 120.0);
+        assert_eq!(offset.get_untracked(), 120.0);
+    }
+
+    #[test]
+    fn scroll_views_without_on_scroll_send_nothing() {
+        let tester = WidgetTester::mount(scroll(text("Posts")));
+
+        let found = tester.find(&by_kind("Scroll"));
+        assert_eq!(found.node.scroll_action_id(), None);
+    }
+
+    #[test]
+    fn drags_on_a_draggable_or_its_content_report_each_movement() {
+        let offset = state((0.0, 0.0));
+        let on_drag = offset.clone();
+        let mut tester = WidgetTester::mount(draggable(text("Card"), move |delta: DragDelta| {
+            on_drag.update(|(x, y)| {
+                *x += delta.dx;
+                *y += delta.dy;
+            })
+        }));
+
+        tester.drag(&by_kind("Draggable"), 10.0, -4.0);
+        tester.drag(&by_text("Card"), 5.0, 2.0);
+        assert_eq!(offset.get_untracked(), (15.0, -2.0));
+    }
+}